@@ -52,8 +52,10 @@ use crate::{
 };
 
 /// Specifies if the screen should be updated after the callback function has returned
+///
+/// Variants are ordered by cost (see `Update::rank`), not by declaration order.
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Update {
     /// The screen does not need to redraw after the callback has been called
     DoNothing,
@@ -62,16 +64,49 @@ pub enum Update {
     RefreshDom,
     /// The layout has to be re-calculated for all windows
     RefreshDomAllWindows,
+    /// The callback only changed scroll positions: the last display list can be
+    /// re-composited with the new scroll offsets, no DOM regeneration or relayout needed.
+    ///
+    /// Declared last so the discriminants of the other variants stay stable for the
+    /// C API, ranks between `DoNothing` and `RefreshDom`.
+    ScrollOnly,
 }
 
 impl Update {
+    /// Cost of the update, used for ordering:
+    /// `DoNothing` < `ScrollOnly` < `RefreshDom` < `RefreshDomAllWindows`
+    pub const fn rank(&self) -> u8 {
+        match self {
+            Update::DoNothing => 0,
+            Update::ScrollOnly => 1,
+            Update::RefreshDom => 2,
+            Update::RefreshDomAllWindows => 3,
+        }
+    }
+
     pub fn max_self(&mut self, other: Self) {
-        if *self == Update::DoNothing && other != Update::DoNothing {
-            *self = other;
-        } else if *self == Update::RefreshDom && other == Update::RefreshDomAllWindows {
+        if other > *self {
             *self = other;
         }
     }
+
+    /// Returns true if the DOM has to be regenerated (the layout() callback
+    /// has to be called again) after this update
+    pub fn needs_dom_regeneration(&self) -> bool {
+        matches!(self, Update::RefreshDom | Update::RefreshDomAllWindows)
+    }
+}
+
+impl PartialOrd for Update {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Update {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
 }
 
 // -- layout callback
@@ -547,12 +547,14 @@ fn encode_margin_i16<T: HasInnerPixelValue>(val: &CssPropertyValue<T>) -> i16 {
     encode_css_pixel_as_i16(val)
 }
 
-/// Encode CssPropertyValue<LayoutFlexBasis> — LayoutFlexBasis is Auto | Exact(PixelValue).
+/// Encode CssPropertyValue<LayoutFlexBasis> — LayoutFlexBasis is Auto | Exact(PixelValue) | Content.
+/// `content` has no compact encoding and is resolved through the slow path.
 fn encode_flex_basis(val: &CssPropertyValue<LayoutFlexBasis>) -> u32 {
     match val {
         CssPropertyValue::Exact(fb) => match fb {
             LayoutFlexBasis::Auto => U32_AUTO,
             LayoutFlexBasis::Exact(pv) => encode_pixel_value_u32(pv),
+            LayoutFlexBasis::Content => U32_SENTINEL,
         },
         CssPropertyValue::Auto => U32_AUTO,
         CssPropertyValue::Initial => U32_INITIAL,
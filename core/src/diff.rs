@@ -639,7 +639,7 @@ pub fn reconcile_dom(
                     dom_id,
                     &timestamp,
                     LifecycleEventData {
-                        reason: LifecycleReason::InitialMount, // Context implies unmount
+                        reason: LifecycleReason::Unmount,
                         previous_bounds: Some(bounds),
                         current_bounds: LogicalRect::zero(),
                     },
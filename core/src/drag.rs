@@ -14,7 +14,7 @@ use alloc::collections::btree_map::BTreeMap;
 use alloc::vec::Vec;
 
 use crate::dom::{DomId, DomNodeId, NodeId, OptionDomNodeId};
-use crate::geom::LogicalPosition;
+use crate::geom::{LogicalPosition, LogicalSize};
 use crate::selection::TextCursor;
 use crate::window::WindowPosition;
 
@@ -169,6 +169,40 @@ pub enum WindowResizeEdge {
     BottomRight,
 }
 
+impl WindowResizeEdge {
+    /// Which resize edge of a borderless window the cursor is over.
+    ///
+    /// `cursor` is relative to the window, `border` is the thickness of the
+    /// grab area along each edge. Returns `None` in the interior and outside
+    /// of the window. Corners win over edges where the two overlap.
+    pub fn from_cursor(cursor: LogicalPosition, window_size: LogicalSize, border: f32) -> Option<Self> {
+        if cursor.x < 0.0
+            || cursor.y < 0.0
+            || cursor.x > window_size.width
+            || cursor.y > window_size.height
+        {
+            return None;
+        }
+
+        let left = cursor.x < border;
+        let right = cursor.x > window_size.width - border;
+        let top = cursor.y < border;
+        let bottom = cursor.y > window_size.height - border;
+
+        match (top, bottom, left, right) {
+            (true, _, true, _) => Some(WindowResizeEdge::TopLeft),
+            (true, _, _, true) => Some(WindowResizeEdge::TopRight),
+            (_, true, true, _) => Some(WindowResizeEdge::BottomLeft),
+            (_, true, _, true) => Some(WindowResizeEdge::BottomRight),
+            (true, _, _, _) => Some(WindowResizeEdge::Top),
+            (_, true, _, _) => Some(WindowResizeEdge::Bottom),
+            (_, _, true, _) => Some(WindowResizeEdge::Left),
+            (_, _, _, true) => Some(WindowResizeEdge::Right),
+            _ => None,
+        }
+    }
+}
+
 /// File drop from OS drag state.
 ///
 /// Tracks files being dragged from the operating system.
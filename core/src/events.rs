@@ -28,7 +28,7 @@ use crate::{
     resources::{ImageCache, RendererResources},
     styled_dom::{ChangedCssProperty, NodeHierarchyItemId},
     task::Instant,
-    window::RawWindowHandle,
+    window::{RawWindowHandle, WindowTheme},
     FastBTreeSet, FastHashMap,
 };
 
@@ -297,6 +297,8 @@ pub enum LifecycleReason {
     Resize,
     /// Props or state changed
     Update,
+    /// Removed from DOM
+    Unmount,
 }
 
 /// Keyboard modifier keys state.
@@ -410,6 +412,10 @@ pub struct WindowEventData {
     pub size: Option<LogicalRect>,
     /// Window position (for move events)
     pub position: Option<LogicalPosition>,
+    /// New scale factor (for DPI change events), `dpi / 96`
+    pub hidpi_factor: Option<f32>,
+    /// New window theme (for theme change events)
+    pub theme: Option<WindowTheme>,
 }
 
 /// Union of all possible event data types.
@@ -454,10 +460,15 @@ pub enum EventType {
     MouseDown,
     /// Mouse button released
     MouseUp,
+    /// The set of held mouse buttons changed (chord transition). Fires once per
+    /// frame, even if several buttons went down or up at the same time.
+    MouseButtonsChange,
     /// Mouse click (down + up on same element)
     Click,
     /// Mouse double-click
     DoubleClick,
+    /// Mouse triple-click (third click of a click chain)
+    TripleClick,
     /// Right-click / context menu
     ContextMenu,
 
@@ -466,6 +477,9 @@ pub enum EventType {
     KeyDown,
     /// Key released
     KeyUp,
+    /// Key held down long enough to repeat, fires at the interval configured
+    /// in the window's key repeat state
+    KeyRepeat,
     /// Character input (respects locale/keyboard layout)
     KeyPress,
 
@@ -486,6 +500,8 @@ pub enum EventType {
     FocusIn,
     /// Focus left element and its children
     FocusOut,
+    /// Element received focus via keyboard navigation (CSS `:focus-visible`)
+    FocusVisible,
 
     // Input Events
     /// Input value is being changed (fires on every keystroke)
@@ -552,6 +568,10 @@ pub enum EventType {
     RotateClockwise,
     /// Counter-clockwise rotation gesture
     RotateCounterClockwise,
+    /// Platform trackpad magnify gesture began, changed or ended
+    Magnify,
+    /// Platform trackpad rotate gesture began, changed or ended
+    Rotate,
 
     // Clipboard Events
     /// Content copied to clipboard
@@ -816,6 +836,88 @@ pub fn propagate_event(
     result
 }
 
+/// Continue the bubble phase of an event from a child DOM into its parent DOM.
+///
+/// Call this after `propagate_event` on the child DOM (e.g. the content of a
+/// `VirtualView`) if the event was not stopped there. The event is retargeted
+/// to `host` - the node in the parent DOM that embeds the child DOM - which
+/// then goes through the target phase, followed by the bubble phase through
+/// the host's ancestors. Capture handlers in the parent DOM are not invoked.
+pub fn propagate_event_to_host(
+    event: &mut SyntheticEvent,
+    host: DomNodeId,
+    node_hierarchy: &crate::id::NodeHierarchy,
+    callbacks: &BTreeMap<NodeId, Vec<EventFilter>>,
+) -> PropagationResult {
+    let mut result = PropagationResult::default();
+    if event.stopped || event.stopped_immediate {
+        result.default_prevented = event.prevented_default;
+        return result;
+    }
+
+    let path = get_dom_path(node_hierarchy, host.node);
+    let Some((&host_node_id, ancestors)) = path.split_last() else {
+        result.default_prevented = event.prevented_default;
+        return result;
+    };
+
+    event.target = host;
+    propagate_target_phase(event, host_node_id, callbacks, &mut result);
+
+    if !event.stopped {
+        propagate_phase(
+            event,
+            ancestors.iter().rev().copied(),
+            EventPhase::Bubble,
+            callbacks,
+            &mut result,
+        );
+    }
+
+    result.default_prevented = event.prevented_default;
+    result
+}
+
+/// Tracks `stopPropagation()` / `stopImmediatePropagation()` while the
+/// callbacks planned for a batch of events are invoked one after another.
+///
+/// Every planned callback belongs to a *dispatch*: the callbacks collected by
+/// propagating one event along its DOM path share a dispatch, callbacks that
+/// don't propagate (e.g. window filters, which fire on every node) get one
+/// each. Stopping propagation only affects the dispatch it was called in, so
+/// unrelated nodes - such as a sibling with the same kind of callback - still
+/// receive their events.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PropagationState {
+    /// Dispatch -> node whose callback stopped the propagation
+    stopped_at: BTreeMap<usize, DomNodeId>,
+    /// Dispatches in which no further callbacks may run at all
+    stopped_immediately: BTreeSet<usize>,
+}
+
+impl PropagationState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a callback of `dispatch` on `node` may still run. After
+    /// `stop_propagation`, the remaining callbacks on the same node still do.
+    pub fn should_invoke(&self, dispatch: usize, node: DomNodeId) -> bool {
+        !self.stopped_immediately.contains(&dispatch)
+            && self.stopped_at.get(&dispatch).map_or(true, |n| *n == node)
+    }
+
+    /// Stop `dispatch` from reaching any node other than `node`
+    pub fn stop_propagation(&mut self, dispatch: usize, node: DomNodeId) {
+        self.stopped_at.entry(dispatch).or_insert(node);
+    }
+
+    /// Stop `dispatch` from invoking any further callbacks
+    pub fn stop_immediate_propagation(&mut self, dispatch: usize) {
+        self.stopped_immediately.insert(dispatch);
+    }
+}
+
 /// Process a single propagation phase (Capture or Bubble)
 fn propagate_phase(
     event: &mut SyntheticEvent,
@@ -1052,7 +1154,14 @@ fn matches_filter_phase(
 
     match filter {
         EventFilter::Hover(hover_filter) => {
-            matches_hover_filter(hover_filter, event, current_phase)
+            // Bubbling callbacks are skipped on the way down
+            current_phase != EventPhase::Capture
+                && matches_hover_filter(hover_filter, event, current_phase)
+        }
+        EventFilter::Capture(hover_filter) => {
+            // Capturing callbacks are skipped on the way back up
+            current_phase != EventPhase::Bubble
+                && matches_hover_filter(hover_filter, event, current_phase)
         }
         EventFilter::Focus(focus_filter) => {
             matches_focus_filter(focus_filter, event, current_phase)
@@ -1071,6 +1180,14 @@ fn matches_filter_phase(
     }
 }
 
+/// The scroll delta of a `Scroll` event, if it carries one
+fn scroll_delta(event: &SyntheticEvent) -> Option<LogicalPosition> {
+    match &event.data {
+        EventData::Scroll(scroll) => Some(scroll.delta),
+        _ => None,
+    }
+}
+
 /// Check if a hover filter matches the event.
 fn matches_hover_filter(
     filter: &HoverEventFilter,
@@ -1129,11 +1246,14 @@ fn matches_hover_filter(
         (MouseEnter, EventType::MouseEnter) => true,
         (MouseLeave, EventType::MouseLeave) => true,
         (Scroll, EventType::Scroll) => true,
+        (ScrollHorizontal, EventType::Scroll) => scroll_delta(event).map_or(false, |d| d.x != 0.0),
+        (ScrollVertical, EventType::Scroll) => scroll_delta(event).map_or(false, |d| d.y != 0.0),
         (ScrollStart, EventType::ScrollStart) => true,
         (ScrollEnd, EventType::ScrollEnd) => true,
         (TextInput, EventType::Input) => true,
         (VirtualKeyDown, EventType::KeyDown) => true,
         (VirtualKeyUp, EventType::KeyUp) => true,
+        (VirtualKeyRepeat, EventType::KeyRepeat) => true,
         (HoveredFile, EventType::FileHover) => true,
         (DroppedFile, EventType::FileDrop) => true,
         (HoveredFileCancelled, EventType::FileHoverCancel) => true,
@@ -1149,6 +1269,7 @@ fn matches_hover_filter(
         (DragLeave, EventType::DragLeave) => true,
         (Drop, EventType::Drop) => true,
         (DoubleClick, EventType::DoubleClick) => true,
+        (TripleClick, EventType::TripleClick) => true,
         _ => false,
     }
 }
@@ -1215,8 +1336,10 @@ fn matches_focus_filter(
         (TextInput, EventType::Input) => true,
         (VirtualKeyDown, EventType::KeyDown) => true,
         (VirtualKeyUp, EventType::KeyUp) => true,
+        (VirtualKeyRepeat, EventType::KeyRepeat) => true,
         (FocusReceived, EventType::Focus) => true,
         (FocusLost, EventType::Blur) => true,
+        (FocusVisible, EventType::FocusVisible) => true,
         (DragStart, EventType::DragStart) => true,
         (Drag, EventType::Drag) => true,
         (DragEnd, EventType::DragEnd) => true,
@@ -1285,16 +1408,28 @@ fn matches_window_filter(
         (MouseEnter, EventType::MouseEnter) => true,
         (MouseLeave, EventType::MouseLeave) => true,
         (Scroll, EventType::Scroll) => true,
+        (ScrollHorizontal, EventType::Scroll) => scroll_delta(event).map_or(false, |d| d.x != 0.0),
+        (ScrollVertical, EventType::Scroll) => scroll_delta(event).map_or(false, |d| d.y != 0.0),
         (ScrollStart, EventType::ScrollStart) => true,
         (ScrollEnd, EventType::ScrollEnd) => true,
         (TextInput, EventType::Input) => true,
         (VirtualKeyDown, EventType::KeyDown) => true,
         (VirtualKeyUp, EventType::KeyUp) => true,
+        (VirtualKeyRepeat, EventType::KeyRepeat) => true,
         (HoveredFile, EventType::FileHover) => true,
         (DroppedFile, EventType::FileDrop) => true,
         (HoveredFileCancelled, EventType::FileHoverCancel) => true,
         (Resized, EventType::WindowResize) => true,
         (Moved, EventType::WindowMove) => true,
+        (MouseButtonsChanged, EventType::MouseButtonsChange) => true,
+        (CompositionStart, EventType::CompositionStart) => true,
+        (CompositionUpdate, EventType::CompositionUpdate) => true,
+        (CompositionEnd, EventType::CompositionEnd) => true,
+        (Copy, EventType::Copy) => true,
+        (Cut, EventType::Cut) => true,
+        (Paste, EventType::Paste) => true,
+        (Magnify, EventType::Magnify) => true,
+        (Rotate, EventType::Rotate) => true,
         (TouchStart, EventType::TouchStart) => true,
         (TouchMove, EventType::TouchMove) => true,
         (TouchEnd, EventType::TouchEnd) => true,
@@ -1431,7 +1566,7 @@ fn create_unmount_event(
         dom_id,
         timestamp,
         LifecycleEventData {
-            reason: LifecycleReason::InitialMount,
+            reason: LifecycleReason::Unmount,
             previous_bounds: Some(previous_bounds),
             current_bounds: LogicalRect::zero(),
         },
@@ -1671,6 +1806,15 @@ pub enum HoverEventFilter {
     /// IME composition ended (W3C `compositionend`)
     CompositionEnd,
 
+    /// Triple-click detected on the hovered element
+    TripleClick,
+    /// Scroll with a non-zero horizontal delta on the hovered element
+    ScrollHorizontal,
+    /// Scroll with a non-zero vertical delta on the hovered element
+    ScrollVertical,
+    /// Virtual key repeated while element is hovered
+    VirtualKeyRepeat,
+
     // Internal System Events (not exposed to user callbacks)
     #[doc(hidden)]
     /// Internal: Single click for text cursor placement
@@ -1750,6 +1894,10 @@ impl HoverEventFilter {
             HoverEventFilter::CompositionStart => Some(FocusEventFilter::CompositionStart),
             HoverEventFilter::CompositionUpdate => Some(FocusEventFilter::CompositionUpdate),
             HoverEventFilter::CompositionEnd => Some(FocusEventFilter::CompositionEnd),
+            HoverEventFilter::TripleClick => None,
+            HoverEventFilter::ScrollHorizontal => None,
+            HoverEventFilter::ScrollVertical => None,
+            HoverEventFilter::VirtualKeyRepeat => Some(FocusEventFilter::VirtualKeyRepeat),
             // System internal events - don't convert to focus events
             HoverEventFilter::SystemTextSingleClick => None,
             HoverEventFilter::SystemTextDoubleClick => None,
@@ -1803,6 +1951,9 @@ pub enum FocusEventFilter {
     FocusReceived,
     /// Element lost keyboard focus
     FocusLost,
+    /// Element received focus through keyboard navigation (e.g. Tab), but not
+    /// through a mouse click. Use this to draw focus rings (CSS `:focus-visible`).
+    FocusVisible,
     /// Pen/stylus made contact on the focused element
     PenDown,
     /// Pen/stylus moved while in contact on the focused element
@@ -1857,6 +2008,8 @@ pub enum FocusEventFilter {
     CompositionUpdate,
     /// IME composition ended (W3C `compositionend`)
     CompositionEnd,
+    /// Virtual key repeated while element is focused
+    VirtualKeyRepeat,
 }
 
 /// Event filter that fires when any action fires on the entire window
@@ -1978,6 +2131,37 @@ pub enum WindowEventFilter {
     /// The window moved to a different monitor. The new monitor is available
     /// via `CallbackInfo::get_current_monitor()`.
     MonitorChanged,
+    /// The combination of held mouse buttons changed (e.g. left+right chord).
+    /// The new mask is available via `CallbackInfo::get_mouse_buttons()`.
+    MouseButtonsChanged,
+    /// IME composition started anywhere in window
+    CompositionStart,
+    /// IME preedit string changed anywhere in window, available via
+    /// `CallbackInfo::get_ime_composition()`
+    CompositionUpdate,
+    /// IME composition was committed or cancelled anywhere in window
+    CompositionEnd,
+    /// Platform copy shortcut (Ctrl+C, Cmd+C on macOS) pressed while a node
+    /// has focus, targeted at the focused node
+    Copy,
+    /// Platform cut shortcut (Ctrl+X, Cmd+X on macOS), see `Copy`
+    Cut,
+    /// Platform paste shortcut (Ctrl+V, Cmd+V on macOS), see `Copy`
+    Paste,
+    /// Trackpad magnify gesture began, changed or ended. The per-update delta
+    /// and the cumulative scale are in `TrackpadGestureState`
+    /// (see `CallbackInfo::get_trackpad_gesture_state`)
+    Magnify,
+    /// Trackpad rotate gesture began, changed or ended, see `Magnify`
+    Rotate,
+    /// Triple-click detected anywhere in window
+    TripleClick,
+    /// Scroll with a non-zero horizontal delta anywhere in window
+    ScrollHorizontal,
+    /// Scroll with a non-zero vertical delta anywhere in window
+    ScrollVertical,
+    /// Virtual key held down long enough to repeat, see `KeyRepeatState`
+    VirtualKeyRepeat,
 }
 
 impl WindowEventFilter {
@@ -2044,6 +2228,19 @@ impl WindowEventFilter {
             // Window-specific events with no hover equivalent
             WindowEventFilter::DpiChanged => None,
             WindowEventFilter::MonitorChanged => None,
+            WindowEventFilter::MouseButtonsChanged => None,
+            WindowEventFilter::CompositionStart => Some(HoverEventFilter::CompositionStart),
+            WindowEventFilter::CompositionUpdate => Some(HoverEventFilter::CompositionUpdate),
+            WindowEventFilter::CompositionEnd => Some(HoverEventFilter::CompositionEnd),
+            WindowEventFilter::Copy => None,
+            WindowEventFilter::Cut => None,
+            WindowEventFilter::Paste => None,
+            WindowEventFilter::Magnify => None,
+            WindowEventFilter::Rotate => None,
+            WindowEventFilter::TripleClick => Some(HoverEventFilter::TripleClick),
+            WindowEventFilter::ScrollHorizontal => Some(HoverEventFilter::ScrollHorizontal),
+            WindowEventFilter::ScrollVertical => Some(HoverEventFilter::ScrollVertical),
+            WindowEventFilter::VirtualKeyRepeat => Some(HoverEventFilter::VirtualKeyRepeat),
         }
     }
 }
@@ -2129,6 +2326,11 @@ pub enum EventFilter {
     Component(ComponentEventFilter),
     /// Something happened with the application (started, shutdown, device plugged in).
     Application(ApplicationEventFilter),
+    /// Same event as `Hover`, but the callback runs in the capture phase: on
+    /// the way from the root down to the target, before any bubbling callback.
+    /// This lets a parent intercept an event (e.g. with `stop_propagation`)
+    /// before its children see it.
+    Capture(HoverEventFilter),
 }
 
 impl EventFilter {
@@ -2162,6 +2364,7 @@ macro_rules! get_single_enum_type {
 
 impl EventFilter {
     get_single_enum_type!(as_hover_event_filter, EventFilter::Hover(HoverEventFilter));
+    get_single_enum_type!(as_capture_event_filter, EventFilter::Capture(HoverEventFilter));
     get_single_enum_type!(as_focus_event_filter, EventFilter::Focus(FocusEventFilter));
     get_single_enum_type!(as_not_event_filter, EventFilter::Not(NotEventFilter));
     get_single_enum_type!(
@@ -2243,32 +2446,38 @@ pub trait EventProvider {
 /// Deduplicate synthetic events by (target node, event type).
 ///
 /// Groups by (target.dom, target.node, event_type), keeping the latest timestamp.
-pub fn deduplicate_synthetic_events(mut events: Vec<SyntheticEvent>) -> Vec<SyntheticEvent> {
+/// Mouse events are additionally grouped by button, so pressing two buttons in
+/// the same frame still dispatches one `MouseDown` per button.
+/// The result keeps the order in which events were first emitted, so sequences
+/// that must be dispatched in order (e.g. `Blur` on the old node before `Focus`
+/// on the new one) survive deduplication.
+pub fn deduplicate_synthetic_events(events: Vec<SyntheticEvent>) -> Vec<SyntheticEvent> {
     if events.len() <= 1 {
         return events;
     }
 
-    events.sort_by_key(|e| (e.target.dom, e.target.node, e.event_type));
+    let mut result: Vec<SyntheticEvent> = Vec::with_capacity(events.len());
+    let mut seen = BTreeMap::new();
 
-    // Coalesce consecutive events with same target and event_type
-    let mut result = Vec::with_capacity(events.len());
-    let mut iter = events.into_iter();
-
-    if let Some(mut prev) = iter.next() {
-        for curr in iter {
-            if prev.target == curr.target && prev.event_type == curr.event_type {
-                // Keep the one with later timestamp
-                prev = if curr.timestamp > prev.timestamp {
-                    curr
-                } else {
-                    prev
-                };
-            } else {
-                result.push(prev);
-                prev = curr;
+    for event in events {
+        let button = match &event.data {
+            EventData::Mouse(mouse) => Some(mouse.button),
+            _ => None,
+        };
+        let key = (event.target.dom, event.target.node, event.event_type, button);
+        match seen.get(&key) {
+            Some(&index) => {
+                // Keep the one with later timestamp, at the first position
+                let prev: &mut SyntheticEvent = &mut result[index];
+                if event.timestamp > prev.timestamp {
+                    *prev = event;
+                }
+            }
+            None => {
+                seen.insert(key, result.len());
+                result.push(event);
             }
         }
-        result.push(prev);
     }
 
     result
@@ -2335,21 +2544,36 @@ pub fn event_type_to_filters(event_type: EventType, event_data: &EventData) -> V
         E::MouseOut => vec![EF::Hover(H::MouseOut)],
 
         E::DoubleClick => vec![EF::Hover(H::DoubleClick), EF::Window(W::DoubleClick)],
+        E::TripleClick => vec![EF::Hover(H::TripleClick), EF::Window(W::TripleClick)],
         E::ContextMenu => vec![EF::Hover(H::RightMouseDown)],
 
         // Keyboard events
         E::KeyDown => vec![EF::Focus(F::VirtualKeyDown)],
         E::KeyUp => vec![EF::Focus(F::VirtualKeyUp)],
+        E::KeyRepeat => vec![EF::Focus(F::VirtualKeyRepeat), EF::Window(W::VirtualKeyRepeat)],
         E::KeyPress => vec![EF::Focus(F::TextInput)],
 
         // IME Composition events
-        E::CompositionStart => vec![EF::Hover(H::CompositionStart), EF::Focus(F::CompositionStart)],
-        E::CompositionUpdate => vec![EF::Hover(H::CompositionUpdate), EF::Focus(F::CompositionUpdate)],
-        E::CompositionEnd => vec![EF::Hover(H::CompositionEnd), EF::Focus(F::CompositionEnd)],
+        E::CompositionStart => vec![
+            EF::Hover(H::CompositionStart),
+            EF::Focus(F::CompositionStart),
+            EF::Window(W::CompositionStart),
+        ],
+        E::CompositionUpdate => vec![
+            EF::Hover(H::CompositionUpdate),
+            EF::Focus(F::CompositionUpdate),
+            EF::Window(W::CompositionUpdate),
+        ],
+        E::CompositionEnd => vec![
+            EF::Hover(H::CompositionEnd),
+            EF::Focus(F::CompositionEnd),
+            EF::Window(W::CompositionEnd),
+        ],
 
         // Focus events
         E::Focus => vec![EF::Focus(F::FocusReceived)],
         E::Blur => vec![EF::Focus(F::FocusLost)],
+        E::FocusVisible => vec![EF::Focus(F::FocusVisible)],
         E::FocusIn => vec![EF::Hover(H::FocusIn), EF::Focus(F::FocusIn)],
         E::FocusOut => vec![EF::Hover(H::FocusOut), EF::Focus(F::FocusOut)],
 
@@ -2357,7 +2581,22 @@ pub fn event_type_to_filters(event_type: EventType, event_data: &EventData) -> V
         E::Input | E::Change => vec![EF::Focus(F::TextInput)],
 
         // Scroll events
-        E::Scroll | E::ScrollStart | E::ScrollEnd => vec![EF::Hover(H::Scroll)],
+        // Scroll keeps the combined filter, plus one filter per axis that moved
+        E::Scroll => {
+            let mut v = vec![EF::Hover(H::Scroll)];
+            if let EventData::Scroll(scroll) = event_data {
+                if scroll.delta.x != 0.0 {
+                    v.push(EF::Hover(H::ScrollHorizontal));
+                    v.push(EF::Window(W::ScrollHorizontal));
+                }
+                if scroll.delta.y != 0.0 {
+                    v.push(EF::Hover(H::ScrollVertical));
+                    v.push(EF::Window(W::ScrollVertical));
+                }
+            }
+            v
+        }
+        E::ScrollStart | E::ScrollEnd => vec![EF::Hover(H::Scroll)],
 
         // Drag events
         E::DragStart => vec![EF::Hover(H::DragStart), EF::Window(W::DragStart)],
@@ -2383,11 +2622,26 @@ pub fn event_type_to_filters(event_type: EventType, event_data: &EventData) -> V
         E::ThemeChange => vec![EF::Window(W::ThemeChanged)],
         E::WindowDpiChanged => vec![EF::Window(W::DpiChanged)],
         E::WindowMonitorChanged => vec![EF::Window(W::MonitorChanged)],
+        E::MouseButtonsChange => vec![EF::Window(W::MouseButtonsChanged)],
+
+        // Clipboard shortcuts
+        E::Copy => vec![EF::Window(W::Copy)],
+        E::Cut => vec![EF::Window(W::Cut)],
+        E::Paste => vec![EF::Window(W::Paste)],
+
+        // Trackpad gestures
+        E::Magnify => vec![EF::Window(W::Magnify)],
+        E::Rotate => vec![EF::Window(W::Rotate)],
 
         // Application events
         E::MonitorConnected => vec![EF::Application(ApplicationEventFilter::MonitorConnected)],
         E::MonitorDisconnected => vec![EF::Application(ApplicationEventFilter::MonitorDisconnected)],
 
+        // Component lifecycle events (target-only, see dispatch)
+        E::Mount => vec![EF::Component(ComponentEventFilter::AfterMount)],
+        E::Unmount => vec![EF::Component(ComponentEventFilter::BeforeUnmount)],
+        E::Resize => vec![EF::Component(ComponentEventFilter::NodeResized)],
+
         // File events
         E::FileHover => vec![EF::Hover(H::HoveredFile)],
         E::FileDrop => vec![EF::Hover(H::DroppedFile)],
@@ -50,7 +50,7 @@ use crate::{
 /// * `scrollbar_h_opacity_keys` - Maps (DomId, NodeId) to horizontal scrollbar opacity keys
 /// * `scrollbar_v_opacity_values` - Current vertical scrollbar opacity values
 /// * `scrollbar_h_opacity_values` - Current horizontal scrollbar opacity values
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct GpuValueCache {
     /// Vertical scrollbar thumb transform keys (keyed by scrollable node ID)
     pub transform_keys: HashMap<NodeId, TransformKey>,
@@ -73,6 +73,12 @@ pub struct GpuValueCache {
     pub scrollbar_h_opacity_values: HashMap<(DomId, NodeId), f32>,
 }
 
+/// Opaque copy of a [`GpuValueCache`], created by [`GpuValueCache::snapshot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpuValueCacheSnapshot {
+    cache: GpuValueCache,
+}
+
 /// Represents a change to a GPU transform key.
 ///
 /// These events are generated when synchronizing the cache with the `StyledDom`
@@ -98,6 +104,23 @@ impl GpuValueCache {
         Self::default()
     }
 
+    /// Captures the complete key and value state of the cache.
+    ///
+    /// Used together with [`GpuValueCache::restore`] to roll back a frame whose
+    /// GPU updates were discarded (e.g. because the frame was never submitted).
+    pub fn snapshot(&self) -> GpuValueCacheSnapshot {
+        GpuValueCacheSnapshot {
+            cache: self.clone(),
+        }
+    }
+
+    /// Restores the cache to the exact state captured by [`GpuValueCache::snapshot`].
+    ///
+    /// Any keys added, changed or removed since the snapshot was taken are reverted.
+    pub fn restore(&mut self, snapshot: GpuValueCacheSnapshot) {
+        *self = snapshot.cache;
+    }
+
     /// Synchronizes the cache with the current `StyledDom`, generating change events.
     ///
     /// This method:
@@ -147,9 +170,10 @@ impl GpuValueCache {
                 let node_id = NodeId::new(node_id);
                 let styled_node_state = &node_states[node_id].styled_node_state;
                 let node_data = &node_data[node_id];
+                // no `?` here: a node whose transform was removed still has to emit `Removed`
                 let current_transform = css_property_cache
-                    .get_transform(node_data, &node_id, styled_node_state)?
-                    .get_property()
+                    .get_transform(node_data, &node_id, styled_node_state)
+                    .and_then(|t| t.get_property())
                     .map(|t| {
                         // TODO: look up the parent nodes size properly to resolve animation of
                         // transforms with %
@@ -183,6 +207,7 @@ impl GpuValueCache {
                         TransformKey::unique(),
                         new,
                     )),
+                    (Some(old), Some(new)) if *old == new => None,
                     (Some(old), Some(new)) => Some(GpuTransformKeyEvent::Changed(
                         node_id,
                         self.css_transform_keys.get(&node_id).copied()?,
@@ -221,9 +246,11 @@ impl GpuValueCache {
                 let node_id = NodeId::new(node_id);
                 let styled_node_state = &node_states[node_id].styled_node_state;
                 let node_data = &node_data[node_id];
-                let current_opacity =
-                    css_property_cache.get_opacity(node_data, &node_id, styled_node_state)?;
-                let current_opacity = current_opacity.get_property();
+                let current_opacity = css_property_cache
+                    .get_opacity(node_data, &node_id, styled_node_state)
+                    .and_then(|o| o.get_property())
+                    // animations may overshoot, the GPU expects 0.0..=1.0
+                    .map(|o| o.inner.normalized().clamp(0.0, 1.0));
                 let existing_opacity = self.current_opacity_values.get(&node_id);
 
                 match (existing_opacity, current_opacity) {
@@ -231,13 +258,14 @@ impl GpuValueCache {
                     (None, Some(new)) => Some(GpuOpacityKeyEvent::Added(
                         node_id,
                         OpacityKey::unique(),
-                        new.inner.normalized(),
+                        new,
                     )),
+                    (Some(old), Some(new)) if *old == new => None,
                     (Some(old), Some(new)) => Some(GpuOpacityKeyEvent::Changed(
                         node_id,
                         self.opacity_keys.get(&node_id).copied()?,
                         *old,
-                        new.inner.normalized(),
+                        new,
                     )),
                     (Some(_old), None) => Some(GpuOpacityKeyEvent::Removed(
                         node_id,
@@ -264,10 +292,19 @@ impl GpuValueCache {
             }
         }
 
+        let mut transparent_nodes = self
+            .current_opacity_values
+            .iter()
+            .filter(|(_, opacity)| **opacity == 0.0)
+            .map(|(node_id, _)| *node_id)
+            .collect::<Vec<_>>();
+        transparent_nodes.sort();
+
         GpuEventChanges {
             transform_key_changes: all_current_transform_events,
             opacity_key_changes: all_current_opacity_events,
             scrollbar_opacity_changes: Vec::new(), // Filled by separate synchronization
+            transparent_nodes,
         }
     }
 }
@@ -304,6 +341,9 @@ pub struct GpuEventChanges {
     pub opacity_key_changes: Vec<GpuOpacityKeyEvent>,
     /// All scrollbar opacity key changes (additions, modifications, removals)
     pub scrollbar_opacity_changes: Vec<GpuScrollbarOpacityEvent>,
+    /// Nodes whose CSS opacity is currently exactly 0, the renderer can skip
+    /// drawing them. This is state, not a change, so `is_empty` ignores it.
+    pub transparent_nodes: Vec<NodeId>,
 }
 
 impl GpuEventChanges {
@@ -329,6 +369,8 @@ impl GpuEventChanges {
             .extend(other.opacity_key_changes.drain(..));
         self.scrollbar_opacity_changes
             .extend(other.scrollbar_opacity_changes.drain(..));
+        self.transparent_nodes
+            .extend(other.transparent_nodes.drain(..));
     }
 }
 
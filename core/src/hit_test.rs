@@ -50,6 +50,14 @@ impl HitTest {
             && self.scrollbar_hit_test_nodes.is_empty()
             && self.cursor_hit_test_nodes.is_empty()
     }
+
+    /// Returns true if the cursor is over a scrollbar thumb or track.
+    ///
+    /// Such hits should be routed to scroll handling instead of the
+    /// content underneath the scrollbar.
+    pub fn is_scrollbar_hit(&self) -> bool {
+        !self.scrollbar_hit_test_nodes.is_empty()
+    }
 }
 
 /// NEW: Unique identifier for a specific component of a scrollbar.
@@ -62,6 +70,16 @@ pub enum ScrollbarHitId {
     HorizontalThumb(DomId, NodeId),
 }
 
+impl ScrollbarHitId {
+    /// Returns true if this is the draggable thumb (as opposed to the track).
+    pub fn is_thumb(&self) -> bool {
+        matches!(
+            self,
+            ScrollbarHitId::VerticalThumb(..) | ScrollbarHitId::HorizontalThumb(..)
+        )
+    }
+}
+
 /// Hit test item specifically for scrollbar components.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
@@ -122,6 +140,19 @@ impl Default for OverflowingScrollNode {
     }
 }
 
+impl OverflowingScrollNode {
+    /// Returns whether both nodes describe the same scroll frame, ignoring the
+    /// rects, which may jitter by sub-pixel amounts between re-layouts.
+    ///
+    /// Use this instead of `==` when deciding whether scroll state from the
+    /// previous frame should be kept.
+    pub fn same_identity(&self, other: &Self) -> bool {
+        self.parent_external_scroll_id == other.parent_external_scroll_id
+            && self.parent_dom_hash == other.parent_dom_hash
+            && self.scroll_tag_id == other.scroll_tag_id
+    }
+}
+
 /// This type carries no valuable semantics for WR. However, it reflects the fact that
 /// clients (Servo) may generate pipelines by different semi-independent sources.
 ///
@@ -97,6 +97,20 @@ impl_vec_clone!(
 );
 impl_vec_partialeq!(ChangedCssProperty, ChangedCssPropertyVec);
 
+/// Removes repeated properties of the same type, keeping the last value
+/// at the position where the property type first appeared.
+pub fn coalesce_css_properties(properties: &[CssProperty]) -> Vec<CssProperty> {
+    let mut coalesced: Vec<CssProperty> = Vec::with_capacity(properties.len());
+    for prop in properties {
+        let prop_type = prop.get_type();
+        match coalesced.iter_mut().find(|p| p.get_type() == prop_type) {
+            Some(existing) => *existing = prop.clone(),
+            None => coalesced.push(prop.clone()),
+        }
+    }
+    coalesced
+}
+
 /// Focus state change for restyle operations
 #[derive(Debug, Clone, PartialEq)]
 pub struct FocusChange {
@@ -125,7 +139,7 @@ pub struct ActiveChange {
 }
 
 /// Result of a restyle operation, indicating what needs to be updated
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct RestyleResult {
     /// Nodes whose CSS properties changed, with details of the changes
     pub changed_nodes: BTreeMap<NodeId, Vec<ChangedCssProperty>>,
@@ -1858,7 +1872,20 @@ impl StyledDom {
         hover_changes: Option<HoverChange>,
         active_changes: Option<ActiveChange>,
     ) -> RestyleResult {
-        
+        // Fast path for idle frames: nothing to restyle
+        let no_focus_change = focus_changes
+            .as_ref()
+            .map_or(true, |f| f.lost_focus.is_none() && f.gained_focus.is_none());
+        let no_hover_change = hover_changes
+            .as_ref()
+            .map_or(true, |h| h.left_nodes.is_empty() && h.entered_nodes.is_empty());
+        let no_active_change = active_changes
+            .as_ref()
+            .map_or(true, |a| a.deactivated.is_empty() && a.activated.is_empty());
+        if no_focus_change && no_hover_change && no_active_change {
+            return RestyleResult::default();
+        }
+
         let mut result = RestyleResult::default();
         result.gpu_only_changes = true; // Start with GPU-only assumption
 
@@ -1965,6 +1992,11 @@ impl StyledDom {
             return map;
         }
 
+        // If the same property is set multiple times (e.g. by several callbacks),
+        // only the last value is applied and reported
+        let new_properties = coalesce_css_properties(new_properties);
+        let new_properties = new_properties.as_slice();
+
         let node_data = self.node_data.as_container();
         let node_data = &node_data[*node_id];
 
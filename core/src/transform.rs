@@ -15,7 +15,7 @@ use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
 use azul_css::props::style::{StyleTransform, StyleTransformOrigin};
 
-use crate::geom::LogicalPosition;
+use crate::geom::{LogicalPosition, LogicalRect, LogicalSize};
 
 /// CPU feature detection: true if initialization has been performed
 pub static INITIALIZED: AtomicBool = AtomicBool::new(false);
@@ -245,7 +245,8 @@ impl ComputedTransform3D {
             + self.m[0][0] * self.m[1][1] * self.m[2][2] * self.m[3][3]
     }
 
-    fn multiply_scalar(&self, x: f32) -> Self {
+    /// Multiplies every element of the matrix by `x`.
+    pub fn multiply_scalar(&self, x: f32) -> Self {
         ComputedTransform3D::new(
             self.m[0][0] * x,
             self.m[0][1] * x,
@@ -576,11 +577,47 @@ impl ComputedTransform3D {
         )
     }
 
+    /// Create a 3d rotation transform from a unit quaternion `(x, y, z, w)`,
+    /// laid out like [`new_rotation`](Self::new_rotation): the quaternion of a
+    /// rotation by `theta` around the axis `(ax, ay, az)` gives the same matrix.
+    #[inline]
+    pub fn from_quaternion(x: f32, y: f32, z: f32, w: f32) -> Self {
+        let mut m = Self::IDENTITY.m;
+        for (out, row) in m.iter_mut().zip(quaternion_rotation([x, y, z, w]).iter()) {
+            out[..3].copy_from_slice(row);
+        }
+        Self { m }
+    }
+
+    /// Extracts the rotation of the matrix as a unit quaternion `(x, y, z, w)`,
+    /// ignoring translation.
+    ///
+    /// Returns `None` if the rotation cannot be separated: for singular
+    /// matrices and for non-uniform scale or skew.
+    pub fn to_quaternion(&self) -> Option<(f32, f32, f32, f32)> {
+        const EPSILON: f32 = 1e-4;
+        let d = self.decompose()?;
+        let uniform =
+            (d.scale[0] - d.scale[1]).abs() < EPSILON && (d.scale[0] - d.scale[2]).abs() < EPSILON;
+        let skewed = d.skew.iter().any(|s| s.abs() > EPSILON);
+        if !uniform || skewed {
+            return None;
+        }
+        let [x, y, z, w] = d.quaternion;
+        Some((x, y, z, w))
+    }
+
+    /// Create a 2d skew transform, `alpha` / `beta` being the CSS
+    /// `skew(alpha, beta)` angles in degrees.
+    ///
+    /// Points are row vectors (`p * M`), so `x' = x + tan(alpha) * y` comes from
+    /// `m[1][0]` and `y' = tan(beta) * x + y` from `m[0][1]`.
     #[inline]
     pub fn new_skew(alpha: f32, beta: f32) -> Self {
-        let (sx, sy) = (beta.to_radians().tan(), alpha.to_radians().tan());
+        let (tan_alpha, tan_beta) = (alpha.to_radians().tan(), beta.to_radians().tan());
         Self::new(
-            1.0, sx, 0.0, 0.0, sy, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+            1.0, tan_beta, 0.0, 0.0, tan_alpha, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+            1.0,
         )
     }
 
@@ -605,13 +642,271 @@ impl ComputedTransform3D {
         )
     }
 
-    // Transforms a 2D point into the target coordinate space
+    /// Flattens the matrix into the column-major array layout expected by
+    /// OpenGL (`glUniformMatrix4fv` with `transpose = GL_FALSE`).
+    ///
+    /// Points are transformed as row vectors (`p * M`, translation in `m[3]`),
+    /// while GL multiplies column vectors (`M^T * p`). Storing `M^T`
+    /// column-major is the same as storing `M` row-major, so the translation
+    /// ends up at indices 12, 13 and 14 as GL expects.
+    pub fn to_column_major_array(&self) -> [f32; 16] {
+        let mut arr = [0.0; 16];
+        for (row, values) in self.m.iter().enumerate() {
+            arr[row * 4..row * 4 + 4].copy_from_slice(values);
+        }
+        arr
+    }
+
+    /// Inverse of [`to_column_major_array`](Self::to_column_major_array).
+    pub fn from_column_major_array(arr: [f32; 16]) -> Self {
+        let mut m = [[0.0; 4]; 4];
+        for (row, values) in m.iter_mut().enumerate() {
+            values.copy_from_slice(&arr[row * 4..row * 4 + 4]);
+        }
+        Self { m }
+    }
+
+    /// Divides the whole matrix by `m[3][3]`, so that composed transforms
+    /// whose w component drifted from 1.0 no longer scale everything.
+    ///
+    /// Returns `None` if `m[3][3]` is zero.
+    pub fn normalize(&self) -> Option<Self> {
+        let w = self.m[3][3];
+        if w == 0.0 {
+            return None;
+        }
+        let mut m = self.m;
+        for value in m.iter_mut().flatten() {
+            *value /= w;
+        }
+        Some(Self { m })
+    }
+
+    /// Returns whether the matrix is a pure 2D affine transform, i.e. it only
+    /// has the six components of CSS `matrix(a, b, c, d, e, f)`.
+    pub fn is_2d(&self) -> bool {
+        self.m[0][2] == 0.0
+            && self.m[0][3] == 0.0
+            && self.m[1][2] == 0.0
+            && self.m[1][3] == 0.0
+            && self.m[2][0] == 0.0
+            && self.m[2][1] == 0.0
+            && self.m[2][2] == 1.0
+            && self.m[2][3] == 0.0
+            && self.m[3][2] == 0.0
+            && self.m[3][3] == 1.0
+    }
+
+    /// Interpolates between two 2D affine transforms without a full 3D
+    /// decomposition.
+    ///
+    /// Both matrices are split into translation, scale, skew and rotation
+    /// angle; translation, scale and skew are interpolated linearly, the angle
+    /// along the shortest path. Returns `None` if either matrix is not
+    /// `is_2d()` or is singular, in which case the caller has to fall back to
+    /// a full 3D interpolation.
+    pub fn lerp_2d(&self, other: &Self, t: f32) -> Option<Self> {
+        let a = Decomposed2d::from_matrix(self)?;
+        let b = Decomposed2d::from_matrix(other)?;
+
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+        let mut angle_delta = b.angle - a.angle;
+        if angle_delta > core::f32::consts::PI {
+            angle_delta -= 2.0 * core::f32::consts::PI;
+        } else if angle_delta < -core::f32::consts::PI {
+            angle_delta += 2.0 * core::f32::consts::PI;
+        }
+
+        Some(
+            Decomposed2d {
+                translate: (
+                    lerp(a.translate.0, b.translate.0),
+                    lerp(a.translate.1, b.translate.1),
+                ),
+                scale: (lerp(a.scale.0, b.scale.0), lerp(a.scale.1, b.scale.1)),
+                skew: lerp(a.skew, b.skew),
+                angle: a.angle + angle_delta * t,
+            }
+            .to_matrix(),
+        )
+    }
+
+    /// Splits the matrix into translation, scale, skew, rotation and
+    /// perspective, following the "Decomposing a 3D matrix" algorithm of
+    /// CSS Transforms Level 2.
+    ///
+    /// Returns `None` for singular matrices, which cannot be interpolated.
+    pub fn decompose(&self) -> Option<DecomposedTransform> {
+        let mut matrix = self.normalize()?;
+
+        // Upper 3x4 without the perspective column
+        let mut perspective_matrix = matrix;
+        for row in perspective_matrix.m.iter_mut().take(3) {
+            row[3] = 0.0;
+        }
+        perspective_matrix.m[3][3] = 1.0;
+        if perspective_matrix.determinant() == 0.0 {
+            return None;
+        }
+
+        let perspective = if matrix.m[0][3] != 0.0 || matrix.m[1][3] != 0.0 || matrix.m[2][3] != 0.0
+        {
+            // Solve for the perspective column: M = perspective_matrix * P
+            let rhs = [
+                matrix.m[0][3],
+                matrix.m[1][3],
+                matrix.m[2][3],
+                matrix.m[3][3],
+            ];
+            let inverse = perspective_matrix.inverse();
+            let mut perspective = [0.0; 4];
+            for (i, p) in perspective.iter_mut().enumerate() {
+                *p = (0..4).map(|j| inverse.m[i][j] * rhs[j]).sum();
+            }
+            for row in matrix.m.iter_mut() {
+                row[3] = 0.0;
+            }
+            matrix.m[3][3] = 1.0;
+            perspective
+        } else {
+            [0.0, 0.0, 0.0, 1.0]
+        };
+
+        let translate = [matrix.m[3][0], matrix.m[3][1], matrix.m[3][2]];
+
+        let mut row = [[0.0; 3]; 3];
+        for (i, r) in row.iter_mut().enumerate() {
+            r.copy_from_slice(&matrix.m[i][..3]);
+        }
+
+        let mut scale = [0.0; 3];
+        let mut skew = [0.0; 3];
+
+        scale[0] = vec3_length(row[0]);
+        row[0] = vec3_scale(row[0], 1.0 / scale[0]);
+
+        skew[0] = vec3_dot(row[0], row[1]);
+        row[1] = vec3_combine(row[1], row[0], -skew[0]);
+        scale[1] = vec3_length(row[1]);
+        row[1] = vec3_scale(row[1], 1.0 / scale[1]);
+        skew[0] /= scale[1];
+
+        skew[1] = vec3_dot(row[0], row[2]);
+        row[2] = vec3_combine(row[2], row[0], -skew[1]);
+        skew[2] = vec3_dot(row[1], row[2]);
+        row[2] = vec3_combine(row[2], row[1], -skew[2]);
+        scale[2] = vec3_length(row[2]);
+        row[2] = vec3_scale(row[2], 1.0 / scale[2]);
+        skew[1] /= scale[2];
+        skew[2] /= scale[2];
+
+        // Flip everything if the coordinate system is mirrored
+        if vec3_dot(row[0], vec3_cross(row[1], row[2])) < 0.0 {
+            for (s, r) in scale.iter_mut().zip(row.iter_mut()) {
+                *s = -*s;
+                *r = vec3_scale(*r, -1.0);
+            }
+        }
+
+        let quaternion = rotation_to_quaternion(&row);
+
+        Some(DecomposedTransform {
+            translate,
+            scale,
+            skew,
+            perspective,
+            quaternion,
+        })
+    }
+
+    /// Builds the matrix back from the output of [`decompose`](Self::decompose).
+    pub fn recompose(d: &DecomposedTransform) -> Self {
+        let rotation = quaternion_rotation(d.quaternion);
+
+        // Rows are scale * (skew * rotation), see `decompose`
+        let rows = [
+            rotation[0],
+            vec3_combine(rotation[1], rotation[0], d.skew[0]),
+            vec3_combine(
+                vec3_combine(rotation[2], rotation[0], d.skew[1]),
+                rotation[1],
+                d.skew[2],
+            ),
+        ];
+
+        let mut m = Self::IDENTITY.m;
+        for ((out, row), scale) in m.iter_mut().zip(rows.iter()).zip(d.scale.iter()) {
+            out[..3].copy_from_slice(&vec3_scale(*row, *scale));
+        }
+        m[3][..3].copy_from_slice(&d.translate);
+
+        let mut perspective = Self::IDENTITY;
+        for (i, p) in d.perspective.iter().enumerate() {
+            perspective.m[i][3] = *p;
+        }
+
+        Self { m }.then(&perspective)
+    }
+
+    /// Interpolates between two transforms by decomposing both, spherically
+    /// interpolating the rotations and linearly interpolating everything else.
+    ///
+    /// If either matrix cannot be decomposed, snaps from `self` to `other`
+    /// at `t = 0.5`, as CSS does for non-invertible transforms.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let (a, b) = match (self.decompose(), other.decompose()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return if t < 0.5 { *self } else { *other },
+        };
+
+        let lerp3 = |from: [f32; 3], to: [f32; 3]| {
+            core::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+        };
+        let lerp4 = |from: [f32; 4], to: [f32; 4], from_weight: f32, to_weight: f32| {
+            core::array::from_fn(|i| from[i] * from_weight + to[i] * to_weight)
+        };
+
+        // Spherical interpolation, unless both rotations are the same
+        let product = a
+            .quaternion
+            .iter()
+            .zip(b.quaternion.iter())
+            .map(|(qa, qb)| qa * qb)
+            .sum::<f32>()
+            .clamp(-1.0, 1.0);
+        let quaternion = if product.abs() < 1.0 {
+            let theta = product.acos();
+            let w = (t * theta).sin() / (1.0 - product * product).sqrt();
+            lerp4(
+                a.quaternion,
+                b.quaternion,
+                (t * theta).cos() - product * w,
+                w,
+            )
+        } else {
+            a.quaternion
+        };
+
+        Self::recompose(&DecomposedTransform {
+            translate: lerp3(a.translate, b.translate),
+            scale: lerp3(a.scale, b.scale),
+            skew: lerp3(a.skew, b.skew),
+            perspective: lerp4(a.perspective, b.perspective, 1.0 - t, t),
+            quaternion,
+        })
+    }
+
+    /// Transforms a 2D point (z = 0) into the target coordinate space,
+    /// including the translation row and the perspective divide.
+    ///
+    /// Returns `None` if the point maps to infinity (`w == 0`).
     #[must_use]
     pub fn transform_point2d(&self, p: LogicalPosition) -> Option<LogicalPosition> {
         let w =
             p.x.mul_add(self.m[0][3], p.y.mul_add(self.m[1][3], self.m[3][3]));
 
-        if !w.is_sign_positive() {
+        if w == 0.0 {
             return None;
         }
 
@@ -623,6 +918,35 @@ impl ComputedTransform3D {
         Some(LogicalPosition { x: x / w, y: y / w })
     }
 
+    /// Axis-aligned bounding box of `rect` after transforming its four corners
+    /// with [`transform_point2d`](Self::transform_point2d), e.g. the screen area
+    /// covered by a rotated element.
+    ///
+    /// Returns `None` if any corner maps to infinity.
+    #[must_use]
+    pub fn transform_bounding_rect(&self, rect: LogicalRect) -> Option<LogicalRect> {
+        let LogicalRect { origin, size } = rect;
+        let corners = [
+            origin,
+            LogicalPosition::new(origin.x + size.width, origin.y),
+            LogicalPosition::new(origin.x, origin.y + size.height),
+            LogicalPosition::new(origin.x + size.width, origin.y + size.height),
+        ];
+
+        let mut min = LogicalPosition::new(f32::INFINITY, f32::INFINITY);
+        let mut max = LogicalPosition::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+        for corner in corners {
+            let p = self.transform_point2d(corner)?;
+            min = LogicalPosition::new(min.x.min(p.x), min.y.min(p.y));
+            max = LogicalPosition::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        Some(LogicalRect::new(
+            min,
+            LogicalSize::new(max.x - min.x, max.y - min.y),
+        ))
+    }
+
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         // only scale the translation, don't scale anything else
         self.m[3][0] *= scale_factor;
@@ -631,9 +955,26 @@ impl ComputedTransform3D {
     }
 
     /// Computes the sum of two matrices while applying `other` AFTER the current matrix.
+    ///
+    /// With the `simd` feature, dispatches to `then_sse` once CPU detection
+    /// found SSE support, otherwise uses `then_scalar`.
     #[must_use]
     #[inline]
     pub fn then(&self, other: &Self) -> Self {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        {
+            if INITIALIZED.load(AtomicOrdering::Relaxed) && USE_SSE.load(AtomicOrdering::Relaxed) {
+                // SAFETY: USE_SSE is only set if cpuid reports SSE support
+                return unsafe { self.then_sse(other) };
+            }
+        }
+        self.then_scalar(other)
+    }
+
+    /// Portable implementation of `then`.
+    #[must_use]
+    #[inline]
+    pub fn then_scalar(&self, other: &Self) -> Self {
         Self::new(
             self.m[0][0].mul_add(
                 other.m[0][0],
@@ -887,3 +1228,179 @@ impl ComputedTransform3D {
         pre_transform.then(&rotate_transform).then(&post_transform)
     }
 }
+
+/// A 2D affine transform split into its components, so that the linear part
+/// equals `scale * skew * rotation` (row-vector order: scale first).
+#[derive(Debug, Copy, Clone)]
+struct Decomposed2d {
+    translate: (f32, f32),
+    scale: (f32, f32),
+    /// Horizontal shear factor (x += skew * y)
+    skew: f32,
+    /// Rotation in radians
+    angle: f32,
+}
+
+impl Decomposed2d {
+    fn from_matrix(m: &ComputedTransform3D) -> Option<Self> {
+        if !m.is_2d() {
+            return None;
+        }
+
+        let (a, b) = (m.m[0][0], m.m[0][1]);
+        let (c, d) = (m.m[1][0], m.m[1][1]);
+
+        // First row is scale_x * (cos, sin)
+        let scale_x = a.hypot(b);
+        if scale_x <= f32::EPSILON {
+            return None;
+        }
+        let angle = b.atan2(a);
+        let (sin, cos) = angle.sin_cos();
+
+        // Second row is scale_y * (skew * (cos, sin) + (-sin, cos)),
+        // scale_y keeps the sign of the determinant
+        let scale_y = d * cos - c * sin;
+        if scale_y.abs() <= f32::EPSILON {
+            return None;
+        }
+        let skew = (c * cos + d * sin) / scale_y;
+
+        Some(Self {
+            translate: (m.m[3][0], m.m[3][1]),
+            scale: (scale_x, scale_y),
+            skew,
+            angle,
+        })
+    }
+
+    fn to_matrix(&self) -> ComputedTransform3D {
+        let (sin, cos) = self.angle.sin_cos();
+        let (scale_x, scale_y) = self.scale;
+        ComputedTransform3D::new_2d(
+            scale_x * cos,
+            scale_x * sin,
+            scale_y * (self.skew * cos - sin),
+            scale_y * (self.skew * sin + cos),
+            self.translate.0,
+            self.translate.1,
+        )
+    }
+}
+
+/// A 3D transform split into its components by [`ComputedTransform3D::decompose`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DecomposedTransform {
+    pub translate: [f32; 3],
+    pub scale: [f32; 3],
+    /// Shear factors XY, XZ and YZ
+    pub skew: [f32; 3],
+    /// Last column of the matrix, `[0, 0, 0, 1]` without perspective
+    pub perspective: [f32; 4],
+    /// Rotation as a unit quaternion `[x, y, z, w]`
+    pub quaternion: [f32; 4],
+}
+
+/// Rows of the 3x3 rotation matrix of the unit quaternion `[x, y, z, w]`
+fn quaternion_rotation([x, y, z, w]: [f32; 4]) -> [[f32; 3]; 3] {
+    [
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + z * w),
+            2.0 * (x * z - y * w),
+        ],
+        [
+            2.0 * (x * y - z * w),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + x * w),
+        ],
+        [
+            2.0 * (x * z + y * w),
+            2.0 * (y * z - x * w),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ]
+}
+
+fn vec3_dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Quaternion `[x, y, z, w]` (with `w >= 0`) of an orthonormal rotation matrix.
+///
+/// Uses Shepperd's method: the component with the largest magnitude is taken
+/// from the diagonal, the others from off-diagonal sums / differences divided
+/// by it. Extracting every component via `sqrt` instead loses most of the f32
+/// precision for components close to zero.
+fn rotation_to_quaternion(row: &[[f32; 3]; 3]) -> [f32; 4] {
+    let trace = row[0][0] + row[1][1] + row[2][2];
+    let q = if trace >= row[0][0] && trace >= row[1][1] && trace >= row[2][2] {
+        let w = 0.5 * (1.0 + trace).sqrt();
+        let s = 0.25 / w;
+        [
+            (row[1][2] - row[2][1]) * s,
+            (row[2][0] - row[0][2]) * s,
+            (row[0][1] - row[1][0]) * s,
+            w,
+        ]
+    } else if row[0][0] >= row[1][1] && row[0][0] >= row[2][2] {
+        let x = 0.5 * (1.0 + row[0][0] - row[1][1] - row[2][2]).sqrt();
+        let s = 0.25 / x;
+        [
+            x,
+            (row[0][1] + row[1][0]) * s,
+            (row[0][2] + row[2][0]) * s,
+            (row[1][2] - row[2][1]) * s,
+        ]
+    } else if row[1][1] >= row[2][2] {
+        let y = 0.5 * (1.0 - row[0][0] + row[1][1] - row[2][2]).sqrt();
+        let s = 0.25 / y;
+        [
+            (row[0][1] + row[1][0]) * s,
+            y,
+            (row[1][2] + row[2][1]) * s,
+            (row[2][0] - row[0][2]) * s,
+        ]
+    } else {
+        let z = 0.5 * (1.0 - row[0][0] - row[1][1] + row[2][2]).sqrt();
+        let s = 0.25 / z;
+        [
+            (row[0][2] + row[2][0]) * s,
+            (row[1][2] + row[2][1]) * s,
+            z,
+            (row[0][1] - row[1][0]) * s,
+        ]
+    };
+
+    // q and -q are the same rotation, keep w non-negative
+    if q[3] < 0.0 {
+        [-q[0], -q[1], -q[2], -q[3]]
+    } else {
+        q
+    }
+}
+
+fn vec3_length(a: [f32; 3]) -> f32 {
+    vec3_dot(a, a).sqrt()
+}
+
+fn vec3_scale(a: [f32; 3], s: f32) -> [f32; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+/// `a + b * b_scale`
+fn vec3_combine(a: [f32; 3], b: [f32; 3], b_scale: f32) -> [f32; 3] {
+    [
+        a[0] + b[0] * b_scale,
+        a[1] + b[1] * b_scale,
+        a[2] + b[2] * b_scale,
+    ]
+}
+
+fn vec3_cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
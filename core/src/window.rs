@@ -27,6 +27,7 @@ use rust_fontconfig::FcFontCache;
 use crate::{
     callbacks::{LayoutCallback, LayoutCallbackType, Update},
     dom::{DomId, DomNodeId, NodeHierarchy},
+    events::KeyModifiers,
     geom::{
         LogicalPosition, LogicalRect, LogicalSize, OptionLogicalSize, PhysicalPositionI32,
         PhysicalSize,
@@ -41,7 +42,7 @@ use crate::{
     },
     selection::SelectionState,
     styled_dom::NodeHierarchyItemId,
-    task::{Instant, ThreadId, TimerId},
+    task::{Duration, Instant, OptionInstant, ThreadId, TimerId},
     FastBTreeSet, FastHashMap,
 };
 
@@ -310,6 +311,9 @@ pub struct KeyboardState {
     /// For example entering `à` will fire a `VirtualKeyCode::Grave`, then `VirtualKeyCode::A`,
     /// so to correctly combine characters, the framework handles text composition internally.
     pub current_virtual_keycode: OptionVirtualKeyCode,
+    /// Physical scancode of the key in `current_virtual_keycode`. Unlike the virtual
+    /// keycode, it stays the same when the user switches the keyboard layout.
+    pub current_scancode: OptionU32,
     /// Currently pressed virtual keycodes (READONLY) - it can happen that more than one key is
     /// pressed
     ///
@@ -327,6 +331,9 @@ pub struct KeyboardState {
     /// the key's host GUI semantics, such as for movement controls in a first-person game
     /// (German keyboard: Z key, UK keyboard: Y key, etc.)
     pub pressed_scancodes: ScanCodeVec,
+    /// Current IME preedit (composition) string, `None` if no IME composition is
+    /// active (READONLY). Committed text is delivered as regular text input.
+    pub ime_composition: OptionString,
 }
 
 impl KeyboardState {
@@ -345,6 +352,21 @@ impl KeyboardState {
     pub fn is_key_down(&self, key: VirtualKeyCode) -> bool {
         self.pressed_virtual_keycodes.iter().any(|k| *k == key)
     }
+    /// Modifier keys (either side) that are currently held down
+    pub fn modifiers(&self) -> KeyModifiers {
+        KeyModifiers {
+            shift: self.shift_down(),
+            ctrl: self.ctrl_down(),
+            alt: self.alt_down(),
+            meta: self.super_down(),
+        }
+    }
+    /// Current key together with the held modifiers, so a callback can tell
+    /// `Ctrl+S` from a plain `S` without inspecting `pressed_virtual_keycodes`
+    pub fn key_with_modifiers(&self) -> Option<(VirtualKeyCode, KeyModifiers)> {
+        let key = self.current_virtual_keycode.into_option()?;
+        Some((key, self.modifiers()))
+    }
 }
 
 impl_option!(
@@ -480,6 +502,14 @@ impl MouseState {
     pub fn mouse_down(&self) -> bool {
         self.right_down || self.left_down || self.middle_down
     }
+
+    /// Bitmask of the currently held buttons, in the same layout as
+    /// `MouseEventData::buttons` (1 = left, 2 = right, 4 = middle)
+    pub fn buttons(&self) -> u8 {
+        (if self.left_down { 1 } else { 0 })
+            | (if self.right_down { 2 } else { 0 })
+            | (if self.middle_down { 4 } else { 0 })
+    }
 }
 
 // TODO: returned by process_system_scroll
@@ -590,6 +620,174 @@ pub struct TouchState {
     pub num_touches: usize,
 }
 
+/// Phase of a platform trackpad gesture (magnify or rotate)
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+#[repr(C)]
+pub enum GesturePhase {
+    /// No gesture is active
+    #[default]
+    None,
+    /// First event of a gesture
+    Began,
+    /// Gesture is in progress
+    Changed,
+    /// Fingers lifted, last event of the gesture
+    Ended,
+}
+
+/// Trackpad magnify ("smart zoom" / pinch) and rotate gesture state, as
+/// reported by the platform. Unlike the touch-based pinch detection in the
+/// gesture manager, these gestures are delivered pre-recognized by the OS.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TrackpadGestureState {
+    /// Phase of the current (or last) magnify gesture
+    pub magnify_phase: GesturePhase,
+    /// Magnification delta of the last update (`0.1` = 10% larger)
+    pub magnify_delta: f32,
+    /// Cumulative scale factor since the magnify gesture began (starts at `1.0`)
+    pub magnification: f32,
+    /// Phase of the current (or last) rotate gesture
+    pub rotate_phase: GesturePhase,
+    /// Rotation delta of the last update in radians (positive = clockwise)
+    pub rotate_delta: f32,
+    /// Cumulative rotation in radians since the rotate gesture began
+    pub rotation: f32,
+}
+
+impl Default for TrackpadGestureState {
+    fn default() -> Self {
+        Self {
+            magnify_phase: GesturePhase::None,
+            magnify_delta: 0.0,
+            magnification: 1.0,
+            rotate_phase: GesturePhase::None,
+            rotate_delta: 0.0,
+            rotation: 0.0,
+        }
+    }
+}
+
+impl TrackpadGestureState {
+    /// Feeds one platform magnify event into the state.
+    ///
+    /// `Began` resets the cumulative scale to `1.0` before applying `delta`,
+    /// so each gesture starts from an identity zoom.
+    pub fn update_magnify(&mut self, phase: GesturePhase, delta: f32) {
+        if phase == GesturePhase::Began {
+            self.magnification = 1.0;
+        }
+        self.magnify_phase = phase;
+        self.magnify_delta = delta;
+        self.magnification *= 1.0 + delta;
+    }
+
+    /// Feeds one platform rotate event into the state.
+    ///
+    /// `Began` resets the cumulative rotation to `0.0` before applying `delta`.
+    pub fn update_rotate(&mut self, phase: GesturePhase, radians: f32) {
+        if phase == GesturePhase::Began {
+            self.rotation = 0.0;
+        }
+        self.rotate_phase = phase;
+        self.rotate_delta = radians;
+        self.rotation += radians;
+    }
+
+    /// Returns whether the magnify part of the state differs from `previous`
+    /// while a gesture is (or just was) active
+    pub fn magnify_changed(&self, previous: &Self) -> bool {
+        self.magnify_phase != GesturePhase::None
+            && (self.magnify_phase != previous.magnify_phase
+                || self.magnification != previous.magnification)
+    }
+
+    /// Returns whether the rotate part of the state differs from `previous`
+    /// while a gesture is (or just was) active
+    pub fn rotate_changed(&self, previous: &Self) -> bool {
+        self.rotate_phase != GesturePhase::None
+            && (self.rotate_phase != previous.rotate_phase || self.rotation != previous.rotation)
+    }
+}
+
+/// Key-repeat timing for the currently held key. The platform only reports
+/// the initial key press, so repeats are synthesized from frame timestamps:
+/// the first repeat fires after `initial_delay_ms`, then every `interval_ms`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct KeyRepeatState {
+    /// Delay between the key press and the first repeat (READWRITE)
+    pub initial_delay_ms: u32,
+    /// Delay between two repeats, `0` disables key repeat (READWRITE)
+    pub interval_ms: u32,
+    /// When the currently held key was pressed, `None` if no key is held
+    pub key_down_time: OptionInstant,
+    /// Timestamp of the frame this state was last advanced in
+    pub frame_time: OptionInstant,
+}
+
+impl Default for KeyRepeatState {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 500,
+            interval_ms: 33,
+            key_down_time: OptionInstant::None,
+            frame_time: OptionInstant::None,
+        }
+    }
+}
+
+impl KeyRepeatState {
+    /// Advances the state to the frame at `now`, restarting the repeat timer
+    /// whenever a different key (or a key after no key) is pressed.
+    pub fn advance(
+        &mut self,
+        current_key: Option<VirtualKeyCode>,
+        previous_key: Option<VirtualKeyCode>,
+        now: Instant,
+    ) {
+        if current_key.is_none() {
+            self.key_down_time = OptionInstant::None;
+        } else if current_key != previous_key || self.key_down_time.is_none() {
+            self.key_down_time = OptionInstant::Some(now.clone());
+        }
+        self.frame_time = OptionInstant::Some(now);
+    }
+
+    /// Returns whether at least one repeat became due between the frame of
+    /// `previous` and `now`, so a slow frame yields one repeat, not a burst
+    pub fn is_repeat_due(&self, previous: &Self, now: &Instant) -> bool {
+        let (down, last_frame) = match (self.key_down_time.as_ref(), previous.frame_time.as_ref()) {
+            (Some(down), Some(last_frame)) => (down, last_frame),
+            _ => return false,
+        };
+        if self.interval_ms == 0 || last_frame < down || now < last_frame {
+            return false;
+        }
+        let elapsed_now = duration_millis(&now.duration_since(down));
+        let elapsed_before = duration_millis(&last_frame.duration_since(down));
+        self.repeats_after(elapsed_now) > self.repeats_after(elapsed_before)
+    }
+
+    /// Number of repeats that have fired `elapsed_ms` after the key press
+    fn repeats_after(&self, elapsed_ms: u64) -> u64 {
+        let delay = u64::from(self.initial_delay_ms);
+        if elapsed_ms < delay {
+            0
+        } else {
+            1 + (elapsed_ms - delay) / u64::from(self.interval_ms)
+        }
+    }
+}
+
+/// Ticks are treated as milliseconds, same as the gesture manager does
+fn duration_millis(duration: &Duration) -> u64 {
+    match duration {
+        Duration::System(d) => d.millis(),
+        Duration::Tick(t) => t.tick_diff,
+    }
+}
+
 /// Single touch point (finger, stylus, etc.)
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
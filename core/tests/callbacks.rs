@@ -0,0 +1,64 @@
+//! Tests for callback return values and callback-related helper types
+
+use azul_core::callbacks::Update;
+
+#[test]
+fn test_update_scroll_only_does_not_regenerate_dom() {
+    // A callback that only scrolled returns ScrollOnly
+    let mut update = Update::DoNothing;
+    update.max_self(Update::ScrollOnly);
+
+    assert_eq!(update, Update::ScrollOnly);
+    assert!(!update.needs_dom_regeneration());
+
+    // Merging with a callback that did nothing keeps the scroll signal
+    update.max_self(Update::DoNothing);
+    assert_eq!(update, Update::ScrollOnly);
+}
+
+#[test]
+fn test_update_max_self_ordering() {
+    let mut update = Update::ScrollOnly;
+    update.max_self(Update::RefreshDom);
+    assert_eq!(update, Update::RefreshDom);
+    assert!(update.needs_dom_regeneration());
+
+    update.max_self(Update::ScrollOnly);
+    assert_eq!(update, Update::RefreshDom);
+
+    update.max_self(Update::RefreshDomAllWindows);
+    assert_eq!(update, Update::RefreshDomAllWindows);
+
+    update.max_self(Update::RefreshDom);
+    assert_eq!(update, Update::RefreshDomAllWindows);
+}
+
+#[test]
+fn test_update_ordering_against_every_variant() {
+    // In ascending order of cost
+    let ranked = [
+        Update::DoNothing,
+        Update::ScrollOnly,
+        Update::RefreshDom,
+        Update::RefreshDomAllWindows,
+    ];
+
+    for (i, a) in ranked.iter().enumerate() {
+        for (j, b) in ranked.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+
+            let mut merged = *a;
+            merged.max_self(*b);
+            assert_eq!(merged, ranked[i.max(j)], "{:?}.max_self({:?})", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_update_discriminants_are_stable() {
+    // ScrollOnly is appended, the C API values of the older variants don't move
+    assert_eq!(Update::DoNothing as u32, 0);
+    assert_eq!(Update::RefreshDom as u32, 1);
+    assert_eq!(Update::RefreshDomAllWindows as u32, 2);
+    assert_eq!(Update::ScrollOnly as u32, 3);
+}
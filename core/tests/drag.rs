@@ -0,0 +1,36 @@
+//! Tests for resize edge detection of borderless windows
+
+use azul_core::{
+    drag::WindowResizeEdge,
+    geom::{LogicalPosition, LogicalSize},
+};
+
+fn edge_at(x: f32, y: f32) -> Option<WindowResizeEdge> {
+    WindowResizeEdge::from_cursor(LogicalPosition::new(x, y), LogicalSize::new(800.0, 600.0), 5.0)
+}
+
+#[test]
+fn test_resize_edges_and_corners() {
+    use WindowResizeEdge::*;
+
+    assert_eq!(edge_at(400.0, 2.0), Some(Top));
+    assert_eq!(edge_at(400.0, 598.0), Some(Bottom));
+    assert_eq!(edge_at(2.0, 300.0), Some(Left));
+    assert_eq!(edge_at(798.0, 300.0), Some(Right));
+
+    assert_eq!(edge_at(2.0, 2.0), Some(TopLeft));
+    assert_eq!(edge_at(798.0, 2.0), Some(TopRight));
+    assert_eq!(edge_at(2.0, 598.0), Some(BottomLeft));
+    assert_eq!(edge_at(798.0, 598.0), Some(BottomRight));
+}
+
+#[test]
+fn test_interior_and_outside_have_no_resize_edge() {
+    assert_eq!(edge_at(400.0, 300.0), None);
+    // Just past the border thickness
+    assert_eq!(edge_at(6.0, 300.0), None);
+    assert_eq!(edge_at(400.0, 594.0), None);
+    // Outside of the window
+    assert_eq!(edge_at(-1.0, 300.0), None);
+    assert_eq!(edge_at(400.0, 601.0), None);
+}
@@ -17,6 +17,7 @@ use azul_core::{
     id::{Node, NodeHierarchy, NodeId},
     styled_dom::NodeHierarchyItemId,
     task::{Instant, SystemTick},
+    window::{KeyboardState, VirtualKeyCode},
 };
 
 // Helper: Create a test Instant
@@ -265,6 +266,31 @@ fn test_key_modifiers() {
     assert!(empty.is_empty());
 }
 
+#[test]
+fn test_key_with_modifiers() {
+    let ctrl_s = KeyboardState {
+        current_virtual_keycode: Some(VirtualKeyCode::S).into(),
+        pressed_virtual_keycodes: vec![VirtualKeyCode::LControl, VirtualKeyCode::S].into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        ctrl_s.key_with_modifiers(),
+        Some((VirtualKeyCode::S, KeyModifiers::new().with_ctrl()))
+    );
+
+    let plain_s = KeyboardState {
+        current_virtual_keycode: Some(VirtualKeyCode::S).into(),
+        pressed_virtual_keycodes: vec![VirtualKeyCode::S].into(),
+        ..Default::default()
+    };
+    let (key, modifiers) = plain_s.key_with_modifiers().unwrap();
+    assert_eq!(key, VirtualKeyCode::S);
+    assert!(modifiers.is_empty());
+
+    // No key pressed, nothing to report
+    assert_eq!(KeyboardState::default().key_with_modifiers(), None);
+}
+
 #[test]
 fn test_lifecycle_event_mount() {
     let dom_id = DomId { inner: 1 };
@@ -369,6 +395,12 @@ fn test_lifecycle_event_unmount() {
     for event in &events {
         assert_eq!(event.event_type, EventType::Unmount);
         assert_eq!(event.source, EventSource::Lifecycle);
+
+        if let EventData::Lifecycle(data) = &event.data {
+            assert_eq!(data.reason, LifecycleReason::Unmount);
+        } else {
+            panic!("Expected Lifecycle event data");
+        }
     }
 }
 
@@ -457,3 +489,141 @@ fn test_event_filter_hover_match() {
     // We can't test it directly without making the function public
     // but it's tested indirectly through propagate_event
 }
+
+#[test]
+fn test_propagate_event_bubbles_into_host_dom() {
+    // Parent DOM: root (0) -> container (1) -> virtual view host (2)
+    let parent_hierarchy = create_test_hierarchy();
+    // Child DOM: root (0) -> child (1) -> clicked node (2), no callbacks at all
+    let child_hierarchy = create_test_hierarchy();
+    let child_dom = DomId { inner: 1 };
+    let host = DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+    };
+
+    let mut event = SyntheticEvent::new(
+        EventType::MouseUp,
+        EventSource::User,
+        DomNodeId {
+            dom: child_dom,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+        },
+        test_instant(),
+        EventData::None,
+    );
+    let filter = EventFilter::Hover(HoverEventFilter::MouseUp);
+
+    let inner = propagate_event(&mut event, &child_hierarchy, &BTreeMap::new());
+    assert!(inner.callbacks_to_invoke.is_empty());
+
+    let mut parent_callbacks = BTreeMap::new();
+    parent_callbacks.insert(NodeId::new(2), vec![filter]);
+    parent_callbacks.insert(NodeId::new(0), vec![filter]);
+    let outer = propagate_event_to_host(&mut event, host, &parent_hierarchy, &parent_callbacks);
+
+    assert_eq!(
+        outer.callbacks_to_invoke,
+        vec![(NodeId::new(2), filter), (NodeId::new(0), filter)]
+    );
+    // The event is retargeted to the host
+    assert_eq!(event.target, host);
+
+    // A stopped event does not leave the child DOM
+    event.stopped = true;
+    let stopped = propagate_event_to_host(&mut event, host, &parent_hierarchy, &parent_callbacks);
+    assert!(stopped.callbacks_to_invoke.is_empty());
+}
+
+#[test]
+fn test_horizontal_scroll_fires_only_the_horizontal_filter() {
+    let data = EventData::Scroll(ScrollEventData {
+        delta: LogicalPosition::new(12.0, 0.0),
+        delta_mode: ScrollDeltaMode::Pixel,
+    });
+
+    let filters = event_type_to_filters(EventType::Scroll, &data);
+    assert!(filters.contains(&EventFilter::Hover(HoverEventFilter::Scroll)));
+    assert!(filters.contains(&EventFilter::Hover(HoverEventFilter::ScrollHorizontal)));
+    assert!(filters.contains(&EventFilter::Window(WindowEventFilter::ScrollHorizontal)));
+    assert!(!filters.contains(&EventFilter::Hover(HoverEventFilter::ScrollVertical)));
+    assert!(!filters.contains(&EventFilter::Window(WindowEventFilter::ScrollVertical)));
+
+    // Callbacks on the scrolled node only run for the axis that moved
+    let mut event = SyntheticEvent::new(
+        EventType::Scroll,
+        EventSource::User,
+        DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+        },
+        test_instant(),
+        data,
+    );
+    let horizontal = EventFilter::Hover(HoverEventFilter::ScrollHorizontal);
+    let vertical = EventFilter::Hover(HoverEventFilter::ScrollVertical);
+    let mut callbacks = BTreeMap::new();
+    callbacks.insert(NodeId::new(2), vec![horizontal, vertical]);
+
+    let result = propagate_event(&mut event, &create_test_hierarchy(), &callbacks);
+    assert_eq!(result.callbacks_to_invoke, vec![(NodeId::new(2), horizontal)]);
+}
+
+#[test]
+fn test_stop_propagation_does_not_block_sibling_callbacks() {
+    let node = |id| DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+    };
+    // root (0) -> [button (1), button (2)], each clicked in its own dispatch
+    let (root, first, second) = (node(0), node(1), node(2));
+    let (first_click, second_click) = (0, 1);
+
+    let mut propagation = PropagationState::new();
+    propagation.stop_propagation(first_click, first);
+
+    // The stopped event still reaches the other callbacks on its node, but
+    // does not bubble further
+    assert!(propagation.should_invoke(first_click, first));
+    assert!(!propagation.should_invoke(first_click, root));
+
+    // The sibling's callback of the same type still runs and bubbles
+    assert!(propagation.should_invoke(second_click, second));
+    assert!(propagation.should_invoke(second_click, root));
+
+    propagation.stop_immediate_propagation(second_click);
+    assert!(!propagation.should_invoke(second_click, second));
+    assert!(propagation.should_invoke(first_click, first));
+}
+
+#[test]
+fn test_capturing_parent_runs_before_bubbling_child() {
+    // root (0) -> child (1) -> clicked node (2)
+    let hierarchy = create_test_hierarchy();
+    let mut event = SyntheticEvent::new(
+        EventType::MouseUp,
+        EventSource::User,
+        DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+        },
+        test_instant(),
+        EventData::None,
+    );
+    let capture = EventFilter::Capture(HoverEventFilter::MouseUp);
+    let bubble = EventFilter::Hover(HoverEventFilter::MouseUp);
+
+    let mut callbacks = BTreeMap::new();
+    callbacks.insert(NodeId::new(0), vec![capture, bubble]);
+    callbacks.insert(NodeId::new(2), vec![bubble]);
+
+    let result = propagate_event(&mut event, &hierarchy, &callbacks);
+    assert_eq!(
+        result.callbacks_to_invoke,
+        vec![
+            (NodeId::new(0), capture),
+            (NodeId::new(2), bubble),
+            (NodeId::new(0), bubble),
+        ]
+    );
+}
@@ -0,0 +1,173 @@
+//! Tests for `GpuValueCache` synchronization and snapshot / restore
+
+use azul_core::{
+    dom::{Dom, NodeId},
+    gpu::{GpuOpacityKeyEvent, GpuTransformKeyEvent, GpuValueCache},
+    styled_dom::StyledDom,
+};
+use azul_css::{
+    css::{Css, CssPropertyValue},
+    dynamic_selector::CssPropertyWithConditions,
+    props::{
+        basic::PixelValue,
+        property::CssProperty,
+        style::{
+            effects::StyleOpacity,
+            transform::{StyleTransform, StyleTransformVec},
+        },
+    },
+};
+
+fn dom_with_opacity_children(count: usize) -> StyledDom {
+    let mut dom = Dom::create_div();
+    for _ in 0..count {
+        let mut child = Dom::create_div();
+        child
+            .root
+            .add_css_property(CssPropertyWithConditions::simple(CssProperty::Opacity(
+                CssPropertyValue::Exact(StyleOpacity::const_new(50)),
+            )));
+        dom.add_child(child);
+    }
+    StyledDom::create(&mut dom, Css::empty())
+}
+
+#[test]
+fn test_restore_reverts_keys_added_after_snapshot() {
+    let mut cache = GpuValueCache::empty();
+    let _ = cache.synchronize(&dom_with_opacity_children(1));
+    assert_eq!(cache.opacity_keys.len(), 1);
+
+    let before = cache.clone();
+    let snapshot = cache.snapshot();
+
+    let changes = cache.synchronize(&dom_with_opacity_children(3));
+    assert!(!changes.is_empty());
+    assert_eq!(cache.opacity_keys.len(), 3);
+
+    cache.restore(snapshot);
+    assert_eq!(cache, before);
+    assert_eq!(cache.opacity_keys.len(), 1);
+}
+
+#[test]
+fn test_restore_empty_snapshot() {
+    let mut cache = GpuValueCache::empty();
+    let snapshot = cache.snapshot();
+
+    let _ = cache.synchronize(&dom_with_opacity_children(2));
+    assert_eq!(cache.current_opacity_values.len(), 2);
+
+    cache.restore(snapshot);
+    assert_eq!(cache, GpuValueCache::empty());
+}
+
+/// Root div with a single child carrying the given opacity (percent) and translateX (px)
+fn dom_with_child(opacity: Option<isize>, translate_x: Option<f32>) -> StyledDom {
+    let mut child = Dom::create_div();
+    if let Some(opacity) = opacity {
+        child
+            .root
+            .add_css_property(CssPropertyWithConditions::simple(CssProperty::Opacity(
+                CssPropertyValue::Exact(StyleOpacity::const_new(opacity)),
+            )));
+    }
+    if let Some(x) = translate_x {
+        let transform = StyleTransformVec::from_vec(vec![StyleTransform::TranslateX(
+            PixelValue::px(x),
+        )]);
+        child
+            .root
+            .add_css_property(CssPropertyWithConditions::simple(CssProperty::Transform(
+                CssPropertyValue::Exact(transform),
+            )));
+    }
+    let mut dom = Dom::create_div().with_child(child);
+    StyledDom::create(&mut dom, Css::empty())
+}
+
+#[test]
+fn test_synchronize_opacity_transitions() {
+    let child = NodeId::new(1);
+    let mut cache = GpuValueCache::empty();
+
+    let changes = cache.synchronize(&dom_with_child(Some(50), None));
+    let key = match changes.opacity_key_changes.as_slice() {
+        [GpuOpacityKeyEvent::Added(node, key, value)] if *node == child && *value == 0.5 => *key,
+        other => panic!("expected Added, got {:?}", other),
+    };
+
+    // Unchanged value: nothing to send to the GPU
+    assert!(cache.synchronize(&dom_with_child(Some(50), None)).is_empty());
+
+    let changes = cache.synchronize(&dom_with_child(Some(25), None));
+    assert_eq!(
+        changes.opacity_key_changes,
+        vec![GpuOpacityKeyEvent::Changed(child, key, 0.5, 0.25)]
+    );
+    assert_eq!(cache.current_opacity_values.get(&child), Some(&0.25));
+
+    let changes = cache.synchronize(&dom_with_child(None, None));
+    assert_eq!(
+        changes.opacity_key_changes,
+        vec![GpuOpacityKeyEvent::Removed(child, key)]
+    );
+    assert!(cache.opacity_keys.is_empty());
+    assert!(cache.current_opacity_values.is_empty());
+}
+
+#[test]
+fn test_synchronize_clamps_opacity_and_lists_transparent_nodes() {
+    let child = NodeId::new(1);
+    let mut cache = GpuValueCache::empty();
+
+    let changes = cache.synchronize(&dom_with_child(Some(150), None));
+    assert!(matches!(
+        changes.opacity_key_changes.as_slice(),
+        [GpuOpacityKeyEvent::Added(node, _, value)] if *node == child && *value == 1.0
+    ));
+    assert_eq!(cache.current_opacity_values.get(&child), Some(&1.0));
+    assert!(changes.transparent_nodes.is_empty());
+
+    let changes = cache.synchronize(&dom_with_child(Some(0), None));
+    assert_eq!(cache.current_opacity_values.get(&child), Some(&0.0));
+    assert_eq!(changes.transparent_nodes, vec![child]);
+
+    // Still listed while the opacity stays at zero
+    let changes = cache.synchronize(&dom_with_child(Some(-20), None));
+    assert!(changes.opacity_key_changes.is_empty());
+    assert_eq!(changes.transparent_nodes, vec![child]);
+}
+
+#[test]
+fn test_synchronize_transform_transitions() {
+    let child = NodeId::new(1);
+    let mut cache = GpuValueCache::empty();
+
+    let changes = cache.synchronize(&dom_with_child(None, Some(10.0)));
+    let (key, first) = match changes.transform_key_changes.as_slice() {
+        [GpuTransformKeyEvent::Added(node, key, matrix)] if *node == child => (*key, *matrix),
+        other => panic!("expected Added, got {:?}", other),
+    };
+    assert_eq!(cache.css_transform_keys.get(&child), Some(&key));
+
+    assert!(cache.synchronize(&dom_with_child(None, Some(10.0))).is_empty());
+
+    let changes = cache.synchronize(&dom_with_child(None, Some(20.0)));
+    match changes.transform_key_changes.as_slice() {
+        [GpuTransformKeyEvent::Changed(node, changed_key, old, new)] => {
+            assert_eq!((*node, *changed_key, *old), (child, key, first));
+            assert_ne!(*new, first);
+            assert_eq!(cache.css_current_transform_values.get(&child), Some(new));
+        }
+        other => panic!("expected Changed, got {:?}", other),
+    }
+
+    let changes = cache.synchronize(&dom_with_child(None, None));
+    assert_eq!(
+        changes.transform_key_changes,
+        vec![GpuTransformKeyEvent::Removed(child, key)]
+    );
+    assert!(cache.css_transform_keys.is_empty());
+    assert!(cache.css_current_transform_values.is_empty());
+}
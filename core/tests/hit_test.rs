@@ -0,0 +1,45 @@
+//! Tests for scroll hit-test node identity
+
+use azul_core::{
+    dom::{DomNodeHash, ScrollTagId, TagId},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    hit_test::{ExternalScrollId, OverflowingScrollNode, PipelineId},
+};
+
+fn scroll_node(child_height: f32) -> OverflowingScrollNode {
+    OverflowingScrollNode {
+        parent_rect: LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 100.0)),
+        child_rect: LogicalRect::new(
+            LogicalPosition::zero(),
+            LogicalSize::new(100.0, child_height),
+        ),
+        virtual_child_rect: LogicalRect::zero(),
+        parent_external_scroll_id: ExternalScrollId(7, PipelineId::DUMMY),
+        parent_dom_hash: DomNodeHash { inner: 42 },
+        scroll_tag_id: ScrollTagId {
+            inner: TagId { inner: 3 },
+        },
+    }
+}
+
+#[test]
+fn test_same_identity_ignores_rect_jitter() {
+    let a = scroll_node(400.0);
+    let b = scroll_node(400.25);
+
+    assert_ne!(a, b);
+    assert!(a.same_identity(&b));
+    assert!(b.same_identity(&a));
+}
+
+#[test]
+fn test_same_identity_differs_on_scroll_id() {
+    let a = scroll_node(400.0);
+    let mut b = a;
+    b.parent_external_scroll_id = ExternalScrollId(8, PipelineId::DUMMY);
+    assert!(!a.same_identity(&b));
+
+    let mut c = a;
+    c.parent_dom_hash = DomNodeHash { inner: 43 };
+    assert!(!a.same_identity(&c));
+}
@@ -561,3 +561,62 @@ fn test_restyle_preserves_other_state_flags() {
     assert!(state.hover, "Should still be hovered");
     assert!(state.active, "Should still be active");
 }
+
+#[test]
+fn test_restyle_user_property_coalesces_repeated_changes() {
+    let mut styled_dom = create_test_dom_with_layout_pseudo_states();
+    let node_id = NodeId::new(1);
+
+    // Two callbacks set conflicting widths on the same node
+    let final_width = CssProperty::Width(CssPropertyValue::Exact(LayoutWidth::const_px(300)));
+    let changes = styled_dom.restyle_user_property(
+        &node_id,
+        &[
+            CssProperty::Width(CssPropertyValue::Exact(LayoutWidth::const_px(150))),
+            final_width.clone(),
+        ],
+    );
+
+    let node_changes = changes.get(&node_id).expect("width changed");
+    assert_eq!(node_changes.len(), 1, "width should be reported once");
+    assert_eq!(node_changes[0].current_prop, final_width);
+    assert_eq!(
+        node_changes[0].previous_prop,
+        CssProperty::Width(CssPropertyValue::Exact(LayoutWidth::const_px(100)))
+    );
+
+    // Only the final value ends up in the overrides
+    let overrides = &styled_dom
+        .get_css_property_cache()
+        .user_overridden_properties[node_id.index()];
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].1, final_width);
+}
+
+#[test]
+fn test_restyle_noop_frame_takes_early_path() {
+    let mut styled_dom = create_test_dom_with_pseudo_states();
+    let states_before = styled_dom.styled_nodes.as_container().iter().map(|n| n.styled_node_state).collect::<Vec<_>>();
+
+    // Idle frame: no deltas at all
+    let early = styled_dom.restyle_on_state_change(
+        Some(FocusChange { lost_focus: None, gained_focus: None }),
+        Some(HoverChange { left_nodes: Vec::new(), entered_nodes: Vec::new() }),
+        Some(ActiveChange { deactivated: Vec::new(), activated: Vec::new() }),
+    );
+    assert!(!early.has_changes());
+    assert_eq!(early, RestyleResult::default());
+    assert_eq!(styled_dom.restyle_on_state_change(None, None, None), early);
+
+    let states_after = styled_dom.styled_nodes.as_container().iter().map(|n| n.styled_node_state).collect::<Vec<_>>();
+    assert_eq!(states_before, states_after);
+
+    // Full path with a delta that does not affect any property (the root has
+    // no :hover styles) must produce the same result
+    let full = styled_dom.restyle_on_state_change(
+        None,
+        Some(HoverChange { left_nodes: Vec::new(), entered_nodes: vec![NodeId::new(0)] }),
+        None,
+    );
+    assert_eq!(full, early);
+}
@@ -0,0 +1,381 @@
+//! Tests for `ComputedTransform3D`
+
+use azul_core::{
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    transform::{ComputedTransform3D, RotationMode},
+};
+use azul_css::props::{
+    basic::{AngleValue, PixelValue},
+    style::{StyleTransform, StyleTransformOrigin, StyleTransformTranslate2D},
+};
+
+fn assert_matrix_eq(actual: &ComputedTransform3D, expected: &ComputedTransform3D) {
+    for row in 0..4 {
+        for col in 0..4 {
+            assert!(
+                (actual.m[row][col] - expected.m[row][col]).abs() < 1e-4,
+                "m[{}][{}]: expected {:?}, got {:?}",
+                row,
+                col,
+                expected,
+                actual
+            );
+        }
+    }
+}
+
+fn rotation_deg(deg: f32) -> ComputedTransform3D {
+    ComputedTransform3D::new_rotation(0.0, 0.0, 1.0, deg.to_radians())
+}
+
+#[test]
+fn test_is_2d() {
+    assert!(ComputedTransform3D::IDENTITY.is_2d());
+    assert!(ComputedTransform3D::new_2d(2.0, 0.5, -0.5, 3.0, 10.0, 20.0).is_2d());
+    assert!(rotation_deg(30.0).is_2d());
+    assert!(!ComputedTransform3D::new_translation(0.0, 0.0, 5.0).is_2d());
+    assert!(!ComputedTransform3D::new_perspective(100.0).is_2d());
+}
+
+#[test]
+fn test_lerp_2d_translation_and_scale() {
+    let from = ComputedTransform3D::new_translation(0.0, 10.0, 0.0);
+    let to = ComputedTransform3D::new_scale(3.0, 5.0, 1.0)
+        .then(&ComputedTransform3D::new_translation(100.0, 50.0, 0.0));
+
+    let half = from.lerp_2d(&to, 0.5).unwrap();
+    let expected = ComputedTransform3D::new_scale(2.0, 3.0, 1.0)
+        .then(&ComputedTransform3D::new_translation(50.0, 30.0, 0.0));
+    assert_matrix_eq(&half, &expected);
+}
+
+#[test]
+fn test_lerp_2d_rotation() {
+    let half = rotation_deg(0.0).lerp_2d(&rotation_deg(90.0), 0.5).unwrap();
+    assert_matrix_eq(&half, &rotation_deg(45.0));
+
+    // Takes the shortest way round: 350deg -> 10deg passes through 0deg
+    let half = rotation_deg(350.0).lerp_2d(&rotation_deg(10.0), 0.5).unwrap();
+    assert_matrix_eq(&half, &ComputedTransform3D::IDENTITY);
+}
+
+#[test]
+fn test_lerp_2d_endpoints_reproduce_inputs() {
+    // skewed + mirrored (negative determinant) + rotated + translated
+    let from = ComputedTransform3D::new_2d(1.5, 0.3, 0.8, -2.0, 12.0, -7.0);
+    let to = ComputedTransform3D::new_scale(0.5, 2.0, 1.0)
+        .then(&rotation_deg(120.0))
+        .then(&ComputedTransform3D::new_translation(-40.0, 25.0, 0.0));
+
+    assert_matrix_eq(&from.lerp_2d(&to, 0.0).unwrap(), &from);
+    assert_matrix_eq(&from.lerp_2d(&to, 1.0).unwrap(), &to);
+}
+
+#[test]
+fn test_lerp_2d_falls_back_for_3d_input() {
+    let flat = ComputedTransform3D::new_translation(10.0, 0.0, 0.0);
+    let perspective = ComputedTransform3D::new_perspective(500.0);
+
+    assert!(flat.lerp_2d(&perspective, 0.5).is_none());
+    assert!(perspective.lerp_2d(&flat, 0.5).is_none());
+}
+
+#[test]
+fn test_column_major_array_round_trip() {
+    let t = ComputedTransform3D::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let arr = t.to_column_major_array();
+    assert_eq!(ComputedTransform3D::from_column_major_array(arr), t);
+}
+
+#[test]
+fn test_column_major_array_translation_slots() {
+    let arr = ComputedTransform3D::new_translation(10.0, 20.0, 30.0).to_column_major_array();
+    assert_eq!(&arr[12..15], &[10.0, 20.0, 30.0]);
+    assert_eq!(arr[15], 1.0);
+    // The upper 3x3 stays identity, nothing leaks into the fourth row of GL's matrix
+    assert_eq!(&arr[0..4], &[1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(arr[7], 0.0);
+    assert_eq!(arr[11], 0.0);
+}
+
+#[test]
+fn test_normalize_divides_by_w() {
+    let t = ComputedTransform3D::new(
+        2.0, 4.0, 0.0, 0.0, 6.0, 8.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 10.0, 20.0, 0.0, 2.0,
+    );
+    let expected = ComputedTransform3D::new(
+        1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, 10.0, 0.0, 1.0,
+    );
+    let normalized = t.normalize().unwrap();
+    assert_eq!(normalized, expected);
+    assert_eq!(normalized.m[3][3], 1.0);
+
+    let mut degenerate = t;
+    degenerate.m[3][3] = 0.0;
+    assert!(degenerate.normalize().is_none());
+}
+
+#[test]
+fn test_transform_point2d() {
+    let p = LogicalPosition::new(3.0, 4.0);
+    assert_eq!(ComputedTransform3D::IDENTITY.transform_point2d(p), Some(p));
+
+    let translated = ComputedTransform3D::new_translation(10.0, -20.0, 5.0).transform_point2d(p);
+    assert_eq!(translated, Some(LogicalPosition::new(13.0, -16.0)));
+
+    // w = 0.5 * x + 1 = 2.5 for x = 3
+    let mut perspective = ComputedTransform3D::IDENTITY;
+    perspective.m[0][3] = 0.5;
+    let divided = perspective.transform_point2d(p).unwrap();
+    assert!((divided.x - 1.2).abs() < 1e-5 && (divided.y - 1.6).abs() < 1e-5);
+
+    // Points on the plane with w == 0 have no finite image
+    perspective.m[3][3] = -1.5;
+    assert_eq!(perspective.transform_point2d(p), None);
+}
+
+fn assert_rect_eq(actual: LogicalRect, expected: LogicalRect) {
+    let a = [actual.origin.x, actual.origin.y, actual.size.width, actual.size.height];
+    let e = [expected.origin.x, expected.origin.y, expected.size.width, expected.size.height];
+    assert!(
+        a.iter().zip(e.iter()).all(|(a, e)| (a - e).abs() < 1e-4),
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_transform_bounding_rect() {
+    let rect = LogicalRect::new(LogicalPosition::new(10.0, 20.0), LogicalSize::new(40.0, 10.0));
+
+    let identity = ComputedTransform3D::IDENTITY.transform_bounding_rect(rect);
+    assert_rect_eq(identity.unwrap(), rect);
+
+    // (x, y) -> (-y, x): the box is 10 wide and 40 high
+    let rotated = rotation_deg(90.0).transform_bounding_rect(rect).unwrap();
+    assert_rect_eq(
+        rotated,
+        LogicalRect::new(LogicalPosition::new(-30.0, 10.0), LogicalSize::new(10.0, 40.0)),
+    );
+
+    // A 45deg rotation grows the box beyond both original sides
+    let diagonal = rotation_deg(45.0).transform_bounding_rect(rect).unwrap();
+    let side = 50.0 / 2.0_f32.sqrt();
+    assert!((diagonal.size.width - side).abs() < 1e-3);
+    assert!((diagonal.size.height - side).abs() < 1e-3);
+
+    let translated = ComputedTransform3D::new_translation(5.0, -5.0, 0.0)
+        .transform_bounding_rect(rect)
+        .unwrap();
+    assert_rect_eq(
+        translated,
+        LogicalRect::new(LogicalPosition::new(15.0, 15.0), LogicalSize::new(40.0, 10.0)),
+    );
+
+    // w = 0.5 * x - 5 is zero for the left corners (x = 10)
+    let mut perspective = ComputedTransform3D::IDENTITY;
+    perspective.m[0][3] = 0.5;
+    perspective.m[3][3] = -5.0;
+    assert_eq!(perspective.transform_bounding_rect(rect), None);
+}
+
+#[test]
+fn test_then_inverse_is_identity() {
+    let matrices = [
+        ComputedTransform3D::new_translation(10.0, -20.0, 5.0),
+        ComputedTransform3D::new_scale(2.0, 0.5, 4.0),
+        rotation_deg(30.0),
+        ComputedTransform3D::new_rotation(1.0, 1.0, 0.0, 1.2),
+        // all elements distinct and non-zero, catches swapped indices
+        ComputedTransform3D::new(
+            2.0, 0.3, 0.1, 0.0, -0.4, 1.5, 0.2, 0.0, 0.6, -0.7, 3.0, 0.0, 12.0, -8.0, 4.0, 1.0,
+        ),
+    ];
+    for m in matrices.iter() {
+        assert_matrix_eq(&m.then(&m.inverse()), &ComputedTransform3D::IDENTITY);
+        assert_matrix_eq(&m.inverse().then(m), &ComputedTransform3D::IDENTITY);
+    }
+}
+
+#[test]
+fn test_then_matches_reference_multiply() {
+    let a = ComputedTransform3D::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let b = ComputedTransform3D::new(
+        -1.0, 0.5, 2.0, 0.0, 3.0, -2.0, 0.25, 1.0, 0.0, 4.0, -3.0, 2.0, 1.5, 0.0, 1.0, -1.0,
+    );
+    let expected = core::array::from_fn(|row| {
+        core::array::from_fn(|col| (0..4).map(|k| a.m[row][k] * b.m[k][col]).sum())
+    });
+    assert_matrix_eq(&a.then(&b), &ComputedTransform3D { m: expected });
+}
+
+#[test]
+fn test_multiply_scalar_doubles_every_element() {
+    let m = ComputedTransform3D::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    );
+    let doubled = m.multiply_scalar(2.0);
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_eq!(doubled.m[row][col], m.m[row][col] * 2.0);
+        }
+    }
+}
+
+#[test]
+fn test_skew_x_displaces_unit_square_horizontally() {
+    let skew_x = ComputedTransform3D::from_style_transform(
+        &StyleTransform::SkewX(AngleValue::deg(45.0)),
+        &StyleTransformOrigin::default(),
+        1.0,
+        1.0,
+        RotationMode::ForHitTesting,
+    );
+
+    // The top edge (y = 0) stays, the bottom edge (y = 1) moves right by tan(45deg)
+    let corners = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+    let expected = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (2.0, 1.0)];
+    for ((x, y), (ex, ey)) in corners.iter().zip(expected.iter()) {
+        let p = skew_x.transform_point2d(LogicalPosition::new(*x, *y)).unwrap();
+        assert!(
+            (p.x - ex).abs() < 1e-5 && (p.y - ey).abs() < 1e-5,
+            "({x}, {y}) -> {p:?}, expected ({ex}, {ey})"
+        );
+    }
+
+    // skewY is the transposed case
+    let skew_y = ComputedTransform3D::new_skew(0.0, 45.0);
+    let p = skew_y.transform_point2d(LogicalPosition::new(1.0, 0.0)).unwrap();
+    assert!((p.x - 1.0).abs() < 1e-5 && (p.y - 1.0).abs() < 1e-5, "{p:?}");
+}
+
+#[test]
+fn test_from_style_transform_vec_composes_all_entries() {
+    let origin = StyleTransformOrigin::default();
+    let mode = RotationMode::ForHitTesting;
+    let translate = StyleTransform::Translate(StyleTransformTranslate2D {
+        x: PixelValue::px(10.0),
+        y: PixelValue::px(0.0),
+    });
+    let rotate = StyleTransform::Rotate(AngleValue::deg(45.0));
+
+    let composed = ComputedTransform3D::from_style_transform_vec(
+        &[translate, rotate],
+        &origin,
+        100.0,
+        100.0,
+        mode,
+    );
+    let expected =
+        ComputedTransform3D::from_style_transform(&translate, &origin, 100.0, 100.0, mode).then(
+            &ComputedTransform3D::from_style_transform(&rotate, &origin, 100.0, 100.0, mode),
+        );
+
+    assert_ne!(composed, ComputedTransform3D::IDENTITY);
+    assert_matrix_eq(&composed, &expected);
+}
+
+#[test]
+fn test_decompose_recompose_round_trip() {
+    let matrices = [
+        ComputedTransform3D::IDENTITY,
+        ComputedTransform3D::new_translation(10.0, -20.0, 5.0),
+        ComputedTransform3D::new_scale(2.0, 0.5, 3.0),
+        ComputedTransform3D::new_scale(-1.0, 1.0, 1.0),
+        rotation_deg(30.0),
+        ComputedTransform3D::new_scale(2.0, 3.0, 1.0)
+            .then(&ComputedTransform3D::new_skew(20.0, 0.0))
+            .then(&ComputedTransform3D::new_rotation(0.6, 0.0, 0.8, 1.1))
+            .then(&ComputedTransform3D::new_translation(4.0, 5.0, 6.0)),
+        rotation_deg(45.0).then(&ComputedTransform3D::new_perspective(500.0)),
+    ];
+    for m in matrices.iter() {
+        let decomposed = m.decompose().expect("invertible matrix");
+        assert_matrix_eq(&ComputedTransform3D::recompose(&decomposed), m);
+    }
+}
+
+#[test]
+fn test_quaternion_round_trip_90deg_y() {
+    let half = core::f32::consts::FRAC_PI_4;
+    let (sin, cos) = (half.sin(), half.cos());
+
+    let m = ComputedTransform3D::from_quaternion(0.0, sin, 0.0, cos);
+    assert_matrix_eq(
+        &m,
+        &ComputedTransform3D::new_rotation(0.0, 1.0, 0.0, 90f32.to_radians()),
+    );
+
+    let (x, y, z, w) = m.to_quaternion().unwrap();
+    for (actual, expected) in [(x, 0.0), (y, sin), (z, 0.0), (w, cos)] {
+        assert!((actual - expected).abs() < 1e-4, "{:?}", (x, y, z, w));
+    }
+}
+
+#[test]
+fn test_to_quaternion_rejects_non_uniform_scale() {
+    let m = ComputedTransform3D::new_scale(2.0, 1.0, 1.0).then(&rotation_deg(30.0));
+    assert_eq!(m.to_quaternion(), None);
+    assert!(ComputedTransform3D::new_scale(2.0, 2.0, 2.0).to_quaternion().is_some());
+}
+
+#[test]
+fn test_decompose_singular_matrix() {
+    assert!(ComputedTransform3D::new_scale(1.0, 0.0, 1.0).decompose().is_none());
+}
+
+#[test]
+fn test_interpolate() {
+    let a = ComputedTransform3D::new_translation(0.0, 0.0, 0.0);
+    let b = rotation_deg(90.0).then(&ComputedTransform3D::new_translation(100.0, 50.0, 0.0));
+
+    assert_matrix_eq(&a.interpolate(&b, 0.0), &a);
+    assert_matrix_eq(&a.interpolate(&b, 1.0), &b);
+    assert_matrix_eq(
+        &a.interpolate(&b, 0.5),
+        &rotation_deg(45.0).then(&ComputedTransform3D::new_translation(50.0, 25.0, 0.0)),
+    );
+
+    // Non-invertible transforms snap at the midpoint
+    let singular = ComputedTransform3D::new_scale(0.0, 0.0, 1.0);
+    assert_matrix_eq(&a.interpolate(&singular, 0.4), &a);
+    assert_matrix_eq(&a.interpolate(&singular, 0.6), &singular);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn test_then_sse_matches_scalar() {
+    // Small LCG, so the test is deterministic without pulling in `rand`
+    let mut seed = 0x2545_f491_u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) as f32 / (1 << 24) as f32 * 20.0 - 10.0
+    };
+    let mut random_matrix = || {
+        let mut m = [[0.0; 4]; 4];
+        for row in m.iter_mut() {
+            for v in row.iter_mut() {
+                *v = next();
+            }
+        }
+        ComputedTransform3D { m }
+    };
+
+    for _ in 0..100 {
+        let a = random_matrix();
+        let b = random_matrix();
+        let scalar = a.then_scalar(&b);
+        let sse = unsafe { a.then_sse(&b) };
+        for row in 0..4 {
+            for col in 0..4 {
+                let diff = (scalar.m[row][col] - sse.m[row][col]).abs();
+                assert!(diff <= 1e-4 * scalar.m[row][col].abs().max(1.0), "{scalar:?} vs {sse:?}");
+            }
+        }
+    }
+}
@@ -294,6 +294,7 @@ impl crate::format_rust_code::FormatAsRustCode for LayoutFlexBasis {
     fn format_as_rust_code(&self, _tabs: usize) -> String {
         match self {
             LayoutFlexBasis::Auto => String::from("LayoutFlexBasis::Auto"),
+            LayoutFlexBasis::Content => String::from("LayoutFlexBasis::Content"),
             LayoutFlexBasis::Exact(px) => {
                 format!(
                     "LayoutFlexBasis::Exact({})",
@@ -812,6 +813,8 @@ pub enum LayoutFlexBasis {
     Auto,
     /// Fixed size
     Exact(crate::props::basic::pixel::PixelValue),
+    /// content: size to the item's max-content, ignoring `width` / `height`
+    Content,
 }
 
 impl core::fmt::Debug for LayoutFlexBasis {
@@ -830,6 +833,7 @@ impl PrintAsCssValue for LayoutFlexBasis {
     fn print_as_css_value(&self) -> String {
         match self {
             LayoutFlexBasis::Auto => "auto".to_string(),
+            LayoutFlexBasis::Content => "content".to_string(),
             LayoutFlexBasis::Exact(px) => px.print_as_css_value(),
         }
     }
@@ -885,6 +889,7 @@ pub fn parse_layout_flex_basis<'a>(
 
     match input.trim() {
         "auto" => Ok(LayoutFlexBasis::Auto),
+        "content" => Ok(LayoutFlexBasis::Content),
         s => parse_pixel_value(s)
             .map(LayoutFlexBasis::Exact)
             .map_err(|_| FlexBasisParseError::InvalidValue(input)),
@@ -1075,6 +1080,10 @@ mod tests {
             parse_layout_flex_basis("  10em  ").unwrap(),
             LayoutFlexBasis::Exact(PixelValue::em(10.0))
         );
+        assert_eq!(
+            parse_layout_flex_basis("content").unwrap(),
+            LayoutFlexBasis::Content
+        );
         assert!(parse_layout_flex_basis("none").is_err());
         // Liberal parsing accepts unitless numbers (treated as px)
         assert_eq!(
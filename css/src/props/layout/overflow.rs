@@ -63,6 +63,45 @@ impl LayoutOverflow {
     pub fn is_overflow_hidden(&self) -> bool {
         *self == LayoutOverflow::Hidden
     }
+
+    /// Returns `true` if the user can scroll along this axis (wheel, touch,
+    /// scrollbar). `hidden` and `clip` never scroll; `visible` on one axis
+    /// computes to `auto` when the other axis is a scroll container.
+    pub fn is_user_scrollable(&self) -> bool {
+        !matches!(self, LayoutOverflow::Hidden | LayoutOverflow::Clip)
+    }
+}
+
+/// Overflow along a single axis: the declared `overflow-x` / `overflow-y`
+/// value paired with the overflow amount measured during layout.
+///
+/// Keeping both together prevents a computed overflow from hiding the
+/// author's intent (e.g. `hidden` content that overflows must not get a
+/// scrollbar, `scroll` must get one even if nothing overflows).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DirectionalOverflowInfo {
+    /// The value declared in CSS
+    pub declared: LayoutOverflow,
+    /// How far the content extends past the container edge, in whole pixels.
+    /// `None` if layout has not measured this axis (yet).
+    pub amount: Option<isize>,
+}
+
+impl DirectionalOverflowInfo {
+    /// Pairs the declared CSS overflow mode with the measured overflow amount.
+    pub const fn from_css_and_amount(declared: LayoutOverflow, amount: Option<isize>) -> Self {
+        Self { declared, amount }
+    }
+
+    /// Returns `true` if layout measured content past the container edge.
+    pub fn is_overflowing(&self) -> bool {
+        self.amount.is_some_and(|a| a > 0)
+    }
+
+    /// Returns whether a scrollbar should be shown on this axis.
+    pub fn needs_scrollbar(&self) -> bool {
+        self.declared.needs_scrollbar(self.is_overflowing())
+    }
 }
 
 impl PrintAsCssValue for LayoutOverflow {
@@ -186,4 +225,48 @@ mod tests {
         assert!(!LayoutOverflow::Visible.needs_scrollbar(true));
         assert!(!LayoutOverflow::Clip.needs_scrollbar(true));
     }
+
+    #[test]
+    fn test_directional_overflow_keeps_declared_mode() {
+        let info = DirectionalOverflowInfo::from_css_and_amount(LayoutOverflow::Hidden, Some(25));
+        assert_eq!(info.declared, LayoutOverflow::Hidden);
+        assert_eq!(info.amount, Some(25));
+        assert!(info.is_overflowing());
+        assert!(!info.needs_scrollbar());
+    }
+
+    #[test]
+    fn test_directional_overflow_default_is_visible() {
+        let info = DirectionalOverflowInfo::default();
+        assert_eq!(info.declared, LayoutOverflow::Visible);
+        assert!(!info.is_overflowing());
+    }
+
+    #[test]
+    fn test_directional_overflow_needs_scrollbar_truth_table() {
+        use LayoutOverflow::*;
+
+        // (declared, [positive, negative, zero, unmeasured])
+        let table = [
+            (Scroll, [true, true, true, true]),
+            (Auto, [true, false, false, false]),
+            (Hidden, [false, false, false, false]),
+            (Visible, [false, false, false, false]),
+            (Clip, [false, false, false, false]),
+        ];
+        let amounts = [Some(10), Some(-10), Some(0), None];
+
+        for (declared, expected) in table.iter() {
+            for (amount, expected) in amounts.iter().zip(expected.iter()) {
+                let info = DirectionalOverflowInfo::from_css_and_amount(*declared, *amount);
+                assert_eq!(
+                    info.needs_scrollbar(),
+                    *expected,
+                    "{:?} with amount {:?}",
+                    declared,
+                    amount
+                );
+            }
+        }
+    }
 }
@@ -160,7 +160,7 @@ use azul_layout::{
     callbacks::{
         Callback as LayoutCallback, CallbackInfo, ExternalSystemCallbacks,
     },
-    event_determination::determine_all_events,
+    event_determination::{theme_change_event, FrameInput},
     hit_test::FullHitTest,
     window::{LayoutWindow, ScrollbarDragState},
     window_state::{self, FullWindowState},
@@ -443,6 +443,9 @@ fn apply_focus_restyle(
         None, // hover
         None, // active
     );
+    if !restyle_result.changed_nodes.is_empty() {
+        layout_result.mark_changed();
+    }
 
     log_debug!(
         super::debug_server::LogCategory::Input,
@@ -476,6 +479,58 @@ fn apply_focus_restyle(
     }
 }
 
+// Event Propagation Helper
+
+/// Build the inputs for `propagate_event()` for one DOM: its node hierarchy
+/// and the nodes that have a callback for `filter` (or a capturing callback
+/// for the same event).
+fn propagation_inputs(
+    styled_dom: &azul_core::styled_dom::StyledDom,
+    filter: &azul_core::events::EventFilter,
+) -> (
+    azul_core::id::NodeHierarchy,
+    BTreeMap<NodeId, Vec<azul_core::events::EventFilter>>,
+) {
+    let items = styled_dom.node_hierarchy.as_container();
+    let nodes: Vec<azul_core::id::Node> = (0..items.len())
+        .map(|i| {
+            let item = &items.internal[i];
+            azul_core::id::Node {
+                parent: NodeId::from_usize(item.parent),
+                previous_sibling: NodeId::from_usize(item.previous_sibling),
+                next_sibling: NodeId::from_usize(item.next_sibling),
+                last_child: NodeId::from_usize(item.last_child),
+            }
+        })
+        .collect();
+
+    // Capturing callbacks for the same event take part in the same propagation
+    let capture = filter
+        .as_hover_event_filter()
+        .map(azul_core::events::EventFilter::Capture);
+
+    let node_data_container = styled_dom.node_data.as_container();
+    let mut callback_map = BTreeMap::new();
+    for node_idx in 0..node_data_container.len() {
+        let node_id = NodeId::new(node_idx);
+        let Some(nd) = node_data_container.get(node_id) else {
+            continue;
+        };
+        let matching_filters: Vec<_> = nd
+            .get_callbacks()
+            .as_ref()
+            .iter()
+            .filter(|cb| cb.event == *filter || Some(cb.event) == capture)
+            .map(|cb| cb.event)
+            .collect();
+        if !matching_filters.is_empty() {
+            callback_map.insert(node_id, matching_filters);
+        }
+    }
+
+    (azul_core::id::NodeHierarchy::new(nodes), callback_map)
+}
+
 // Platform-Specific Timer Management
 
 /// Hit test node structure for event routing.
@@ -1252,6 +1307,7 @@ pub trait PlatformWindow {
                 // Update StyledDom text content
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(&dom_id) {
+                        layout_result.mark_changed();
                         let idx = internal_node_id.index();
                         if idx < layout_result.styled_dom.node_data.as_ref().len() {
                             layout_result.styled_dom.node_data.as_container_mut()[internal_node_id]
@@ -1265,6 +1321,7 @@ pub trait PlatformWindow {
             CallbackChange::ChangeNodeImage { dom_id, node_id, image, update_type: _ } => {
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let idx = node_id.index();
                         if idx < layout_result.styled_dom.node_data.as_ref().len() {
                             layout_result.styled_dom.node_data.as_container_mut()[*node_id]
@@ -1297,6 +1354,7 @@ pub trait PlatformWindow {
             CallbackChange::ChangeNodeImageMask { dom_id, node_id, mask } => {
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let idx = node_id.index();
                         if idx < layout_result.styled_dom.node_data.as_ref().len() {
                             layout_result.styled_dom.node_data.as_container_mut()[*node_id]
@@ -1311,6 +1369,7 @@ pub trait PlatformWindow {
                 // Update StyledDom CSS properties
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let idx = node_id.index();
                         if idx < layout_result.styled_dom.node_data.as_ref().len() {
                             use azul_css::dynamic_selector::CssPropertyWithConditions;
@@ -1806,6 +1865,7 @@ pub trait PlatformWindow {
             } => {
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let parent_idx = parent_node_id.index();
                         if parent_idx < layout_result.styled_dom.node_data.as_ref().len() {
                             // Parse node_type_str into a NodeType
@@ -1840,6 +1900,7 @@ pub trait PlatformWindow {
             CallbackChange::DeleteNode { dom_id, node_id } => {
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let idx = node_id.index();
                         let node_count = layout_result.styled_dom.node_data.as_ref().len();
                         if idx < node_count && idx != 0 {
@@ -1890,6 +1951,7 @@ pub trait PlatformWindow {
             CallbackChange::SetNodeIdsAndClasses { dom_id, node_id, ids_and_classes } => {
                 if let Some(lw) = self.get_layout_window_mut() {
                     if let Some(layout_result) = lw.layout_results.get_mut(dom_id) {
+                        layout_result.mark_changed();
                         let idx = node_id.index();
                         if idx < layout_result.styled_dom.node_data.as_ref().len() {
                             layout_result.styled_dom.node_data.as_container_mut()[*node_id]
@@ -2218,6 +2280,7 @@ pub trait PlatformWindow {
                             let node_id = node_drag.node_id;
 
                             if let Some(layout_result) = layout_window.layout_results.get_mut(&dom_id) {
+                                layout_result.mark_changed();
                                 let mut styled_nodes = layout_result.styled_dom.styled_nodes.as_container_mut();
                                 if let Some(styled_node) = styled_nodes.get_mut(node_id) {
                                     styled_node.styled_node_state.dragging = true;
@@ -2241,6 +2304,7 @@ pub trait PlatformWindow {
                 if let Some(target_node_id) = target.node.into_crate_internal() {
                     if let Some(layout_window) = self.get_layout_window_mut() {
                         if let Some(layout_result) = layout_window.layout_results.get_mut(&target.dom) {
+                            layout_result.mark_changed();
                             let mut styled_nodes = layout_result.styled_dom.styled_nodes.as_container_mut();
                             if let Some(styled_node) = styled_nodes.get_mut(target_node_id) {
                                 styled_node.styled_node_state.drag_over = *active;
@@ -2291,6 +2355,7 @@ pub trait PlatformWindow {
                             let dom_id = node_drag.dom_id;
                             let node_id = node_drag.node_id;
                             if let Some(layout_result) = layout_window.layout_results.get_mut(&dom_id) {
+                                layout_result.mark_changed();
                                 let mut styled_nodes = layout_result.styled_dom.styled_nodes.as_container_mut();
                                 if let Some(styled_node) = styled_nodes.get_mut(node_id) {
                                     styled_node.styled_node_state.dragging = false;
@@ -2306,6 +2371,7 @@ pub trait PlatformWindow {
                                 let dom_id = drop_target.dom;
                                 if let Some(target_node_id) = drop_target.node.into_crate_internal() {
                                     if let Some(layout_result) = layout_window.layout_results.get_mut(&dom_id) {
+                                        layout_result.mark_changed();
                                         let mut styled_nodes = layout_result.styled_dom.styled_nodes.as_container_mut();
                                         if let Some(styled_node) = styled_nodes.get_mut(target_node_id) {
                                             styled_node.styled_node_state.drag_over = false;
@@ -2604,14 +2670,21 @@ pub trait PlatformWindow {
         let hit_test = {
             let layout_window = self.get_layout_window().unwrap();
 
-            crate::desktop::wr_translate2::fullhittest_new_webrender(
+            let mut hit_test = crate::desktop::wr_translate2::fullhittest_new_webrender(
                 &*resolved_hit_tester,
                 document_id,
                 focused_node,
                 &layout_window.layout_results,
                 &CursorPosition::InWindow(position),
                 hidpi_factor,
-            )
+            );
+
+            // Scrollbars are not tagged in the display list, add them from the scroll geometry
+            layout_window
+                .scroll_manager
+                .append_scrollbar_hits(&mut hit_test, position);
+
+            hit_test
         };
 
         // Store hit test in hover manager
@@ -2619,6 +2692,9 @@ pub trait PlatformWindow {
             layout_window
                 .hover_manager
                 .push_hit_test(InputPointId::Mouse, hit_test);
+            layout_window
+                .hover_manager
+                .update_hover_durations(azul_core::task::Instant::now());
         }
     }
 
@@ -2655,7 +2731,7 @@ pub trait PlatformWindow {
         use azul_core::{
             callbacks::{CoreCallbackData, Update},
             dom::{DomId, NodeId as CoreNodeId},
-            events::{EventFilter, EventPhase, SyntheticEvent},
+            events::{ComponentEventFilter, EventFilter, EventPhase, SyntheticEvent},
             id::NodeId,
             styled_dom::NodeHierarchyItem,
         };
@@ -2666,6 +2742,9 @@ pub trait PlatformWindow {
             dom_id: DomId,
             node_id: NodeId,
             callback_data: CoreCallbackData,
+            /// Callbacks reached by propagating the same event share a dispatch,
+            /// see `PropagationState`
+            dispatch: usize,
         }
 
         // ===================================================================
@@ -2679,8 +2758,11 @@ pub trait PlatformWindow {
 
             let focused_node = layout_window.focus_manager.get_focused_node().cloned();
             let mut planned = Vec::new();
+            // Non-propagating callbacks each get their own dispatch, numbered
+            // after the per-event dispatches of the propagating ones
+            let mut next_dispatch = events.len();
 
-            for event in events {
+            for (event_index, event) in events.iter().enumerate() {
                 let event_filters = azul_core::events::event_type_to_filters(
                     event.event_type,
                     &event.data,
@@ -2690,86 +2772,69 @@ pub trait PlatformWindow {
                     match filter {
                         EventFilter::Hover(_) => {
                             // W3C propagation: Capture → Target → Bubble
-                            let dom_id = event.target.dom;
+                            let mut dom_id = event.target.dom;
                             let layout_result = match layout_window.layout_results.get(&dom_id) {
                                 Some(lr) => lr,
                                 None => continue,
                             };
 
-                            // Build NodeHierarchy from NodeHierarchyItemVec for propagation
-                            let node_hierarchy = {
-                                let items = layout_result.styled_dom.node_hierarchy.as_container();
-                                let nodes: Vec<azul_core::id::Node> = (0..items.len())
-                                    .map(|i| {
-                                        let item = &items.internal[i];
-                                        azul_core::id::Node {
-                                            parent: NodeId::from_usize(item.parent),
-                                            previous_sibling: NodeId::from_usize(
-                                                item.previous_sibling,
-                                            ),
-                                            next_sibling: NodeId::from_usize(item.next_sibling),
-                                            last_child: NodeId::from_usize(item.last_child),
-                                        }
-                                    })
-                                    .collect();
-                                azul_core::id::NodeHierarchy::new(nodes)
-                            };
-
-                            // Build callback map: NodeId → Vec<EventFilter>
-                            let node_data_container =
-                                layout_result.styled_dom.node_data.as_container();
-                            let mut callback_map: std::collections::BTreeMap<
-                                NodeId,
-                                Vec<EventFilter>,
-                            > = std::collections::BTreeMap::new();
-
-                            for node_idx in 0..node_data_container.len() {
-                                let node_id = match NodeId::from_usize(node_idx + 1) {
-                                    // +1 for 1-based encoding
-                                    Some(nid) => nid,
-                                    None => NodeId::new(node_idx),
-                                };
-                                // NodeId::new(idx) creates 0-based NodeId directly
-                                let node_id = NodeId::new(node_idx);
-                                if let Some(nd) = node_data_container.get(node_id) {
-                                    let matching_filters: Vec<EventFilter> = nd
-                                        .get_callbacks()
-                                        .as_ref()
-                                        .iter()
-                                        .filter(|cb| cb.event == *filter)
-                                        .map(|cb| cb.event)
-                                        .collect();
-                                    if !matching_filters.is_empty() {
-                                        callback_map.insert(node_id, matching_filters);
-                                    }
-                                }
-                            }
-
-                            if callback_map.is_empty() {
-                                continue;
-                            }
-
                             // Run W3C event propagation
                             let mut event_clone = event.clone();
-                            let prop_result = azul_core::events::propagate_event(
+                            let (node_hierarchy, callback_map) =
+                                propagation_inputs(&layout_result.styled_dom, filter);
+                            let mut prop_result = azul_core::events::propagate_event(
                                 &mut event_clone,
                                 &node_hierarchy,
                                 &callback_map,
                             );
 
-                            // Collect actual CoreCallbackData for each matched node+filter
-                            for (node_id, matched_filter) in &prop_result.callbacks_to_invoke {
-                                if let Some(nd) = node_data_container.get(*node_id) {
-                                    for cb in nd.get_callbacks().as_ref().iter() {
-                                        if cb.event == *matched_filter {
-                                            planned.push(PlannedInvocation {
-                                                dom_id,
-                                                node_id: *node_id,
-                                                callback_data: cb.clone(),
-                                            });
+                            loop {
+                                // Collect actual CoreCallbackData for each matched node+filter
+                                let node_data_container = match layout_window.layout_results.get(&dom_id) {
+                                    Some(lr) => lr.styled_dom.node_data.as_container(),
+                                    None => break,
+                                };
+                                for (node_id, matched_filter) in &prop_result.callbacks_to_invoke {
+                                    if let Some(nd) = node_data_container.get(*node_id) {
+                                        for cb in nd.get_callbacks().as_ref().iter() {
+                                            if cb.event == *matched_filter {
+                                                planned.push(PlannedInvocation {
+                                                    dom_id,
+                                                    node_id: *node_id,
+                                                    callback_data: cb.clone(),
+                                                    dispatch: event_index,
+                                                });
+                                            }
                                         }
                                     }
                                 }
+
+                                // Keep bubbling out of a VirtualView into the DOM hosting it
+                                let Some((host_dom_id, host_node_id)) = layout_window
+                                    .virtual_view_manager
+                                    .get_host_of_nested_dom(dom_id)
+                                else {
+                                    break;
+                                };
+                                let Some(host_layout_result) =
+                                    layout_window.layout_results.get(&host_dom_id)
+                                else {
+                                    break;
+                                };
+                                let (node_hierarchy, callback_map) =
+                                    propagation_inputs(&host_layout_result.styled_dom, filter);
+                                prop_result = azul_core::events::propagate_event_to_host(
+                                    &mut event_clone,
+                                    azul_core::dom::DomNodeId {
+                                        dom: host_dom_id,
+                                        node: NodeHierarchyItemId::from_crate_internal(Some(
+                                            host_node_id,
+                                        )),
+                                    },
+                                    &node_hierarchy,
+                                    &callback_map,
+                                );
+                                dom_id = host_dom_id;
                             }
                         }
                         EventFilter::Focus(_) => {
@@ -2788,6 +2853,7 @@ pub trait PlatformWindow {
                                                         dom_id,
                                                         node_id,
                                                         callback_data: cb.clone(),
+                                                        dispatch: event_index,
                                                     });
                                                 }
                                             }
@@ -2805,10 +2871,13 @@ pub trait PlatformWindow {
                                     if let Some(nd) = ndc.get(node_id) {
                                         for cb in nd.get_callbacks().as_ref().iter() {
                                             if cb.event == *filter {
+                                                let dispatch = next_dispatch;
+                                                next_dispatch += 1;
                                                 planned.push(PlannedInvocation {
                                                     dom_id: *dom_id,
                                                     node_id,
                                                     callback_data: cb.clone(),
+                                                    dispatch,
                                                 });
                                             }
                                         }
@@ -2825,10 +2894,13 @@ pub trait PlatformWindow {
                                     if let Some(nd) = ndc.get(node_id) {
                                         for cb in nd.get_callbacks().as_ref().iter() {
                                             if cb.event == *filter {
+                                                let dispatch = next_dispatch;
+                                                next_dispatch += 1;
                                                 planned.push(PlannedInvocation {
                                                     dom_id: *dom_id,
                                                     node_id,
                                                     callback_data: cb.clone(),
+                                                    dispatch,
                                                 });
                                             }
                                         }
@@ -2836,8 +2908,48 @@ pub trait PlatformWindow {
                                 }
                             }
                         }
-                        // Not/Component filters: not used in event dispatch
-                        EventFilter::Not(_) | EventFilter::Component(_) => {}
+                        EventFilter::Component(ComponentEventFilter::BeforeUnmount) => {
+                            // The node is gone from the current DOM, its callbacks
+                            // were kept by the lifecycle manager
+                            if let Some(node_id) = event.target.node.into_crate_internal() {
+                                for cb in layout_window
+                                    .lifecycle_manager
+                                    .unmount_callbacks(&event.target)
+                                {
+                                    planned.push(PlannedInvocation {
+                                        dom_id: event.target.dom,
+                                        node_id,
+                                        callback_data: cb.clone(),
+                                        dispatch: event_index,
+                                    });
+                                }
+                            }
+                        }
+                        EventFilter::Component(_) => {
+                            // Component (lifecycle) events fire on the event target only
+                            let dom_id = event.target.dom;
+                            if let Some(node_id) = event.target.node.into_crate_internal() {
+                                if let Some(lr) = layout_window.layout_results.get(&dom_id) {
+                                    let ndc = lr.styled_dom.node_data.as_container();
+                                    if let Some(nd) = ndc.get(node_id) {
+                                        for cb in nd.get_callbacks().as_ref().iter() {
+                                            if cb.event == *filter {
+                                                planned.push(PlannedInvocation {
+                                                    dom_id,
+                                                    node_id,
+                                                    callback_data: cb.clone(),
+                                                    dispatch: event_index,
+                                                });
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        // Not filters: not used in event dispatch. Capture filters
+                        // are never produced by events, their callbacks are
+                        // collected by the propagation of the matching Hover filter.
+                        EventFilter::Not(_) | EventFilter::Capture(_) => {}
                     }
                 }
             }
@@ -2857,20 +2969,20 @@ pub trait PlatformWindow {
         let mut all_changes: Vec<azul_layout::callbacks::CallbackChange> = Vec::new();
         let mut any_prevent_default = false;
 
-        // Track propagation control flags (W3C semantics):
+        // Track propagation control (W3C semantics), per dispatch:
         //  - stop_propagation: remaining handlers on the *same* node still fire,
-        //    but handlers on different nodes are skipped.
-        //  - stop_immediate_propagation: no further handlers fire at all.
-        let mut propagation_stopped = false;
-        let mut propagation_stopped_node: Option<(DomId, NodeId)> = None;
+        //    but the event does not reach any other node.
+        //  - stop_immediate_propagation: no further handlers of the event fire.
+        // Callbacks of other dispatches (e.g. on a sibling node) are unaffected.
+        let mut propagation = azul_core::events::PropagationState::new();
 
         for planned in planned_callbacks {
-            // W3C stopImmediatePropagation: break immediately
-            if propagation_stopped && propagation_stopped_node.map_or(true, |(dom, nid)| {
-                dom != planned.dom_id || nid != planned.node_id
-            }) {
-                // We crossed to a different node and stop_propagation was called → skip
-                break;
+            let planned_node = azul_core::dom::DomNodeId {
+                dom: planned.dom_id,
+                node: NodeHierarchyItemId::from_crate_internal(Some(planned.node_id)),
+            };
+            if !propagation.should_invoke(planned.dispatch, planned_node) {
+                continue;
             }
 
             let mut callback = LayoutCallback::from_core(planned.callback_data.callback);
@@ -2910,21 +3022,25 @@ pub trait PlatformWindow {
             // Accumulate changes for later application
             all_changes.extend(changes);
 
-            // stopPropagation: record that we should stop after remaining same-node handlers
-            if should_stop_propagation && !propagation_stopped {
-                propagation_stopped = true;
-                propagation_stopped_node = Some((planned.dom_id, planned.node_id));
+            // stopPropagation: only the remaining handlers on this node still fire
+            if should_stop_propagation {
+                propagation.stop_propagation(planned.dispatch, planned_node);
             }
 
-            // stopImmediatePropagation: break immediately
+            // stopImmediatePropagation: no further handlers of this event fire
             if should_stop_immediate {
-                break;
+                propagation.stop_immediate_propagation(planned.dispatch);
             }
         }
 
         // Drop borrows before calling apply_user_change on self
         drop(borrows);
 
+        // Several callbacks may have changed the same CSS property on one node:
+        // only apply the final value once
+        let all_changes =
+            azul_layout::callbacks::CallbackChange::coalesce_css_changes(all_changes);
+
         // Apply all accumulated user changes, tracking max ProcessEventResult
         let mut changes_result = ProcessEventResult::DoNothing;
         for change in &all_changes {
@@ -3182,6 +3298,27 @@ pub trait PlatformWindow {
             return ProcessEventResult::DoNothing;
         }
 
+        // Get current timestamp
+        #[cfg(feature = "std")]
+        let timestamp = azul_core::task::Instant::from(std::time::Instant::now());
+        #[cfg(not(feature = "std"))]
+        let timestamp = azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0));
+
+        // Advance the key repeat timer before diffing, so a held key can
+        // produce KeyRepeat events
+        let previous_key = self
+            .get_previous_window_state()
+            .as_ref()
+            .and_then(|prev| prev.keyboard_state.current_virtual_keycode.into_option());
+        let current_window_state = self.get_current_window_state_mut();
+        let current_key = current_window_state
+            .keyboard_state
+            .current_virtual_keycode
+            .into_option();
+        current_window_state
+            .key_repeat
+            .advance(current_key, previous_key, timestamp.clone());
+
         // Get previous state (or use current as fallback for first frame)
         let has_previous = self.get_previous_window_state().is_some();
         let previous_state = self
@@ -3191,53 +3328,26 @@ pub trait PlatformWindow {
 
         let current_state = self.get_current_window_state();
 
-        // DEBUG: Print state comparison for mouse buttons
-
-        // Get gesture manager for gesture detection (if available)
-        let gesture_manager = self.get_layout_window().map(|lw| &lw.gesture_drag_manager);
-
-        // Detect all events that occurred by comparing states
-        // Using new SyntheticEvent architecture with determine_all_events()
-
-        // Get managers for event detection
-        let focus_manager = self.get_layout_window().map(|w| &w.focus_manager);
-        let file_drop_manager = self.get_layout_window().map(|w| &w.file_drop_manager);
-        let hover_manager = self.get_layout_window().map(|w| &w.hover_manager);
-
-        // Get EventProvider managers (text input, etc.)
-        let text_manager_ref = self.get_layout_window().map(|w| &w.text_input_manager);
-
-        // Build list of EventProvider managers
-        let mut event_providers: Vec<&dyn azul_core::events::EventProvider> = Vec::new();
-        if let Some(tm) = text_manager_ref.as_ref() {
-            event_providers.push(*tm as &dyn azul_core::events::EventProvider);
-        }
-
-        // Get current timestamp
-        #[cfg(feature = "std")]
-        let timestamp = azul_core::task::Instant::from(std::time::Instant::now());
-        #[cfg(not(feature = "std"))]
-        let timestamp = azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0));
-
-        // Determine all events (returns Vec<SyntheticEvent>)
-        let synthetic_events = if let (Some(fm), Some(fdm), Some(hm)) =
-            (focus_manager, file_drop_manager, hover_manager)
-        {
-            determine_all_events(
-                self.get_current_window_state(),
+        // Determine all events (window state diff + hover, focus, file drop,
+        // gesture and EventProvider managers)
+        let mut synthetic_events = match self.get_layout_window() {
+            Some(layout_window) => FrameInput {
+                current_state: self.get_current_window_state(),
                 previous_state,
-                hm,
-                fm,
-                fdm,
-                gesture_manager,
-                &event_providers,
-                timestamp,
-            )
-        } else {
+                layout_window,
+                timestamp: timestamp.clone(),
+            }
+            .process()
+            .events,
             // Fallback: no events if managers not available
-            Vec::new()
+            None => Vec::new(),
         };
 
+        // First frame: nothing to diff the theme against, report the initial one
+        if !has_previous {
+            synthetic_events.push(theme_change_event(current_state.theme, timestamp));
+        }
+
         if synthetic_events.is_empty() {
             return ProcessEventResult::DoNothing;
         }
@@ -3359,6 +3469,12 @@ pub trait PlatformWindow {
             self.dispatch_events_propagated(&pre_filter.user_events);
         result = result.max(changes_result);
 
+        // Lifecycle events of the last relayout have been dispatched now
+        // (BeforeUnmount callbacks were looked up from the lifecycle manager)
+        if let Some(layout_window) = self.get_layout_window_mut() {
+            layout_window.lifecycle_manager.clear();
+        }
+
         let mut should_recurse = false;
 
         use azul_core::callbacks::Update;
@@ -3373,6 +3489,11 @@ pub trait PlatformWindow {
                 result = result.max(ProcessEventResult::ShouldRegenerateDomAllWindows);
                 should_recurse = true;
             }
+            Update::ScrollOnly => {
+                // scroll offsets were already applied via CallbackChanges,
+                // re-compositing the existing display list is enough
+                result = result.max(ProcessEventResult::ShouldReRenderCurrentWindow);
+            }
             Update::DoNothing => {}
         }
 
@@ -3581,6 +3702,12 @@ pub trait PlatformWindow {
                             old_focus,
                         });
                         result = result.max(r);
+                        if let Some(layout_window) = self.get_layout_window_mut() {
+                            layout_window.focus_manager.set_focused_node_with_origin(
+                                Some(new_focus_target),
+                                azul_layout::managers::focus_cursor::FocusOrigin::Pointer,
+                            );
+                        }
                         mouse_click_focus_changed = true;
                     }
                 }
@@ -3622,6 +3749,12 @@ pub trait PlatformWindow {
                                             old_focus: focused_node,
                                         });
                                         result = result.max(r);
+                                        if let Some(layout_window) = self.get_layout_window_mut() {
+                                            layout_window.focus_manager.set_focused_node_with_origin(
+                                                new_focus_node,
+                                                azul_layout::managers::focus_cursor::FocusOrigin::Keyboard,
+                                            );
+                                        }
                                         default_action_focus_changed = true;
                                     }
                                 }
@@ -3731,39 +3864,26 @@ pub trait PlatformWindow {
                     { azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0)) }
                 };
 
-                let mut focus_events = Vec::new();
+                // FocusVisible only for keyboard-initiated focus (:focus-visible)
+                let focus_visible = self
+                    .get_layout_window()
+                    .map(|lw| lw.focus_manager.is_focus_visible())
+                    .unwrap_or(false);
 
-                // FocusLost (Blur) on old node
-                if let Some(old_node) = old_focus {
-                    log_debug!(
-                        super::debug_server::LogCategory::Input,
-                        "[Event] Dispatching FocusLost to node {:?}",
-                        old_node
-                    );
-                    focus_events.push(azul_core::events::SyntheticEvent::new(
-                        azul_core::events::EventType::Blur,
-                        azul_core::events::EventSource::User,
-                        old_node,
-                        now.clone(),
-                        azul_core::events::EventData::None,
-                    ));
-                }
+                log_debug!(
+                    super::debug_server::LogCategory::Input,
+                    "[Event] Dispatching FocusLost to {:?}, then FocusReceived to {:?}",
+                    old_focus,
+                    new_focus
+                );
 
-                // FocusReceived on new node
-                if let Some(new_node) = new_focus {
-                    log_debug!(
-                        super::debug_server::LogCategory::Input,
-                        "[Event] Dispatching FocusReceived to node {:?}",
-                        new_node
-                    );
-                    focus_events.push(azul_core::events::SyntheticEvent::new(
-                        azul_core::events::EventType::Focus,
-                        azul_core::events::EventSource::User,
-                        new_node,
-                        now.clone(),
-                        azul_core::events::EventData::None,
-                    ));
-                }
+                // FocusLost (Blur) on the old node always precedes FocusReceived
+                let focus_events = azul_layout::event_determination::focus_transition_events(
+                    old_focus,
+                    new_focus,
+                    focus_visible,
+                    now,
+                );
 
                 if !focus_events.is_empty() {
                     let (focus_changes_result, focus_update, _) = self.dispatch_events_propagated(&focus_events);
@@ -464,6 +464,12 @@ pub fn regenerate_layout(
                             scrollbar_info.needs_horizontal,
                             scrollbar_info.needs_vertical,
                         );
+                        layout_window.scroll_manager.set_scrollable_axes(
+                            *dom_id,
+                            dom_node_id,
+                            node.computed_style.overflow_x.is_user_scrollable(),
+                            node.computed_style.overflow_y.is_user_scrollable(),
+                        );
 
                         log_debug!(LogCategory::Layout,
                             "[regenerate_layout] Registered scroll node: dom={:?} node={:?} container={:?} content={:?}",
@@ -588,6 +594,12 @@ pub fn incremental_relayout(
                             scrollbar_info.needs_horizontal,
                             scrollbar_info.needs_vertical,
                         );
+                        layout_window.scroll_manager.set_scrollable_axes(
+                            *_dom_id,
+                            dom_node_id,
+                            node.computed_style.overflow_x.is_user_scrollable(),
+                            node.computed_style.overflow_y.is_user_scrollable(),
+                        );
                     }
                 }
             }
@@ -817,7 +829,9 @@ fn update_managers_with_node_moves(
     }
     
     // 2. Update ScrollManager
-    // The ScrollManager tracks scroll offsets by DomNodeId, which also needs to be updated
+    // The ScrollManager tracks scroll offsets by DomNodeId, which also needs to be updated.
+    // node_moves contains every matched node, so unmatched scroll states are stale.
+    layout_window.scroll_manager.prune_removed_nodes(dom_id, &node_id_map);
     layout_window.scroll_manager.remap_node_ids(dom_id, &node_id_map);
     
     // 3. Update CursorManager (text cursor position)
@@ -840,6 +840,8 @@ impl WaylandWindow {
                     keyboard_state: options.window_state.keyboard_state.clone(),
                     mouse_state: options.window_state.mouse_state.clone(),
                     touch_state: options.window_state.touch_state.clone(),
+                    gesture_state: Default::default(),
+                    key_repeat: Default::default(),
                     ime_position: options.window_state.ime_position,
                     platform_specific_options: options.window_state.platform_specific_options.clone(),
                     renderer_options: options.window_state.renderer_options,
@@ -1402,6 +1404,9 @@ impl WaylandWindow {
                 Update::RefreshDom | Update::RefreshDomAllWindows => {
                     event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldRegenerateDomCurrentWindow);
                 }
+                Update::ScrollOnly => {
+                    event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldReRenderCurrentWindow);
+                }
                 Update::DoNothing => {}
             }
 
@@ -1469,6 +1474,9 @@ impl WaylandWindow {
         self.common.current_window_state
             .keyboard_state
             .current_virtual_keycode = OptionVirtualKeyCode::Some(virtual_keycode);
+        self.common.current_window_state
+            .keyboard_state
+            .current_scancode = Some(key).into();
 
         // Update pressed_virtual_keycodes and pressed_scancodes lists
         if is_pressed {
@@ -3427,6 +3435,8 @@ impl WaylandPopup {
             keyboard_state: parent.current_window_state.keyboard_state.clone(),
             mouse_state: parent.current_window_state.mouse_state.clone(),
             touch_state: parent.current_window_state.touch_state.clone(),
+            gesture_state: Default::default(),
+            key_repeat: Default::default(),
             ime_position: parent.current_window_state.ime_position,
             platform_specific_options: parent
                 .current_window_state
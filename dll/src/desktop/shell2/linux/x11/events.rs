@@ -459,6 +459,9 @@ impl X11Window {
                 self.common.current_window_state
                     .keyboard_state
                     .current_virtual_keycode = Some(vk).into();
+                self.common.current_window_state
+                    .keyboard_state
+                    .current_scancode = Some(event.keycode as u32).into();
 
                 // Track scancode (X11 keycode is the scancode)
                 self.common.current_window_state
@@ -473,6 +476,9 @@ impl X11Window {
                 self.common.current_window_state
                     .keyboard_state
                     .current_virtual_keycode = None.into();
+                self.common.current_window_state
+                    .keyboard_state
+                    .current_scancode = None.into();
 
                 // Remove scancode
                 self.common.current_window_state
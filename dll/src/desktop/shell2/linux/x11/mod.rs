@@ -837,6 +837,8 @@ impl X11Window {
                     keyboard_state: Default::default(),
                     mouse_state: Default::default(),
                     touch_state: Default::default(),
+                    gesture_state: Default::default(),
+                    key_repeat: Default::default(),
                     ime_position: options.window_state.ime_position,
                     platform_specific_options: options.window_state.platform_specific_options.clone(),
                     renderer_options: options.window_state.renderer_options,
@@ -1223,6 +1225,9 @@ impl X11Window {
                 Update::RefreshDom | Update::RefreshDomAllWindows => {
                     event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldRegenerateDomCurrentWindow);
                 }
+                Update::ScrollOnly => {
+                    event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldReRenderCurrentWindow);
+                }
                 Update::DoNothing => {}
             }
 
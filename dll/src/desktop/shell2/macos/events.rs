@@ -861,6 +861,8 @@ impl MacOSWindow {
             }
             keyboard_state.current_virtual_keycode =
                 azul_core::window::OptionVirtualKeyCode::Some(vk);
+            // macOS key codes are tied to the key position, not the layout
+            keyboard_state.current_scancode = Some(u32::from(keycode)).into();
         } else {
             // Remove from pressed keys
             let pressed_vec: Vec<VirtualKeyCode> = keyboard_state
@@ -873,6 +875,7 @@ impl MacOSWindow {
             keyboard_state.pressed_virtual_keycodes =
                 azul_core::window::VirtualKeyCodeVec::from_vec(pressed_vec);
             keyboard_state.current_virtual_keycode = azul_core::window::OptionVirtualKeyCode::None;
+            keyboard_state.current_scancode = None.into();
         }
     }
 
@@ -2591,6 +2591,8 @@ impl MacOSWindow {
             keyboard_state: Default::default(),
             mouse_state: Default::default(),
             touch_state: Default::default(),
+            gesture_state: Default::default(),
+            key_repeat: Default::default(),
             ime_position: options.window_state.ime_position,
             platform_specific_options: options.window_state.platform_specific_options.clone(),
             renderer_options: options.window_state.renderer_options,
@@ -323,7 +323,9 @@ impl StubWindow {
 
         // Rebuild CPU hit-tester from new layout results
         if let Some(lw) = self.common.layout_window.as_ref() {
-            self.cpu_backend.hit_tester.rebuild_from_layout(&lw.layout_results);
+            self.cpu_backend
+                .hit_tester
+                .rebuild_from_layout_with_transforms(&lw.layout_results, &lw.gpu_state_manager.caches);
         }
 
         // Mark that frame needs regeneration
@@ -371,6 +371,8 @@ impl Win32Window {
             keyboard_state: Default::default(),
             mouse_state: Default::default(),
             touch_state: Default::default(),
+            gesture_state: Default::default(),
+            key_repeat: Default::default(),
             ime_position: initial_window_state.ime_position,
             platform_specific_options: initial_window_state.platform_specific_options.clone(),
             renderer_options: initial_window_state.renderer_options,
@@ -2577,6 +2579,10 @@ unsafe extern "system" fn window_proc(
                     .keyboard_state
                     .current_virtual_keycode =
                     azul_core::window::OptionVirtualKeyCode::Some(virtual_key);
+                window
+                    .current_window_state
+                    .keyboard_state
+                    .current_scancode = Some(scan_code).into();
                 window
                     .current_window_state
                     .keyboard_state
@@ -2614,6 +2620,10 @@ unsafe extern "system" fn window_proc(
                     .current_window_state
                     .keyboard_state
                     .current_virtual_keycode = azul_core::window::OptionVirtualKeyCode::None;
+                window
+                    .current_window_state
+                    .keyboard_state
+                    .current_scancode = None.into();
                 window
                     .current_window_state
                     .keyboard_state
@@ -2706,12 +2716,13 @@ unsafe extern "system" fn window_proc(
 
             if lparam & GCS_RESULTSTR != 0 {
                 // Final composed string is ready - clear composition preview
-                window.ime_composition = None;
+                window.set_ime_composition(None);
 
                 // Let default processing handle it which will generate WM_IME_CHAR messages
                 (window.win32.user32.DefWindowProcW)(hwnd, msg, wparam, lparam)
             } else if lparam & GCS_COMPSTR != 0 {
                 // Intermediate composition - extract and store it
+                let mut composition = None;
                 if let Some(ref imm32) = window.win32.imm32 {
                     unsafe {
                         // Get IME context
@@ -2739,13 +2750,8 @@ unsafe extern "system" fn window_proc(
                                 );
 
                                 if result > 0 {
-                                    // Convert to String and store
-                                    window.ime_composition = String::from_utf16(&buffer).ok();
-                                    log_trace!(
-                                        LogCategory::Input,
-                                        "IME Composition: {:?}",
-                                        window.ime_composition
-                                    );
+                                    // Convert to String
+                                    composition = String::from_utf16(&buffer).ok();
                                 }
                             }
 
@@ -2755,6 +2761,11 @@ unsafe extern "system" fn window_proc(
                     }
                 }
 
+                if composition.is_some() {
+                    log_trace!(LogCategory::Input, "IME Composition: {:?}", composition);
+                    window.set_ime_composition(composition);
+                }
+
                 // Let Windows show composition window by default
                 (window.win32.user32.DefWindowProcW)(hwnd, msg, wparam, lparam)
             } else {
@@ -2764,7 +2775,7 @@ unsafe extern "system" fn window_proc(
 
         WM_IME_ENDCOMPOSITION => {
             // IME composition ended - clear composition preview
-            window.ime_composition = None;
+            window.set_ime_composition(None);
             (window.win32.user32.DefWindowProcW)(hwnd, msg, wparam, lparam)
         }
 
@@ -2901,6 +2912,9 @@ unsafe extern "system" fn window_proc(
                         Update::RefreshDom | Update::RefreshDomAllWindows => {
                             event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldRegenerateDomCurrentWindow);
                         }
+                        Update::ScrollOnly => {
+                            event_result = event_result.max(azul_core::events::ProcessEventResult::ShouldReRenderCurrentWindow);
+                        }
                         Update::DoNothing => {}
                     }
 
@@ -3755,6 +3769,18 @@ impl Win32Window {
             self.set_ime_composition_window(rect);
         }
     }
+
+    /// Store the IME preedit string and mirror it into the keyboard state.
+    ///
+    /// Only the window state is updated: the composition events are emitted by
+    /// the next regular event pass, which diffs it against the previous state.
+    fn set_ime_composition(&mut self, composition: Option<String>) {
+        use azul_css::AzString;
+
+        self.ime_composition = composition.clone();
+        self.common.current_window_state.keyboard_state.ime_composition =
+            composition.map(AzString::from).into();
+    }
 }
 
 #[cfg(test)]
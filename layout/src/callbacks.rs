@@ -27,11 +27,11 @@ use azul_core::{
     impl_callback,
     menu::Menu,
     refany::{OptionRefAny, RefAny},
-    resources::{ImageCache, ImageMask, ImageRef, RendererResources},
+    resources::{DpiScaleFactor, ImageCache, ImageMask, ImageRef, RendererResources},
     selection::{Selection, SelectionRange, SelectionRangeVec, SelectionState, TextCursor},
     styled_dom::{NodeHierarchyItemId, NodeHierarchyItemIdVec, StyledDom},
     task::{self, GetSystemTimeCallback, Instant, ThreadId, ThreadIdVec, TimerId, TimerIdVec},
-    window::{KeyboardState, Monitor, MonitorVec, MouseState, OptionMonitor, RawWindowHandle, WindowFlags, WindowSize},
+    window::{KeyboardState, Monitor, MonitorVec, MouseState, OptionMonitor, OptionVirtualKeyCode, RawWindowHandle, TrackpadGestureState, WindowFlags, WindowSize, WindowTheme},
     FastBTreeSet, FastHashMap,
 };
 use azul_css::{
@@ -41,7 +41,7 @@ use azul_css::{
         property::{CssProperty, CssPropertyType, CssPropertyVec},
     },
     system::SystemStyle,
-    AzString, StringVec,
+    AzString, OptionString, OptionU32, StringVec,
 };
 use rust_fontconfig::FcFontCache;
 
@@ -488,6 +488,59 @@ pub enum CallbackChange {
     },
 }
 
+impl CallbackChange {
+    /// Merges all `ChangeNodeCssProperties` targeting the same node into a single change.
+    ///
+    /// When several callbacks change the same node, the merged change keeps the
+    /// position of the first one and contains the last value for each property,
+    /// so every property is restyled (and reported as changed) only once.
+    pub fn coalesce_css_changes(changes: Vec<CallbackChange>) -> Vec<CallbackChange> {
+        let mut result: Vec<CallbackChange> = Vec::with_capacity(changes.len());
+        let mut css_change_index: BTreeMap<(DomId, NodeId), usize> = BTreeMap::new();
+
+        for change in changes {
+            let (dom_id, node_id, properties) = match change {
+                CallbackChange::ChangeNodeCssProperties {
+                    dom_id,
+                    node_id,
+                    properties,
+                } => (dom_id, node_id, properties),
+                other => {
+                    result.push(other);
+                    continue;
+                }
+            };
+
+            match css_change_index.get(&(dom_id, node_id)) {
+                Some(&idx) => {
+                    if let CallbackChange::ChangeNodeCssProperties {
+                        properties: existing,
+                        ..
+                    } = &mut result[idx]
+                    {
+                        let mut merged = existing.as_ref().to_vec();
+                        merged.extend(properties.as_ref().iter().cloned());
+                        *existing = azul_core::styled_dom::coalesce_css_properties(&merged).into();
+                    }
+                }
+                None => {
+                    css_change_index.insert((dom_id, node_id), result.len());
+                    result.push(CallbackChange::ChangeNodeCssProperties {
+                        dom_id,
+                        node_id,
+                        properties: azul_core::styled_dom::coalesce_css_properties(
+                            properties.as_ref(),
+                        )
+                        .into(),
+                    });
+                }
+            }
+        }
+
+        result
+    }
+}
+
 /// Main callback type for UI event handling
 pub type CallbackType = extern "C" fn(RefAny, CallbackInfo) -> Update;
 
@@ -1225,6 +1278,15 @@ impl CallbackInfo {
             .get_pending_changeset()
     }
 
+    /// Get the single character typed in the current text input event (if any)
+    ///
+    /// Returns None if no text input is being processed or if the input is a
+    /// multi-character sequence (IME commit, dead key composition) - use
+    /// `get_text_changeset()` and its `inserted_text` for those.
+    pub fn get_text_input_char(&self) -> Option<char> {
+        self.get_text_changeset()?.inserted_char()
+    }
+
     /// Set/override the text changeset for the current text input operation
     ///
     /// This allows you to modify what text will be inserted during text input events.
@@ -1790,6 +1852,8 @@ impl CallbackInfo {
 
     // Node Data and State
 
+    /// Get the dataset attached to a node (see `Dom::with_dataset`), e.g. to find
+    /// out which list item was clicked. The returned `RefAny` is a shallow clone.
     pub fn get_dataset(&mut self, node_id: DomNodeId) -> Option<RefAny> {
         let layout_window = self.get_layout_window();
         let layout_result = layout_window.get_layout_result(&node_id.dom)?;
@@ -2120,6 +2184,69 @@ impl CallbackInfo {
         )
     }
 
+    /// Get the trackpad magnify / rotate gesture state, e.g. to read the
+    /// cumulative zoom in a `WindowEventFilter::Magnify` callback
+    pub fn get_trackpad_gesture_state(&self) -> TrackpadGestureState {
+        self.get_current_window_state().gesture_state
+    }
+
+    /// Get the current window theme, e.g. to restyle in a `ThemeChange` callback
+    pub fn get_theme(&self) -> WindowTheme {
+        self.get_current_window_state().theme
+    }
+
+    /// Get the layout-dependent virtual keycode of the key that changed state
+    pub fn get_current_virtual_keycode(&self) -> OptionVirtualKeyCode {
+        self.get_current_window_state()
+            .keyboard_state
+            .current_virtual_keycode
+    }
+
+    /// Get the physical scancode of the key that changed state, e.g. to bind
+    /// movement to WASD positions regardless of the keyboard layout
+    pub fn get_current_scancode(&self) -> OptionU32 {
+        self.get_current_window_state().keyboard_state.current_scancode
+    }
+
+    /// Get the current IME preedit string, `None` if no composition is active
+    pub fn get_ime_composition(&self) -> OptionString {
+        self.get_current_window_state()
+            .keyboard_state
+            .ime_composition
+            .clone()
+    }
+
+    /// Get the bitmask of currently held mouse buttons
+    /// (1 = left, 2 = right, 4 = middle), e.g. `3` for a left+right chord
+    pub fn get_mouse_buttons(&self) -> u8 {
+        self.get_current_window_state().mouse_state.buttons()
+    }
+
+    /// Get how far the mouse cursor moved since the previous frame
+    ///
+    /// Returns `None` if there is no previous window state or the cursor
+    /// was outside the window in either frame.
+    pub fn get_mouse_delta(&self) -> OptionLogicalPosition {
+        self.get_previous_window_state()
+            .as_ref()
+            .and_then(|prev| self.get_current_window_state().get_mouse_delta(prev))
+            .into()
+    }
+
+    /// Get the current scale factor of the window (`dpi / 96`)
+    ///
+    /// Changes when the window moves to a monitor with a different DPI,
+    /// see `WindowEventFilter::DpiChanged`.
+    pub fn get_hidpi_factor(&self) -> DpiScaleFactor {
+        self.get_current_window_state().size.get_hidpi_factor()
+    }
+
+    /// Get how much the window size changed since the previous frame
+    pub fn get_window_size_delta(&self) -> Option<LogicalSize> {
+        let prev = self.get_previous_window_state().as_ref()?;
+        Some(self.get_current_window_state().get_size_delta(prev))
+    }
+
     // Cursor and Input
 
     pub fn get_cursor_relative_to_node(&self) -> azul_core::geom::OptionCursorNodePosition {
@@ -2230,6 +2357,27 @@ impl CallbackInfo {
         }
     }
 
+    /// Get the **incremental** (frame-to-frame) drag delta in window-local
+    /// coordinates, i.e. how far the cursor moved since the last sample while
+    /// the button was held. Returns `None` if no button is held or fewer than
+    /// 2 samples exist.
+    pub fn get_drag_delta_incremental(&self) -> azul_core::geom::OptionDragDelta {
+        use azul_core::geom::{DragDelta, OptionDragDelta};
+        let gm = self.get_gesture_drag_manager();
+        match gm.get_drag_delta_incremental() {
+            Some((dx, dy)) => OptionDragDelta::Some(DragDelta::new(dx, dy)),
+            None => OptionDragDelta::None,
+        }
+    }
+
+    /// Get the window-local position where the mouse button of the current
+    /// drag went down. Returns `None` if no button is held.
+    pub fn get_drag_start_position(&self) -> OptionLogicalPosition {
+        self.get_gesture_drag_manager()
+            .get_drag_start_position()
+            .into()
+    }
+
     pub fn get_current_window_handle(&self) -> RawWindowHandle {
         unsafe { (*self.ref_data).current_window_handle.clone() }
     }
@@ -2494,6 +2642,16 @@ impl CallbackInfo {
         self.cursor_in_viewport.into_option()
     }
 
+    /// Get the window edge or corner under the cursor, for starting a resize
+    /// of a borderless window. `border` is the width of the grab area in logical pixels.
+    pub fn get_window_resize_edge(&self, border: f32) -> Option<azul_core::drag::WindowResizeEdge> {
+        azul_core::drag::WindowResizeEdge::from_cursor(
+            self.get_cursor_position()?,
+            self.get_current_window_state().size.dimensions,
+            border,
+        )
+    }
+
     /// Get the layout rectangle of the currently hit node (in logical coordinates)
     pub fn get_hit_node_layout_rect(&self) -> Option<LogicalRect> {
         self.get_layout_window()
@@ -3040,6 +3198,12 @@ impl CallbackInfo {
             .has_sufficient_history_for_gestures(&InputPointId::Mouse)
     }
 
+    /// Get how long the mouse cursor has been resting on `node`, e.g. to show
+    /// a tooltip after a delay. Zero if the node is not hovered.
+    pub fn get_hovered_for(&self, node: DomNodeId) -> azul_core::task::Duration {
+        self.get_hover_manager().hovered_for(node)
+    }
+
     // File Drop Manager Access
 
     /// Get immutable reference to the file drop manager
@@ -8,13 +8,13 @@ use azul_core::{
     dom::{DomId, DomNodeId},
     events::{
         deduplicate_synthetic_events, EventData, EventProvider, EventSource, EventType,
-        KeyModifiers, MouseButton, MouseEventData, SyntheticEvent, WindowEventData,
+        MouseButton, MouseEventData, SyntheticEvent, WindowEventData,
     },
     geom::{LogicalPosition, LogicalRect},
     id::NodeId,
     styled_dom::NodeHierarchyItemId,
     task::{Instant, SystemTick},
-    window::{CursorPosition, WindowPosition},
+    window::{CursorPosition, KeyboardState, VirtualKeyCode, WindowPosition, WindowTheme},
 };
 
 use std::collections::BTreeSet;
@@ -118,6 +118,8 @@ fn detect_window_state_events(
                     size: current.size.dimensions.clone(),
                 }),
                 position: None,
+                hidpi_factor: None,
+                theme: None,
             }),
         ));
     }
@@ -144,6 +146,8 @@ fn detect_window_state_events(
                 EventData::Window(WindowEventData {
                     size: None,
                     position: Some(pos),
+                    hidpi_factor: None,
+                    theme: None,
                 }),
             ));
         }
@@ -151,16 +155,7 @@ fn detect_window_state_events(
 
     // Theme changed
     if current.theme != previous.theme {
-        events.push(SyntheticEvent::new(
-            EventType::ThemeChange,
-            EventSource::User,
-            DomNodeId {
-                dom: DomId { inner: 0 },
-                node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)),
-            },
-            timestamp.clone(),
-            EventData::None,
-        ));
+        events.push(theme_change_event(current.theme, timestamp.clone()));
     }
 
     // Mouse entered window
@@ -229,7 +224,8 @@ fn get_all_hovered_nodes(
 /// - Mouse movement (MouseOver)
 /// - Keyboard events (VirtualKeyDown/Up)
 /// - Window state changes (resize, move, theme, focus)
-/// - Gesture events (DragStart, Drag, DragEnd, DoubleClick, LongPress, Swipe, Pinch, Rotate, Pen)
+/// - Gesture events (DragStart, Drag, DragEnd, DoubleClick, TripleClick, LongPress, Swipe, Pinch,
+///   Rotate, Pen)
 /// - File drop events (HoveredFile, DroppedFile)
 ///
 /// ## Arguments
@@ -272,17 +268,10 @@ pub fn determine_all_events(
         .unwrap_or(LogicalPosition { x: 0.0, y: 0.0 });
 
     // Helper: build key modifiers from keyboard state
-    let modifiers = KeyModifiers {
-        shift: current_state.keyboard_state.shift_down(),
-        ctrl: current_state.keyboard_state.ctrl_down(),
-        alt: current_state.keyboard_state.alt_down(),
-        meta: current_state.keyboard_state.super_down(),
-    };
+    let modifiers = current_state.keyboard_state.modifiers();
 
     // Helper: compute mouse buttons bitmask
-    let buttons: u8 = (if current_state.mouse_state.left_down { 1 } else { 0 })
-        | (if current_state.mouse_state.right_down { 2 } else { 0 })
-        | (if current_state.mouse_state.middle_down { 4 } else { 0 });
+    let buttons = current_state.mouse_state.buttons();
 
     // Helper: get deepest hovered node as the event target for mouse events
     let mouse_target = hover_manager
@@ -373,6 +362,17 @@ pub fn determine_all_events(
         ));
     }
 
+    // Chord transition: a single event, even if several buttons changed at once
+    if buttons != previous_state.mouse_state.buttons() {
+        events.push(SyntheticEvent::new(
+            EventType::MouseButtonsChange,
+            EventSource::User,
+            mouse_target.clone(),
+            timestamp.clone(),
+            make_mouse_data(MouseButton::Other(buttons)),
+        ));
+    }
+
     // ========================================================================
     // Click synthesis: if left mouse released on the same node as down
     // ========================================================================
@@ -520,6 +520,77 @@ pub fn determine_all_events(
         ));
     }
 
+    // KeyRepeat: same key held across frames, throttled by the repeat delay / interval
+    let key_repeat_due = current_state
+        .key_repeat
+        .is_repeat_due(&previous_state.key_repeat, &timestamp);
+    if current_key.is_some() && current_key == previous_key && key_repeat_due {
+        events.push(SyntheticEvent::new(
+            EventType::KeyRepeat,
+            EventSource::User,
+            focus_target.clone(),
+            timestamp.clone(),
+            EventData::None,
+        ));
+    }
+
+    // Clipboard shortcuts: only meaningful while a node has focus
+    if let Some(focused) = focus_manager.get_focused_node() {
+        let key_pressed = current_key.is_some() && current_key != previous_key;
+        let shortcut = clipboard_shortcut(&current_state.keyboard_state, cfg!(target_os = "macos"));
+        if let (true, Some(event_type)) = (key_pressed, shortcut) {
+            events.push(SyntheticEvent::new(
+                event_type,
+                EventSource::User,
+                focused.clone(),
+                timestamp.clone(),
+                EventData::None,
+            ));
+        }
+    }
+
+    // IME composition: start / preedit update / end (commit or cancel)
+    let current_preedit = current_state.keyboard_state.ime_composition.as_ref();
+    let previous_preedit = previous_state.keyboard_state.ime_composition.as_ref();
+    let composition_events: &[EventType] = match (previous_preedit, current_preedit) {
+        (None, Some(s)) if s.as_str().is_empty() => &[EventType::CompositionStart],
+        (None, Some(_)) => &[EventType::CompositionStart, EventType::CompositionUpdate],
+        (Some(old), Some(new)) if old != new => &[EventType::CompositionUpdate],
+        (Some(_), None) => &[EventType::CompositionEnd],
+        _ => &[],
+    };
+    for event_type in composition_events {
+        events.push(SyntheticEvent::new(
+            *event_type,
+            EventSource::User,
+            focus_target.clone(),
+            timestamp.clone(),
+            EventData::None,
+        ));
+    }
+
+    // Trackpad magnify / rotate: one event per begin, change and end update
+    let current_gesture = &current_state.gesture_state;
+    let previous_gesture = &previous_state.gesture_state;
+    if current_gesture.magnify_changed(previous_gesture) {
+        events.push(SyntheticEvent::new(
+            EventType::Magnify,
+            EventSource::User,
+            root_node.clone(),
+            timestamp.clone(),
+            EventData::None,
+        ));
+    }
+    if current_gesture.rotate_changed(previous_gesture) {
+        events.push(SyntheticEvent::new(
+            EventType::Rotate,
+            EventSource::User,
+            root_node.clone(),
+            timestamp.clone(),
+            EventData::None,
+        ));
+    }
+
     // Window State Events
 
     // Window resize
@@ -535,6 +606,8 @@ pub fn determine_all_events(
                     size: current_state.size.dimensions.clone(),
                 }),
                 position: None,
+                hidpi_factor: None,
+                theme: None,
             }),
         ));
     }
@@ -553,6 +626,8 @@ pub fn determine_all_events(
                         x: phys_pos.x as f32,
                         y: phys_pos.y as f32,
                     }),
+                    hidpi_factor: None,
+                    theme: None,
                 }),
             ));
         }
@@ -591,13 +666,7 @@ pub fn determine_all_events(
 
     // Theme changed
     if current_state.theme != previous_state.theme {
-        events.push(SyntheticEvent::new(
-            EventType::ThemeChange,
-            EventSource::User,
-            root_node.clone(),
-            timestamp.clone(),
-            EventData::None,
-        ));
+        events.push(theme_change_event(current_state.theme, timestamp.clone()));
     }
 
     // DPI changed (moved to a different-DPI monitor, or system DPI setting changed)
@@ -607,7 +676,12 @@ pub fn determine_all_events(
             EventSource::User,
             root_node.clone(),
             timestamp.clone(),
-            EventData::None,
+            EventData::Window(WindowEventData {
+                size: None,
+                position: None,
+                hidpi_factor: Some(current_state.size.get_hidpi_factor().inner.get()),
+                theme: None,
+            }),
         ));
     }
 
@@ -652,8 +726,9 @@ pub fn determine_all_events(
     if let Some(manager) = gesture_manager {
         let event_was_mouse_release = !current_mouse_down && previous_mouse_down;
 
-        // Detect DragStart (targeted at hovered node)
-        if let Some(_detected_drag) = manager.detect_drag() {
+        // Detect DragStart (targeted at hovered node), once per session when the
+        // movement first crosses the drag threshold
+        if let Some(_detected_drag) = manager.detect_drag_start() {
             if !manager.is_dragging() {
                 events.push(SyntheticEvent::new(
                     EventType::DragStart,
@@ -665,8 +740,11 @@ pub fn determine_all_events(
             }
         }
 
-        // Detect Drag (continuous movement, targeted at hovered node)
-        if manager.is_dragging() && current_mouse_down {
+        // Detect Drag (continuous movement, targeted at hovered node). Plain
+        // button-held movement past the threshold counts too, not only active
+        // node / window / selection drags.
+        let dragging = manager.is_dragging() || manager.detect_drag().is_some();
+        if dragging && current_mouse_down {
             let current_pos = current_state.mouse_state.cursor_position.get_position();
             let previous_pos = previous_state.mouse_state.cursor_position.get_position();
 
@@ -754,8 +832,9 @@ pub fn determine_all_events(
             }
         }
 
-        // Detect DoubleClick (targeted at hovered node)
-        if manager.detect_double_click() {
+        // Detect DoubleClick / TripleClick (targeted at hovered node). Only on
+        // the release that completes the click, not on every later frame.
+        if event_was_mouse_release && manager.detect_double_click() {
             events.push(SyntheticEvent::new(
                 EventType::DoubleClick,
                 EventSource::User,
@@ -764,6 +843,15 @@ pub fn determine_all_events(
                 make_mouse_data(MouseButton::Left),
             ));
         }
+        if event_was_mouse_release && manager.detect_triple_click() {
+            events.push(SyntheticEvent::new(
+                EventType::TripleClick,
+                EventSource::User,
+                mouse_target.clone(),
+                timestamp.clone(),
+                make_mouse_data(MouseButton::Left),
+            ));
+        }
 
         // Detect LongPress (targeted at hovered node)
         if let Some(long_press) = manager.detect_long_press() {
@@ -857,3 +945,164 @@ pub fn determine_all_events(
 
     deduplicate_synthetic_events(events)
 }
+
+/// Maps the currently pressed key to a semantic clipboard event (`Copy`, `Cut`
+/// or `Paste`) if the platform shortcut modifier is held: Cmd on macOS, Ctrl
+/// everywhere else.
+pub fn clipboard_shortcut(keyboard_state: &KeyboardState, macos: bool) -> Option<EventType> {
+    let modifier_down = if macos {
+        keyboard_state.super_down()
+    } else {
+        keyboard_state.ctrl_down()
+    };
+    if !modifier_down {
+        return None;
+    }
+
+    match keyboard_state.current_virtual_keycode.into_option()? {
+        VirtualKeyCode::C => Some(EventType::Copy),
+        VirtualKeyCode::X => Some(EventType::Cut),
+        VirtualKeyCode::V => Some(EventType::Paste),
+        _ => None,
+    }
+}
+
+/// Builds the events for a focus change, in dispatch order: `Blur` on the
+/// node that lost focus, then `Focus` on the node that received it (followed
+/// by `FocusVisible` for keyboard-initiated focus).
+///
+/// The order is part of the contract: a `FocusLost` callback always runs
+/// before the `FocusReceived` callback of the next node.
+pub fn focus_transition_events(
+    old_focus: Option<DomNodeId>,
+    new_focus: Option<DomNodeId>,
+    focus_visible: bool,
+    timestamp: Instant,
+) -> Vec<SyntheticEvent> {
+    let mut events = Vec::new();
+
+    if let Some(old_node) = old_focus {
+        events.push(SyntheticEvent::new(
+            EventType::Blur,
+            EventSource::User,
+            old_node,
+            timestamp.clone(),
+            EventData::None,
+        ));
+    }
+
+    if let Some(new_node) = new_focus {
+        events.push(SyntheticEvent::new(
+            EventType::Focus,
+            EventSource::User,
+            new_node,
+            timestamp.clone(),
+            EventData::None,
+        ));
+        if focus_visible {
+            events.push(SyntheticEvent::new(
+                EventType::FocusVisible,
+                EventSource::User,
+                new_node,
+                timestamp,
+                EventData::None,
+            ));
+        }
+    }
+
+    events
+}
+
+/// Builds the `ThemeChange` event for the window root, carrying the new theme
+/// in its `WindowEventData`.
+///
+/// Also used by the shell on the first frame, where there is no previous
+/// state to diff against, so that callbacks see the initial system theme.
+pub fn theme_change_event(theme: WindowTheme, timestamp: Instant) -> SyntheticEvent {
+    SyntheticEvent::new(
+        EventType::ThemeChange,
+        EventSource::User,
+        DomNodeId {
+            dom: DomId { inner: 0 },
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)),
+        },
+        timestamp,
+        EventData::Window(WindowEventData {
+            size: None,
+            position: None,
+            hidpi_factor: None,
+            theme: Some(theme),
+        }),
+    )
+}
+
+/// Everything the event pipeline needs for one frame.
+///
+/// Bundles the window state diff with the `LayoutWindow` managers so that
+/// embedders call [`FrameInput::process`] instead of wiring the hover, focus,
+/// file drop, gesture and `EventProvider` managers into
+/// [`determine_all_events`] themselves.
+pub struct FrameInput<'a> {
+    /// Window state after the platform updates of this frame
+    pub current_state: &'a FullWindowState,
+    /// Window state of the previous frame
+    pub previous_state: &'a FullWindowState,
+    /// Layout results and managers (hover, focus, scroll, text input, ...)
+    pub layout_window: &'a crate::window::LayoutWindow,
+    /// Timestamp used for all events of this frame
+    pub timestamp: Instant,
+}
+
+/// Result of [`FrameInput::process`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameOutcome {
+    /// Deduplicated events, ready for dispatch
+    pub events: Vec<SyntheticEvent>,
+    /// Targets of `events`, i.e. the nodes whose callbacks have to be checked
+    pub nodes_to_check: BTreeSet<DomNodeId>,
+    /// The cursor moved or the window was resized, so the hover state is stale
+    pub needs_hit_test: bool,
+    /// The window size or DPI changed
+    pub needs_relayout: bool,
+    /// Something happened that may change what is on screen
+    pub needs_redraw: bool,
+}
+
+impl<'a> FrameInput<'a> {
+    /// Runs event determination for this frame and summarizes what the
+    /// shell has to do next.
+    pub fn process(&self) -> FrameOutcome {
+        let lw = self.layout_window;
+        let providers: [&dyn EventProvider; 2] =
+            [&lw.text_input_manager, &lw.lifecycle_manager];
+
+        let events = determine_all_events(
+            self.current_state,
+            self.previous_state,
+            &lw.hover_manager,
+            &lw.focus_manager,
+            &lw.file_drop_manager,
+            Some(&lw.gesture_drag_manager),
+            &providers,
+            self.timestamp.clone(),
+        );
+
+        let nodes_to_check = events.iter().map(|e| e.target).collect();
+
+        let needs_relayout = self.current_state.size.dimensions
+            != self.previous_state.size.dimensions
+            || self.current_state.size.dpi != self.previous_state.size.dpi;
+        let needs_hit_test = needs_relayout
+            || self.current_state.mouse_state.cursor_position
+                != self.previous_state.mouse_state.cursor_position;
+        let needs_redraw = needs_relayout || !events.is_empty();
+
+        FrameOutcome {
+            events,
+            nodes_to_check,
+            needs_hit_test,
+            needs_relayout,
+            needs_redraw,
+        }
+    }
+}
@@ -100,7 +100,14 @@ use std::collections::BTreeMap;
 use azul_core::{
     dom::{DomId, NodeId},
     geom::{LogicalPosition, LogicalRect, LogicalSize},
+    gpu::GpuValueCache,
     resources::RendererResources,
+    transform::ComputedTransform3D,
+};
+
+use crate::solver3::{
+    display_list::{BorderRadius, PhysicalSizeImport},
+    getters::{get_border_radius, get_z_index},
 };
 
 /// Configuration for headless rendering.
@@ -186,6 +193,41 @@ pub struct CpuHitTester {
     /// Cached hit test results from the last layout.
     /// Maps DomId -> list of (NodeId, positioned rect) sorted by paint order.
     node_rects: BTreeMap<DomId, Vec<HitTestEntry>>,
+    /// Incremented on every `rebuild_from_layout`, invalidates `HitTestCache`s.
+    generation: u64,
+}
+
+/// Memoizes the last `CpuHitTester::hit_test_cached` query.
+///
+/// A cached result is returned only if the cursor position, the scroll
+/// offsets and the layout generation all match the previous query exactly.
+#[derive(Debug, Default, Clone)]
+pub struct HitTestCache {
+    last: Option<CachedHitTest>,
+    /// Number of queries answered from the cache
+    pub hits: usize,
+    /// Number of queries that had to run a full hit test
+    pub misses: usize,
+}
+
+#[derive(Debug, Clone)]
+struct CachedHitTest {
+    generation: u64,
+    position: LogicalPosition,
+    scroll_offsets: BTreeMap<(DomId, NodeId), LogicalPosition>,
+    result: Vec<(DomId, NodeId)>,
+}
+
+impl HitTestCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop the cached result, forcing the next query to miss.
+    pub fn clear(&mut self) {
+        self.last = None;
+    }
 }
 
 /// A single entry in the CPU hit test acceleration structure.
@@ -196,8 +238,56 @@ struct HitTestEntry {
     rect: LogicalRect,
     /// Clip rect (intersection of all ancestor overflow clips).
     clip: Option<LogicalRect>,
+    /// Rounded corners of `rect`, points in the cut-off corners miss.
+    border_radius: BorderRadius,
     /// Whether this node is pointer-events: none
     pointer_events_none: bool,
+    /// Non-zero z-indices of this node and its ancestors, outermost first.
+    /// Entries are painted in ascending order of this key, then in tree order.
+    paint_order: Vec<i32>,
+    /// Inverted CSS transforms of this node and its transformed ancestors,
+    /// outermost first, each paired with the origin of the transformed node.
+    /// Empty for untransformed subtrees.
+    inverse_transforms: Vec<(LogicalPosition, ComputedTransform3D)>,
+}
+
+impl HitTestEntry {
+    /// Whether `position` (in window space) hits this entry
+    fn contains(&self, position: LogicalPosition) -> bool {
+        if self.pointer_events_none {
+            return false;
+        }
+
+        // Check clip rect first (if any)
+        if let Some(ref clip) = self.clip {
+            if !point_in_rect(position, clip) {
+                return false;
+            }
+        }
+
+        let local = if self.inverse_transforms.is_empty() {
+            position
+        } else {
+            match self.untransform(position) {
+                Some(p) => p,
+                None => return false,
+            }
+        };
+
+        // Check node rect, minus the rounded-off corners
+        self.border_radius.contains_point(&self.rect, local)
+    }
+
+    /// Maps a point from window space into the untransformed space of this node,
+    /// `None` if one of the transforms flattens the node to nothing.
+    fn untransform(&self, mut point: LogicalPosition) -> Option<LogicalPosition> {
+        for (origin, inverse) in &self.inverse_transforms {
+            let local = inverse
+                .transform_point2d(LogicalPosition::new(point.x - origin.x, point.y - origin.y))?;
+            point = LogicalPosition::new(local.x + origin.x, local.y + origin.y);
+        }
+        Some(point)
+    }
 }
 
 impl CpuHitTester {
@@ -205,9 +295,15 @@ impl CpuHitTester {
     pub fn new() -> Self {
         Self {
             node_rects: BTreeMap::new(),
+            generation: 0,
         }
     }
 
+    /// Layout generation, incremented on every `rebuild_from_layout`.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Rebuild the hit test structure from layout results.
     ///
     /// Called after each layout pass. Extracts positioned rectangles from
@@ -216,14 +312,31 @@ impl CpuHitTester {
     pub fn rebuild_from_layout(
         &mut self,
         layout_results: &BTreeMap<DomId, crate::window::DomLayoutResult>,
+    ) {
+        self.rebuild_from_layout_with_transforms(layout_results, &BTreeMap::new());
+    }
+
+    /// Same as `rebuild_from_layout`, but also takes the CSS transforms from
+    /// `gpu_caches` (usually `GpuStateManager::caches`) into account, so that
+    /// rotated or scaled nodes are hit where they are painted.
+    pub fn rebuild_from_layout_with_transforms(
+        &mut self,
+        layout_results: &BTreeMap<DomId, crate::window::DomLayoutResult>,
+        gpu_caches: &BTreeMap<DomId, GpuValueCache>,
     ) {
         self.node_rects.clear();
+        self.generation += 1;
 
         for (dom_id, layout_result) in layout_results {
             let mut entries = Vec::new();
 
             let positions = &layout_result.calculated_positions;
             let nodes = &layout_result.layout_tree.nodes;
+            let styled_dom = &layout_result.styled_dom;
+            let styled_nodes = styled_dom.styled_nodes.as_container();
+            let gpu_cache = gpu_caches
+                .get(dom_id)
+                .filter(|c| !c.css_current_transform_values.is_empty());
 
             // Walk the layout nodes and their computed positions
             for (idx, node) in nodes.iter().enumerate() {
@@ -250,14 +363,59 @@ impl CpuHitTester {
                     size,
                 };
 
+                let border_radius = get_border_radius(
+                    styled_dom,
+                    node_id,
+                    &styled_nodes[node_id].styled_node_state,
+                    PhysicalSizeImport {
+                        width: size.width,
+                        height: size.height,
+                    },
+                    layout_result.viewport.size,
+                );
+
+                // Transforms are relative to the node origin, same as the
+                // reference frames pushed in the display list
+                let mut inverse_transforms = Vec::new();
+                if let Some(cache) = gpu_cache {
+                    let mut current = Some(idx);
+                    while let Some(i) = current {
+                        let transform = nodes[i]
+                            .dom_node_id
+                            .and_then(|id| cache.css_current_transform_values.get(&id));
+                        if let (Some(t), Some(origin)) = (transform, positions.get(i)) {
+                            inverse_transforms.push((*origin, t.inverse()));
+                        }
+                        current = nodes[i].parent;
+                    }
+                    inverse_transforms.reverse();
+                }
+
+                let mut paint_order = Vec::new();
+                let mut current = Some(idx);
+                while let Some(i) = current {
+                    let z_index = get_z_index(styled_dom, nodes[i].dom_node_id);
+                    if z_index != 0 {
+                        paint_order.push(z_index);
+                    }
+                    current = nodes[i].parent;
+                }
+                paint_order.reverse();
+
                 entries.push(HitTestEntry {
                     node_id,
                     rect,
                     clip: None, // TODO: compute clip chains
+                    border_radius,
                     pointer_events_none: false, // TODO: check CSS property
+                    paint_order,
+                    inverse_transforms,
                 });
             }
 
+            // Stable sort, so that later siblings stay on top within a z-index
+            entries.sort_by(|a, b| a.paint_order.cmp(&b.paint_order));
+
             self.node_rects.insert(*dom_id, entries);
         }
     }
@@ -274,19 +432,7 @@ impl CpuHitTester {
         for (dom_id, entries) in &self.node_rects {
             // Walk in reverse (last painted = topmost)
             for entry in entries.iter().rev() {
-                if entry.pointer_events_none {
-                    continue;
-                }
-
-                // Check clip rect first (if any)
-                if let Some(ref clip) = entry.clip {
-                    if !point_in_rect(position, clip) {
-                        continue;
-                    }
-                }
-
-                // Check node rect
-                if point_in_rect(position, &entry.rect) {
+                if entry.contains(position) {
                     results.push((*dom_id, entry.node_id));
                 }
             }
@@ -294,6 +440,45 @@ impl CpuHitTester {
 
         results
     }
+
+    /// Returns only the frontmost node at the given position, i.e. the one
+    /// that should receive a click. Nested DOMs are painted above their host.
+    pub fn hit_test_topmost(&self, position: LogicalPosition) -> Option<(DomId, NodeId)> {
+        self.node_rects.iter().rev().find_map(|(dom_id, entries)| {
+            let entry = entries.iter().rev().find(|e| e.contains(position))?;
+            Some((*dom_id, entry.node_id))
+        })
+    }
+
+    /// Same as `hit_test`, but returns the previous result from `cache` if
+    /// neither the layout, the cursor position nor the scroll offsets changed
+    /// since the last query.
+    pub fn hit_test_cached(
+        &self,
+        position: LogicalPosition,
+        scroll_offsets: &BTreeMap<(DomId, NodeId), LogicalPosition>,
+        cache: &mut HitTestCache,
+    ) -> Vec<(DomId, NodeId)> {
+        if let Some(last) = cache.last.as_ref() {
+            if last.generation == self.generation
+                && last.position == position
+                && last.scroll_offsets == *scroll_offsets
+            {
+                cache.hits += 1;
+                return last.result.clone();
+            }
+        }
+
+        cache.misses += 1;
+        let result = self.hit_test(position);
+        cache.last = Some(CachedHitTest {
+            generation: self.generation,
+            position,
+            scroll_offsets: scroll_offsets.clone(),
+            result: result.clone(),
+        });
+        result
+    }
 }
 
 /// Simple point-in-rect test.
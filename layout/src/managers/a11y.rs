@@ -19,7 +19,7 @@ use azul_core::{
         AccessibilityAction, AccessibilityInfo, AccessibilityRole, AccessibilityState, DomId,
         DomNodeId, NodeData, NodeId, NodeType, TextSelectionStartEnd,
     },
-    geom::{LogicalPosition, LogicalSize},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
     styled_dom::NodeHierarchyItemId,
 };
 use azul_css::AzString;
@@ -499,3 +499,50 @@ impl A11yManager {
         Self { _private: () }
     }
 }
+
+/// Backend-independent accessibility node, produced by
+/// `DomLayoutResult::accessibility_tree`. Unlike the accesskit tree of the
+/// `A11yManager`, this is always available (no `a11y` feature needed) and
+/// uses absolute layout bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// DOM node this accessibility node was created from
+    pub node_id: NodeId,
+    /// Role from the node's `AccessibilityInfo`, or inferred from its `NodeType`
+    pub role: AccessibilityRole,
+    /// Accessible name, or the text content for text and button-like nodes
+    pub label: Option<String>,
+    /// Absolute border-box bounds of the node
+    pub bounds: LogicalRect,
+    /// Whether the node can receive keyboard focus
+    pub is_focusable: bool,
+    /// Accessible children, decorative descendants are skipped
+    pub children: Vec<AccessibilityNode>,
+}
+
+/// Infers an `AccessibilityRole` from a `NodeType` for nodes without explicit
+/// `AccessibilityInfo`. Generic containers map to `AccessibilityRole::Nothing`.
+pub fn node_type_to_accessibility_role(node_type: &NodeType) -> AccessibilityRole {
+    match node_type {
+        NodeType::Text(_) => AccessibilityRole::StaticText,
+        NodeType::Image(_) => AccessibilityRole::Graphic,
+        NodeType::Button => AccessibilityRole::PushButton,
+        NodeType::Input | NodeType::TextArea => AccessibilityRole::Text,
+        NodeType::Select => AccessibilityRole::ComboBox,
+        NodeType::A => AccessibilityRole::Link,
+        NodeType::H1
+        | NodeType::H2
+        | NodeType::H3
+        | NodeType::H4
+        | NodeType::H5
+        | NodeType::H6 => AccessibilityRole::StaticText,
+        NodeType::Article
+        | NodeType::Section
+        | NodeType::Nav
+        | NodeType::Main
+        | NodeType::Header
+        | NodeType::Footer
+        | NodeType::Aside => AccessibilityRole::Grouping,
+        _ => AccessibilityRole::Nothing,
+    }
+}
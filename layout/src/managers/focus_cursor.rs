@@ -31,6 +31,21 @@ pub struct PendingContentEditableFocus {
     pub text_node_id: NodeId,
 }
 
+/// How the currently focused node received its focus.
+///
+/// Used to decide whether a focus indicator should be drawn: browsers only
+/// match `:focus-visible` when focus was moved with the keyboard, not when
+/// an element was clicked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FocusOrigin {
+    /// Focus moved via keyboard navigation (Tab, Shift+Tab, ...)
+    Keyboard,
+    /// Focus moved because the user clicked / tapped a focusable node
+    Pointer,
+    /// Focus was set from a callback or the accessibility layer
+    Programmatic,
+}
+
 /// Manager for keyboard focus and tab navigation
 ///
 /// Note: Text cursor management is now handled by the separate `CursorManager`.
@@ -57,6 +72,8 @@ pub struct FocusManager {
     pub focused_node: Option<DomNodeId>,
     /// Pending focus request from callback
     pub pending_focus_request: Option<FocusTarget>,
+    /// How the last focus change was triggered
+    pub focus_origin: FocusOrigin,
     
     // --- W3C "flag and defer" pattern fields ---
     
@@ -78,6 +95,7 @@ impl FocusManager {
         Self {
             focused_node: None,
             pending_focus_request: None,
+            focus_origin: FocusOrigin::Programmatic,
             cursor_needs_initialization: false,
             pending_contenteditable_focus: None,
         }
@@ -94,7 +112,25 @@ impl FocusManager {
     /// The event system should check if the newly focused node is contenteditable
     /// and call `CursorManager::initialize_cursor_at_end()` if needed.
     pub fn set_focused_node(&mut self, node: Option<DomNodeId>) {
+        self.set_focused_node_with_origin(node, FocusOrigin::Programmatic);
+    }
+
+    /// Set the focused node and record whether the change came from the
+    /// keyboard or the pointer (see `is_focus_visible`)
+    pub fn set_focused_node_with_origin(&mut self, node: Option<DomNodeId>, origin: FocusOrigin) {
         self.focused_node = node;
+        self.focus_origin = origin;
+    }
+
+    /// Returns how the current focus was obtained
+    pub fn get_focus_origin(&self) -> FocusOrigin {
+        self.focus_origin
+    }
+
+    /// Whether the focused node should show a focus indicator
+    /// (CSS `:focus-visible`), i.e. focus was moved with the keyboard
+    pub fn is_focus_visible(&self) -> bool {
+        self.focused_node.is_some() && self.focus_origin == FocusOrigin::Keyboard
     }
 
     /// Request a focus change (to be processed by event system)
@@ -95,9 +95,9 @@ pub const DEFAULT_SAMPLE_TIMEOUT_MS: u64 = 2000;
 pub struct GestureDetectionConfig {
     /// Minimum distance (pixels) to consider movement a drag, not a click
     pub drag_distance_threshold: f32,
-    /// Maximum time between clicks for double-click detection (milliseconds)
+    /// Maximum time between clicks for double- / triple-click detection (milliseconds)
     pub double_click_time_threshold_ms: u64,
-    /// Maximum distance between clicks for double-click detection (pixels)
+    /// Maximum distance between clicks for double- / triple-click detection (pixels)
     pub double_click_distance_threshold: f32,
     /// Minimum time to hold button for long-press detection (milliseconds)
     pub long_press_time_threshold_ms: u64,
@@ -388,6 +388,9 @@ pub struct GestureAndDragManager {
     long_press_callbacks_invoked: Vec<u64>,
     /// Counter for generating unique session IDs
     next_session_id: u64,
+    /// Number of clicks in the current click chain (1 = single click),
+    /// updated when a session ends
+    click_count: u32,
 }
 
 /// Type alias for backwards compatibility
@@ -409,6 +412,7 @@ impl GestureAndDragManager {
             active_drag: None,
             pen_state: None,
             long_press_callbacks_invoked: Vec::new(),
+            click_count: 0,
         }
     }
 
@@ -568,9 +572,24 @@ impl GestureAndDragManager {
     /// Call this when receiving mouse button up event.
     /// The session is kept for analysis but marked as ended.
     pub fn end_current_session(&mut self) {
-        if let Some(session) = self.input_sessions.last_mut() {
-            session.ended = true;
+        match self.input_sessions.last_mut() {
+            Some(session) if !session.ended => session.ended = true,
+            _ => return,
         }
+
+        // Only the previous ended session is kept around (see
+        // `start_input_session_with_pen`), so the chain continues if the
+        // click before this one was close enough in time and space
+        let n = self.input_sessions.len();
+        let continues_chain = n >= 2
+            && self.input_sessions[n - 2].ended
+            && self.is_click_chain(&self.input_sessions[n - 2], &self.input_sessions[n - 1]);
+
+        self.click_count = if continues_chain {
+            self.click_count + 1
+        } else {
+            1
+        };
     }
 
     /// Clear old input sessions that have timed out
@@ -673,6 +692,22 @@ impl GestureAndDragManager {
         }
     }
 
+    /// Like `detect_drag`, but only returns the drag on the sample that first
+    /// crossed `drag_distance_threshold`, so `DragStart` fires once per session.
+    pub fn detect_drag_start(&self) -> Option<DetectedDrag> {
+        let drag = self.detect_drag()?;
+        let session = self.get_current_session()?;
+        let len = session.samples.len();
+        let first = session.first_sample()?;
+        let prev = &session.samples[len.saturating_sub(2)];
+        let dx = prev.position.x - first.position.x;
+        let dy = prev.position.y - first.position.y;
+        if (dx * dx + dy * dy).sqrt() >= self.config.drag_distance_threshold {
+            return None;
+        }
+        Some(drag)
+    }
+
     /// Detect if current input represents a long press
     ///
     /// Returns Some(DetectedLongPress) if button has been held long enough
@@ -719,26 +754,35 @@ impl GestureAndDragManager {
         }
     }
 
-    /// Detect if last two sessions form a double-click.
+    /// Number of clicks in the current click chain: 1 after a single click,
+    /// 2 after a double-click, 3 after a triple-click, ...
+    ///
+    /// Two clicks chain if they start within `double_click_time_threshold_ms`
+    /// and `double_click_distance_threshold` of each other.
+    pub fn click_count(&self) -> u32 {
+        self.click_count
+    }
+
+    /// Detect if the last session completed a double-click.
     ///
     /// Returns true if timing and distance match double-click criteria.
     pub fn detect_double_click(&self) -> bool {
-        let sessions = &self.input_sessions;
-        if sessions.len() < 2 {
-            return false;
-        }
+        self.last_session_ended() && self.click_count == 2
+    }
 
-        let prev_session = &sessions[sessions.len() - 2];
-        let last_session = &sessions[sessions.len() - 1];
+    /// Detect if the last session completed a triple-click.
+    pub fn detect_triple_click(&self) -> bool {
+        self.last_session_ended() && self.click_count == 3
+    }
 
-        // Both sessions must have ended (button released)
-        if !prev_session.ended || !last_session.ended {
-            return false;
-        }
+    fn last_session_ended(&self) -> bool {
+        self.input_sessions.last().map_or(false, |s| s.ended)
+    }
 
-        let prev_first = prev_session.first_sample();
-        let last_first = last_session.first_sample();
-        let (prev_first, last_first) = match (prev_first, last_first) {
+    /// Checks whether `last` starts close enough (in time and space) to
+    /// `prev` to count as the next click of the same click chain.
+    fn is_click_chain(&self, prev: &InputSession, last: &InputSession) -> bool {
+        let (prev_first, last_first) = match (prev.first_sample(), last.first_sample()) {
             (Some(p), Some(l)) => (p, l),
             _ => return false,
         };
@@ -973,6 +1017,31 @@ impl GestureAndDragManager {
         ))
     }
 
+    /// Get the **incremental** (frame-to-frame) drag delta in window-local
+    /// coordinates, i.e. the movement between the last two samples.
+    ///
+    /// Returns `None` if no button is held or fewer than 2 samples exist.
+    pub fn get_drag_delta_incremental(&self) -> Option<(f32, f32)> {
+        let session = self.get_current_session().filter(|s| !s.ended)?;
+        let len = session.samples.len();
+        if len < 2 {
+            return None;
+        }
+        let prev = &session.samples[len - 2];
+        let last = &session.samples[len - 1];
+        Some((
+            last.position.x - prev.position.x,
+            last.position.y - prev.position.y,
+        ))
+    }
+
+    /// Get the window-local position where the button of the current input
+    /// session went down. Returns `None` once the button was released.
+    pub fn get_drag_start_position(&self) -> Option<LogicalPosition> {
+        let session = self.get_current_session().filter(|s| !s.ended)?;
+        Some(session.first_sample()?.position)
+    }
+
     /// Get the drag delta in **screen-absolute** coordinates.
     ///
     /// Unlike `get_drag_delta()` which uses window-local coordinates (and therefore
@@ -4,8 +4,17 @@
 //! (mouse, touch, pen) over multiple frames to enable gesture detection
 //! (like DragStart) that requires analyzing hover patterns over time
 //! rather than just the current frame.
+//!
+//! It also tracks how long the mouse has been resting on each hovered node,
+//! so that e.g. tooltips can be shown after a delay.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
-use std::collections::{BTreeMap, VecDeque};
+use azul_core::{
+    dom::{DomId, DomNodeId},
+    id::NodeId,
+    task::{Duration, Instant, SystemTickDiff, SystemTimeDiff},
+};
 
 use crate::hit_test::FullHitTest;
 
@@ -35,6 +44,10 @@ pub struct HoverManager {
     /// Hit test history for each input point
     /// Each point has its own ring buffer of the last N frames
     hover_histories: BTreeMap<InputPointId, VecDeque<FullHitTest>>,
+    /// Time at which the mouse started hovering each currently hovered node
+    hover_start: BTreeMap<(DomId, NodeId), Instant>,
+    /// Time of the last `update_hover_durations` call
+    last_hover_update: Option<Instant>,
 }
 
 impl HoverManager {
@@ -42,6 +55,8 @@ impl HoverManager {
     pub fn new() -> Self {
         Self {
             hover_histories: BTreeMap::new(),
+            hover_start: BTreeMap::new(),
+            last_hover_update: None,
         }
     }
 
@@ -114,6 +129,7 @@ impl HoverManager {
     /// Clear all hover history for all input points
     pub fn clear(&mut self) {
         self.hover_histories.clear();
+        self.hover_start.clear();
     }
 
     /// Clear history for a specific input point
@@ -161,6 +177,49 @@ impl HoverManager {
         ht.regular_hit_test_nodes.keys().last().copied()
     }
 
+    /// Advances the hover timers to `now`, should be called once per frame.
+    ///
+    /// Nodes under the current mouse hit test keep accumulating hover time as
+    /// long as they stay hovered; nodes that are no longer hovered are reset,
+    /// newly hovered nodes start at zero.
+    pub fn update_hover_durations(&mut self, now: Instant) {
+        let hovered: BTreeSet<(DomId, NodeId)> = self
+            .get_current_mouse()
+            .map(|hit_test| {
+                hit_test
+                    .hovered_nodes
+                    .iter()
+                    .flat_map(|(dom_id, ht)| {
+                        ht.regular_hit_test_nodes
+                            .keys()
+                            .map(move |node_id| (*dom_id, *node_id))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.hover_start.retain(|key, _| hovered.contains(key));
+        for key in hovered {
+            self.hover_start.entry(key).or_insert_with(|| now.clone());
+        }
+        self.last_hover_update = Some(now);
+    }
+
+    /// Returns how long the mouse has been resting on `node`, as of the last
+    /// `update_hover_durations` call. Zero if the node is not hovered.
+    pub fn hovered_for(&self, node: DomNodeId) -> Duration {
+        let start = node
+            .node
+            .into_crate_internal()
+            .and_then(|node_id| self.hover_start.get(&(node.dom, node_id)));
+        match (start, &self.last_hover_update) {
+            (Some(start), Some(now)) => now.duration_since(start),
+            // Zero, in the same clock as the hover start times
+            (None, Some(Instant::System(_))) => Duration::System(SystemTimeDiff::from_secs(0)),
+            _ => Duration::Tick(SystemTickDiff { tick_diff: 0 }),
+        }
+    }
+
     /// Remap NodeIds in all hover histories after DOM reconciliation.
     ///
     /// When the DOM is regenerated, NodeIds can change. This method updates
@@ -171,6 +230,15 @@ impl HoverManager {
         dom_id: azul_core::dom::DomId,
         node_id_map: &std::collections::BTreeMap<azul_core::id::NodeId, azul_core::id::NodeId>,
     ) {
+        let old_starts = std::mem::take(&mut self.hover_start);
+        for ((d, old_nid), start) in old_starts {
+            if d != dom_id {
+                self.hover_start.insert((d, old_nid), start);
+            } else if let Some(&new_nid) = node_id_map.get(&old_nid) {
+                self.hover_start.insert((d, new_nid), start);
+            }
+        }
+
         for history in self.hover_histories.values_mut() {
             for hit_test in history.iter_mut() {
                 if let Some(ht) = hit_test.hovered_nodes.get_mut(&dom_id) {
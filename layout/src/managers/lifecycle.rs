@@ -0,0 +1,182 @@
+//! Lifecycle Manager
+//!
+//! Collects component lifecycle events produced by a relayout so that
+//! `ComponentEventFilter::{AfterMount, BeforeUnmount, NodeResized}` callbacks
+//! can be invoked on the next event-processing pass:
+//!
+//! - **AfterMount**: node exists in the new DOM but had no match in the
+//!   previous one (every node on the first layout of a DOM)
+//! - **BeforeUnmount**: node of the previous DOM without a match in the new one
+//! - **NodeResized**: matched node whose laid-out size changed
+//!
+//! Only nodes that registered the respective callback are recorded.
+//!
+//! An unmounted node no longer exists in the current layout results, so its
+//! callbacks are stored here and looked up via [`LifecycleManager::unmount_callbacks`]
+//! during dispatch instead of from the styled DOM.
+
+use azul_core::{
+    callbacks::CoreCallbackData,
+    dom::{DomId, DomNodeId, NodeId},
+    events::{
+        EventData, EventPhase, EventProvider, EventSource, EventType, LifecycleEventData,
+        LifecycleReason, SyntheticEvent,
+    },
+    geom::LogicalRect,
+    styled_dom::NodeHierarchyItemId,
+    task::Instant,
+};
+
+/// A lifecycle event recorded during the last relayout
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingLifecycleEvent {
+    /// `Mount`, `Unmount` or `Resize`
+    pub event_type: EventType,
+    /// The affected node. For `Unmount` this is the NodeId in the *previous* DOM.
+    pub node: DomNodeId,
+    /// Reason and bounds of the change
+    pub data: LifecycleEventData,
+}
+
+/// Collects lifecycle events until the next event-processing pass consumes them
+#[derive(Debug, Default, Clone)]
+pub struct LifecycleManager {
+    /// Events recorded since the last call to `clear()`
+    pub pending: Vec<PendingLifecycleEvent>,
+    /// `BeforeUnmount` callbacks of unmounted nodes, keyed by their old node id
+    pub unmounted: Vec<(DomNodeId, Vec<CoreCallbackData>)>,
+}
+
+fn dom_node_id(dom_id: DomId, node_id: NodeId) -> DomNodeId {
+    DomNodeId {
+        dom: dom_id,
+        node: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
+    }
+}
+
+impl LifecycleManager {
+    /// Create a new, empty LifecycleManager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `node_id` in `dom_id` changed size.
+    ///
+    /// If the node was already resized since the last `clear()`, the original
+    /// previous bounds are kept so the callback sees the whole change.
+    pub fn record_resize(
+        &mut self,
+        dom_id: DomId,
+        node_id: NodeId,
+        previous_bounds: LogicalRect,
+        current_bounds: LogicalRect,
+    ) {
+        let node = dom_node_id(dom_id, node_id);
+
+        let existing = self
+            .pending
+            .iter_mut()
+            .find(|p| p.node == node && p.event_type == EventType::Resize);
+
+        match existing {
+            Some(existing) => existing.data.current_bounds = current_bounds,
+            None => self.pending.push(PendingLifecycleEvent {
+                event_type: EventType::Resize,
+                node,
+                data: LifecycleEventData {
+                    reason: LifecycleReason::Resize,
+                    previous_bounds: Some(previous_bounds),
+                    current_bounds,
+                },
+            }),
+        }
+    }
+
+    /// Record that `node_id` appeared in `dom_id`
+    pub fn record_mount(&mut self, dom_id: DomId, node_id: NodeId, bounds: LogicalRect) {
+        self.pending.push(PendingLifecycleEvent {
+            event_type: EventType::Mount,
+            node: dom_node_id(dom_id, node_id),
+            data: LifecycleEventData {
+                reason: LifecycleReason::InitialMount,
+                previous_bounds: None,
+                current_bounds: bounds,
+            },
+        });
+    }
+
+    /// Record that `node_id` of the previous DOM was removed, together with
+    /// its `BeforeUnmount` callbacks.
+    pub fn record_unmount(
+        &mut self,
+        dom_id: DomId,
+        node_id: NodeId,
+        bounds: LogicalRect,
+        callbacks: Vec<CoreCallbackData>,
+    ) {
+        let node = dom_node_id(dom_id, node_id);
+        self.pending.push(PendingLifecycleEvent {
+            event_type: EventType::Unmount,
+            node,
+            data: LifecycleEventData {
+                reason: LifecycleReason::Unmount,
+                previous_bounds: Some(bounds),
+                current_bounds: bounds,
+            },
+        });
+        self.unmounted.push((node, callbacks));
+    }
+
+    /// `BeforeUnmount` callbacks of a node recorded via `record_unmount`
+    pub fn unmount_callbacks(&self, node: &DomNodeId) -> &[CoreCallbackData] {
+        self.unmounted
+            .iter()
+            .find(|(n, _)| n == node)
+            .map(|(_, callbacks)| callbacks.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns true if there are events that have not been dispatched yet
+    pub fn has_pending_events(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Drop all recorded events (called after they were dispatched)
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.unmounted.clear();
+    }
+}
+
+impl EventProvider for LifecycleManager {
+    /// Returns one event per recorded change, targeted at the node only.
+    ///
+    /// Unmounts are returned before mounts, so teardown of a replaced
+    /// component runs before setup of its successor.
+    fn get_pending_events(&self, timestamp: Instant) -> Vec<SyntheticEvent> {
+        let unmounts = self
+            .pending
+            .iter()
+            .filter(|p| p.event_type == EventType::Unmount);
+        let others = self
+            .pending
+            .iter()
+            .filter(|p| p.event_type != EventType::Unmount);
+
+        unmounts
+            .chain(others)
+            .map(|p| SyntheticEvent {
+                event_type: p.event_type,
+                source: EventSource::Lifecycle,
+                phase: EventPhase::Target,
+                target: p.node,
+                current_target: p.node,
+                timestamp: timestamp.clone(),
+                data: EventData::Lifecycle(p.data.clone()),
+                stopped: false,
+                stopped_immediate: false,
+                prevented_default: false,
+            })
+            .collect()
+    }
+}
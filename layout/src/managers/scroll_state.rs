@@ -51,9 +51,13 @@ use azul_core::{
     dom::{DomId, NodeId, ScrollbarOrientation},
     events::EasingFunction,
     geom::{LogicalPosition, LogicalRect, LogicalSize},
-    hit_test::{ExternalScrollId, ScrollPosition},
+    hit_test::{
+        ExternalScrollId, FullHitTest, HitTest, ScrollPosition, ScrollbarHitId,
+        ScrollbarHitTestItem,
+    },
     styled_dom::NodeHierarchyItemId,
     task::{Duration, Instant},
+    ui_solver::ResolvedOffsets,
 };
 
 #[cfg(feature = "std")]
@@ -286,6 +290,27 @@ pub struct ScrollbarHit {
     pub global_position: LogicalPosition,
 }
 
+impl ScrollbarHit {
+    /// Converts this hit into the `ScrollbarHitId` stored in
+    /// `HitTest::scrollbar_hit_test_nodes`. Arrow buttons count as track hits.
+    pub fn to_hit_id(&self) -> ScrollbarHitId {
+        match (self.orientation, self.component) {
+            (ScrollbarOrientation::Vertical, ScrollbarComponent::Thumb) => {
+                ScrollbarHitId::VerticalThumb(self.dom_id, self.node_id)
+            }
+            (ScrollbarOrientation::Vertical, _) => {
+                ScrollbarHitId::VerticalTrack(self.dom_id, self.node_id)
+            }
+            (ScrollbarOrientation::Horizontal, ScrollbarComponent::Thumb) => {
+                ScrollbarHitId::HorizontalThumb(self.dom_id, self.node_id)
+            }
+            (ScrollbarOrientation::Horizontal, _) => {
+                ScrollbarHitId::HorizontalTrack(self.dom_id, self.node_id)
+            }
+        }
+    }
+}
+
 // Core Scroll Manager
 
 /// Manages all scroll state and animations for a window
@@ -299,11 +324,26 @@ pub struct ScrollManager {
     next_external_scroll_id: u64,
     /// Scrollbar geometry states (calculated per frame)
     scrollbar_states: BTreeMap<(DomId, NodeId, ScrollbarOrientation), ScrollbarState>,
+    /// Children of each scroll container with their position from the last
+    /// layout, used to pick the scroll anchor on the next relayout
+    anchor_candidates: BTreeMap<(DomId, NodeId), Vec<ScrollAnchorCandidate>>,
     /// Thread-safe queue for scroll inputs (shared with timer callbacks)
     #[cfg(feature = "std")]
     pub scroll_input_queue: ScrollInputQueue,
 }
 
+/// Position of a scroll container's child as of the last layout, relative to
+/// the unscrolled container origin (see `ScrollManager::apply_scroll_anchoring`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollAnchorCandidate {
+    /// The child node
+    pub node_id: NodeId,
+    /// Top edge of the child, relative to the container
+    pub top: f32,
+    /// Bottom edge of the child, relative to the container
+    pub bottom: f32,
+}
+
 /// The complete scroll state for a single node (with animation support)
 #[derive(Debug, Clone)]
 pub struct AnimatedScrollState {
@@ -338,6 +378,11 @@ pub struct AnimatedScrollState {
     pub has_horizontal_scrollbar: bool,
     /// Whether this node also needs a vertical scrollbar (affects horizontal geometry)
     pub has_vertical_scrollbar: bool,
+    /// Whether the X axis can be scrolled (`false` for `overflow-x: hidden / clip`).
+    /// The offset on a non-scrollable axis is always clamped to 0.
+    pub scrollable_x: bool,
+    /// Whether the Y axis can be scrolled (`false` for `overflow-y: hidden / clip`)
+    pub scrollable_y: bool,
 }
 
 /// Details of an in-progress smooth scroll animation
@@ -377,6 +422,10 @@ pub struct ScrollNodeInfo {
     pub overscroll_behavior_y: azul_css::props::style::scrollbar::OverscrollBehavior,
     /// Per-node overflow scrolling mode (auto vs touch)
     pub overflow_scrolling: azul_css::props::style::scrollbar::OverflowScrolling,
+    /// Whether the X axis can be scrolled (`max_scroll_x` is 0 if not)
+    pub scrollable_x: bool,
+    /// Whether the Y axis can be scrolled (`max_scroll_y` is 0 if not)
+    pub scrollable_y: bool,
 }
 
 /// Result of a scroll tick, indicating what actions are needed
@@ -431,26 +480,42 @@ impl ScrollManager {
         now: Instant,
     ) -> Option<(DomId, NodeId, bool)> {
         let hit_test = hover_manager.get_current(input_point_id)?;
+        let delta = LogicalPosition { x: delta_x, y: delta_y };
+        // When every container is at its limit, the innermost one still gets
+        // the input so that it can rubber-band
+        let (dom_id, node_id) = match self.get_scroll_hit_chain(hit_test, delta).first() {
+            Some(target) => *target,
+            None => scroll_hit_nodes_innermost_first(hit_test)
+                .find(|(dom_id, node_id)| self.is_node_scrollable(*dom_id, *node_id))?,
+        };
 
-        for (dom_id, hit_node) in &hit_test.hovered_nodes {
-            for (node_id, _scroll_item) in &hit_node.scroll_hit_test_nodes {
-                let scrollable = self.is_node_scrollable(*dom_id, *node_id);
-                if !scrollable {
-                    continue;
-                }
-                let input = ScrollInput {
-                    dom_id: *dom_id,
-                    node_id: *node_id,
-                    delta: LogicalPosition { x: delta_x, y: delta_y },
-                    timestamp: now,
-                    source,
-                };
-                let should_start_timer = self.record_scroll_input(input);
-                return Some((*dom_id, *node_id, should_start_timer));
-            }
-        }
+        let input = ScrollInput {
+            dom_id,
+            node_id,
+            delta,
+            timestamp: now,
+            source,
+        };
+        let should_start_timer = self.record_scroll_input(input);
+        Some((dom_id, node_id, should_start_timer))
+    }
 
-        None
+    /// Returns the scroll containers under the cursor that can still move by
+    /// `delta`, innermost first.
+    ///
+    /// A container that is already at its limit in the direction of `delta`
+    /// is skipped, so the scroll is offered to the next ancestor instead.
+    pub fn get_scroll_hit_chain(
+        &self,
+        hit_test: &FullHitTest,
+        delta: LogicalPosition,
+    ) -> Vec<(DomId, NodeId)> {
+        scroll_hit_nodes_innermost_first(hit_test)
+            .filter(|(dom_id, node_id)| {
+                self.is_node_scrollable(*dom_id, *node_id)
+                    && self.can_scroll_by(*dom_id, *node_id, delta)
+            })
+            .collect()
     }
 
     /// Get a clone of the scroll input queue (for sharing with timer callbacks).
@@ -522,13 +587,29 @@ impl ScrollManager {
             let effective_height = state.virtual_scroll_size
                 .map(|s| s.height)
                 .unwrap_or(state.content_rect.size.height);
-            let has_horizontal = effective_width > state.container_rect.size.width;
-            let has_vertical = effective_height > state.container_rect.size.height;
+            let has_horizontal =
+                state.scrollable_x && effective_width > state.container_rect.size.width;
+            let has_vertical =
+                state.scrollable_y && effective_height > state.container_rect.size.height;
             has_horizontal || has_vertical
         });
         result
     }
 
+    /// Whether scrolling the node by `delta` would change its offset, i.e. it
+    /// is not already at its limit in that direction
+    fn can_scroll_by(&self, dom_id: DomId, node_id: NodeId, delta: LogicalPosition) -> bool {
+        self.states.get(&(dom_id, node_id)).map_or(false, |state| {
+            let current = state.current_offset;
+            let target = state.clamp(LogicalPosition {
+                x: current.x + delta.x,
+                y: current.y + delta.y,
+            });
+            (target.x - current.x).abs() > f32::EPSILON
+                || (target.y - current.y).abs() > f32::EPSILON
+        })
+    }
+
     /// Sets scroll position immediately (no animation), clamped to valid bounds.
     pub fn set_scroll_position(
         &mut self,
@@ -672,6 +753,8 @@ impl ScrollManager {
                 visual_width_px: 0.0,
                 has_horizontal_scrollbar: false,
                 has_vertical_scrollbar: false,
+                scrollable_x: true,
+                scrollable_y: true,
             }
         });
         state.virtual_scroll_size = Some(virtual_scroll_size);
@@ -718,8 +801,16 @@ impl ScrollManager {
         let effective_content_height = state.virtual_scroll_size
             .map(|s| s.height)
             .unwrap_or(state.content_rect.size.height);
-        let max_x = (effective_content_width - state.container_rect.size.width).max(0.0);
-        let max_y = (effective_content_height - state.container_rect.size.height).max(0.0);
+        let max_x = if state.scrollable_x {
+            (effective_content_width - state.container_rect.size.width).max(0.0)
+        } else {
+            0.0
+        };
+        let max_y = if state.scrollable_y {
+            (effective_content_height - state.container_rect.size.height).max(0.0)
+        } else {
+            0.0
+        };
         Some(ScrollNodeInfo {
             current_offset: state.current_offset,
             container_rect: state.container_rect,
@@ -729,6 +820,26 @@ impl ScrollManager {
             overscroll_behavior_x: state.overscroll_behavior_x,
             overscroll_behavior_y: state.overscroll_behavior_y,
             overflow_scrolling: state.overflow_scrolling,
+            scrollable_x: state.scrollable_x,
+            scrollable_y: state.scrollable_y,
+        })
+    }
+
+    /// Returns how much content is hidden on each side of the scroll viewport
+    /// at the current scroll offset.
+    ///
+    /// `top` / `left` are the amounts already scrolled past, `bottom` / `right`
+    /// the amounts that can still be scrolled into view. Useful for drawing
+    /// scroll shadows or "more content" indicators.
+    pub fn get_scroll_edges(&self, dom_id: DomId, node_id: NodeId) -> Option<ResolvedOffsets> {
+        let info = self.get_scroll_node_info(dom_id, node_id)?;
+        let offset_x = info.current_offset.x.max(0.0).min(info.max_scroll_x);
+        let offset_y = info.current_offset.y.max(0.0).min(info.max_scroll_y);
+        Some(ResolvedOffsets {
+            top: offset_y,
+            left: offset_x,
+            right: info.max_scroll_x - offset_x,
+            bottom: info.max_scroll_y - offset_y,
         })
     }
 
@@ -810,11 +921,124 @@ impl ScrollManager {
                     visual_width_px,
                     has_horizontal_scrollbar,
                     has_vertical_scrollbar,
+                    scrollable_x: true,
+                    scrollable_y: true,
                 },
             );
         }
     }
 
+    /// Sets which axes of a scroll node can be scrolled, usually derived from
+    /// `overflow-x` / `overflow-y` (see `LayoutOverflow::is_user_scrollable`).
+    ///
+    /// The current offset is re-clamped, so a non-scrollable axis snaps back to 0.
+    pub fn set_scrollable_axes(
+        &mut self,
+        dom_id: DomId,
+        node_id: NodeId,
+        scrollable_x: bool,
+        scrollable_y: bool,
+    ) {
+        if let Some(state) = self.states.get_mut(&(dom_id, node_id)) {
+            state.scrollable_x = scrollable_x;
+            state.scrollable_y = scrollable_y;
+            state.current_offset = state.clamp(state.current_offset);
+            if let Some(target) = state.animation.as_ref().map(|a| state.clamp(a.target_offset)) {
+                if let Some(animation) = state.animation.as_mut() {
+                    animation.target_offset = target;
+                }
+            }
+        }
+    }
+
+    /// Returns `(scrollable_x, scrollable_y)` for a registered scroll node
+    pub fn get_scrollable_axes(&self, dom_id: DomId, node_id: NodeId) -> Option<(bool, bool)> {
+        self.states
+            .get(&(dom_id, node_id))
+            .map(|s| (s.scrollable_x, s.scrollable_y))
+    }
+
+    // Scroll Anchoring
+
+    /// Records the children of a scroll container and their positions after layout
+    pub fn set_anchor_candidates(
+        &mut self,
+        dom_id: DomId,
+        node_id: NodeId,
+        candidates: Vec<ScrollAnchorCandidate>,
+    ) {
+        self.anchor_candidates.insert((dom_id, node_id), candidates);
+    }
+
+    /// Forgets all recorded anchor candidates (before re-recording them)
+    pub fn clear_anchor_candidates(&mut self) {
+        self.anchor_candidates.clear();
+    }
+
+    /// Returns the scroll anchor of a container: the first child (in DOM order)
+    /// that is at least partially below the current scroll offset.
+    ///
+    /// Like in CSS, a container scrolled to the very top has no anchor, so
+    /// content inserted at the top stays visible.
+    pub fn get_scroll_anchor(&self, dom_id: DomId, node_id: NodeId) -> Option<ScrollAnchorCandidate> {
+        let offset = self.states.get(&(dom_id, node_id))?.current_offset;
+        if offset.y <= 0.0 {
+            return None;
+        }
+        self.anchor_candidates
+            .get(&(dom_id, node_id))?
+            .iter()
+            .find(|c| c.bottom > offset.y)
+            .copied()
+    }
+
+    /// CSS scroll anchoring: after a relayout, shifts the vertical scroll offset
+    /// of every container in `dom_id` by how far its anchor moved, so that
+    /// the visible content does not jump when content above it changes size.
+    ///
+    /// `anchor_top(container, anchor)` returns the new top edge of `anchor`
+    /// relative to `container`, or `None` if either is no longer laid out.
+    /// The offset is not clamped here: the content size is only updated when
+    /// the scroll node is re-registered, which clamps it.
+    ///
+    /// Returns the containers whose offset was adjusted.
+    pub fn apply_scroll_anchoring(
+        &mut self,
+        dom_id: DomId,
+        anchor_top: impl Fn(NodeId, NodeId) -> Option<f32>,
+    ) -> Vec<NodeId> {
+        let containers: Vec<NodeId> = self
+            .anchor_candidates
+            .keys()
+            .filter(|(d, _)| *d == dom_id)
+            .map(|(_, node_id)| *node_id)
+            .collect();
+
+        let mut adjusted = Vec::new();
+        for container in containers {
+            let Some(anchor) = self.get_scroll_anchor(dom_id, container) else {
+                continue;
+            };
+            let Some(new_top) = anchor_top(container, anchor.node_id) else {
+                continue;
+            };
+            let delta = new_top - anchor.top;
+            if delta == 0.0 {
+                continue;
+            }
+            let Some(state) = self.states.get_mut(&(dom_id, container)) else {
+                continue;
+            };
+            state.current_offset.y += delta;
+            if let Some(animation) = state.animation.as_mut() {
+                animation.start_offset.y += delta;
+                animation.target_offset.y += delta;
+            }
+            adjusted.push(container);
+        }
+        adjusted
+    }
+
     // ExternalScrollId Management
 
     /// Register a scroll node and get its ExternalScrollId for WebRender.
@@ -1042,6 +1266,33 @@ impl ScrollManager {
         None
     }
 
+    /// Adds a synthetic scrollbar hit to `hit_test` if `global_pos` is over
+    /// a scrollbar thumb or track.
+    ///
+    /// WebRender only reports hits for tagged content, so without this the
+    /// scrollbar geometry would never show up in `scrollbar_hit_test_nodes`
+    /// and clicks on a thumb would be treated as clicks on the content below.
+    pub fn append_scrollbar_hits(&self, hit_test: &mut FullHitTest, global_pos: LogicalPosition) {
+        let hit = match self.hit_test_scrollbars(global_pos) {
+            Some(hit) => hit,
+            None => return,
+        };
+
+        hit_test
+            .hovered_nodes
+            .entry(hit.dom_id)
+            .or_insert_with(HitTest::empty)
+            .scrollbar_hit_test_nodes
+            .insert(
+                hit.to_hit_id(),
+                ScrollbarHitTestItem {
+                    point_in_viewport: global_pos,
+                    point_relative_to_item: hit.local_position,
+                    orientation: hit.orientation,
+                },
+            );
+    }
+
     /// Perform hit-testing for all scrollbars at the given global position.
     ///
     /// This iterates through all visible scrollbars in reverse z-order (top to bottom)
@@ -1105,10 +1356,13 @@ impl AnimatedScrollState {
             visual_width_px: 0.0,
             has_horizontal_scrollbar: false,
             has_vertical_scrollbar: false,
+            scrollable_x: true,
+            scrollable_y: true,
         }
     }
 
     /// Clamp a scroll position to valid bounds (0 to max_scroll).
+    /// Axes that are not scrollable are always clamped to 0.
     ///
     /// When `virtual_scroll_size` is set (for VirtualView nodes), the max bounds
     /// are computed from the virtual size instead of content_rect.
@@ -1119,8 +1373,16 @@ impl AnimatedScrollState {
         let effective_height = self.virtual_scroll_size
             .map(|s| s.height)
             .unwrap_or(self.content_rect.size.height);
-        let max_x = (effective_width - self.container_rect.size.width).max(0.0);
-        let max_y = (effective_height - self.container_rect.size.height).max(0.0);
+        let max_x = if self.scrollable_x {
+            (effective_width - self.container_rect.size.width).max(0.0)
+        } else {
+            0.0
+        };
+        let max_y = if self.scrollable_y {
+            (effective_height - self.container_rect.size.height).max(0.0)
+        } else {
+            0.0
+        };
         LogicalPosition {
             x: position.x.max(0.0).min(max_x),
             y: position.y.max(0.0).min(max_y),
@@ -1128,6 +1390,27 @@ impl AnimatedScrollState {
     }
 }
 
+/// All scroll hit-test nodes, innermost first.
+///
+/// Node IDs are assigned in depth-first order, so within one DOM a nested
+/// container always has a higher ID than the containers around it (and child
+/// DOMs have higher IDs than the DOM that embeds them).
+fn scroll_hit_nodes_innermost_first(
+    hit_test: &FullHitTest,
+) -> impl Iterator<Item = (DomId, NodeId)> + '_ {
+    hit_test
+        .hovered_nodes
+        .iter()
+        .rev()
+        .flat_map(|(dom_id, hit_node)| {
+            hit_node
+                .scroll_hit_test_nodes
+                .keys()
+                .rev()
+                .map(move |node_id| (*dom_id, *node_id))
+        })
+}
+
 // Easing Functions
 
 /// Apply an easing function to a normalized time value (0.0 to 1.0).
@@ -1162,40 +1445,93 @@ impl ScrollManager {
         // Only remap nodes that actually moved (old_id != new_id).
         // Nodes NOT in the map are stable (kept same NodeId) — don't touch them.
         // We cannot distinguish "not moved" from "removed" with just node_moves,
-        // so we conservatively keep states that aren't in the map.
-        
+        // so we conservatively keep states that aren't in the map (see
+        // `prune_removed_nodes` for dropping them).
+        //
+        // All moved entries are taken out first and re-inserted afterwards, so
+        // that shifted nodes (1 -> 2, 2 -> 3) don't overwrite each other.
+        let moved = |node_id: &NodeId| {
+            node_id_map.get(node_id).map_or(false, |new_id| new_id != node_id)
+        };
+
         // Remap states
-        for (&old_node_id, &new_node_id) in node_id_map.iter() {
-            if old_node_id != new_node_id {
-                if let Some(state) = self.states.remove(&(dom_id, old_node_id)) {
-                    self.states.insert((dom_id, new_node_id), state);
-                }
-            }
+        let states_to_remap: Vec<_> = self.states.keys()
+            .filter(|(d, node_id)| *d == dom_id && moved(node_id))
+            .cloned()
+            .collect();
+        let states: Vec<_> = states_to_remap.into_iter()
+            .filter_map(|key| Some((node_id_map[&key.1], self.states.remove(&key)?)))
+            .collect();
+        for (new_node_id, state) in states {
+            self.states.insert((dom_id, new_node_id), state);
         }
-        
+
         // Remap external_scroll_ids
-        for (&old_node_id, &new_node_id) in node_id_map.iter() {
-            if old_node_id != new_node_id {
-                if let Some(scroll_id) = self.external_scroll_ids.remove(&(dom_id, old_node_id)) {
-                    self.external_scroll_ids.insert((dom_id, new_node_id), scroll_id);
-                }
-            }
+        let scroll_ids_to_remap: Vec<_> = self.external_scroll_ids.keys()
+            .filter(|(d, node_id)| *d == dom_id && moved(node_id))
+            .cloned()
+            .collect();
+        let scroll_ids: Vec<_> = scroll_ids_to_remap.into_iter()
+            .filter_map(|key| Some((node_id_map[&key.1], self.external_scroll_ids.remove(&key)?)))
+            .collect();
+        for (new_node_id, scroll_id) in scroll_ids {
+            self.external_scroll_ids.insert((dom_id, new_node_id), scroll_id);
         }
-        
+
         // Remap scrollbar_states
         let scrollbar_states_to_remap: Vec<_> = self.scrollbar_states.keys()
-            .filter(|(d, node_id, _)| {
-                *d == dom_id && node_id_map.get(node_id).map_or(false, |new_id| new_id != node_id)
+            .filter(|(d, node_id, _)| *d == dom_id && moved(node_id))
+            .cloned()
+            .collect();
+        let scrollbar_states: Vec<_> = scrollbar_states_to_remap.into_iter()
+            .filter_map(|key| {
+                let state = self.scrollbar_states.remove(&key)?;
+                Some((node_id_map[&key.1], key.2, state))
             })
+            .collect();
+        for (new_node_id, orientation, state) in scrollbar_states {
+            self.scrollbar_states.insert((dom_id, new_node_id, orientation), state);
+        }
+
+        // Remap anchor candidates (both the container and its children)
+        let candidate_keys: Vec<_> = self.anchor_candidates.keys()
+            .filter(|(d, _)| *d == dom_id)
             .cloned()
             .collect();
-        
-        for (d, old_node_id, orientation) in scrollbar_states_to_remap {
-            if let Some(&new_node_id) = node_id_map.get(&old_node_id) {
-                if let Some(state) = self.scrollbar_states.remove(&(d, old_node_id, orientation)) {
-                    self.scrollbar_states.insert((d, new_node_id, orientation), state);
+        let candidates: Vec<_> = candidate_keys.into_iter()
+            .filter_map(|key| Some((key.1, self.anchor_candidates.remove(&key)?)))
+            .collect();
+        for (node_id, mut candidates) in candidates {
+            for candidate in candidates.iter_mut() {
+                if let Some(new_id) = node_id_map.get(&candidate.node_id) {
+                    candidate.node_id = *new_id;
                 }
             }
+            let new_node_id = node_id_map.get(&node_id).copied().unwrap_or(node_id);
+            self.anchor_candidates.insert((dom_id, new_node_id), candidates);
+        }
+    }
+
+    /// Drops all scroll state of nodes that did not survive DOM reconciliation
+    ///
+    /// `node_id_map` must contain *every* old node that was matched to a node
+    /// in the new DOM (moved or not), as produced by `reconcile_dom`. Any state
+    /// keyed by an old NodeId missing from the map belongs to a removed node.
+    /// Must be called before `remap_node_ids`, while the keys are still old ids.
+    pub fn prune_removed_nodes(
+        &mut self,
+        dom_id: DomId,
+        node_id_map: &std::collections::BTreeMap<NodeId, NodeId>,
+    ) {
+        let survives = |d: &DomId, node_id: &NodeId| {
+            *d != dom_id || node_id_map.contains_key(node_id)
+        };
+        self.states.retain(|(d, node_id), _| survives(d, node_id));
+        self.external_scroll_ids.retain(|(d, node_id), _| survives(d, node_id));
+        self.scrollbar_states.retain(|(d, node_id, _), _| survives(d, node_id));
+        self.anchor_candidates.retain(|(d, node_id), _| survives(d, node_id));
+        for ((d, _), candidates) in self.anchor_candidates.iter_mut() {
+            candidates.retain(|c| survives(d, &c.node_id));
         }
     }
 }
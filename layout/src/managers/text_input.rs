@@ -73,6 +73,17 @@ impl PendingTextEdit {
 
         result.into()
     }
+
+    /// The inserted text if it is exactly one `char`, `None` for empty input
+    /// and for multi-char sequences (IME commits, dead key + combining mark),
+    /// which are only available through `inserted_text`
+    pub fn inserted_char(&self) -> Option<char> {
+        let mut chars = self.inserted_text.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
 }
 
 /// C-compatible Option type for PendingTextEdit
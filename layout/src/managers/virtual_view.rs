@@ -118,6 +118,16 @@ impl VirtualViewManager {
         self.states.get(&(dom_id, node_id)).map(|s| s.nested_dom_id)
     }
 
+    /// Gets the (parent DomId, NodeId) of the VirtualView element hosting a nested DOM
+    ///
+    /// Used to bubble events out of a VirtualView into the DOM that embeds it.
+    pub fn get_host_of_nested_dom(&self, nested_dom_id: DomId) -> Option<(DomId, NodeId)> {
+        self.states
+            .iter()
+            .find(|(_, s)| s.nested_dom_id == nested_dom_id)
+            .map(|(key, _)| *key)
+    }
+
     /// Gets or creates a WebRender PipelineId for a VirtualView
     ///
     /// PipelineIds are used by WebRender to identify distinct rendering contexts.
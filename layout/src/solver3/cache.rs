@@ -48,11 +48,12 @@ use crate::{
             MultiValue,
         },
         layout_tree::{
-            is_block_level, AnonymousBoxType, DirtyFlag, LayoutNode, LayoutTreeBuilder, SubtreeHash,
+            get_display_type, is_block_level, AnonymousBoxType, DirtyFlag, LayoutNode,
+            LayoutTreeBuilder, SubtreeHash,
         },
         positioning::get_position_type,
         scrollbar::ScrollbarRequirements,
-        sizing::calculate_used_size_for_node,
+        sizing::{calculate_used_size_for_node, has_definite_percentage_height_basis},
         LayoutContext, LayoutError, LayoutTree, Result,
     },
     text3::cache::AvailableSpace as Text3AvailableSpace,
@@ -481,6 +482,7 @@ pub const fn style_text_align_to_fc(text_align: StyleTextAlign) -> fc::TextAlign
 /// Collects DOM child IDs from the node hierarchy into a Vec.
 ///
 /// This is a helper function that flattens the sibling iteration into a simple loop.
+/// `display: none` children are skipped, they don't generate a box.
 pub fn collect_children_dom_ids(styled_dom: &StyledDom, parent_dom_id: NodeId) -> Vec<NodeId> {
     let hierarchy_container = styled_dom.node_hierarchy.as_container();
     let mut children = Vec::new();
@@ -489,17 +491,14 @@ pub fn collect_children_dom_ids(styled_dom: &StyledDom, parent_dom_id: NodeId) -
         return children;
     };
 
-    let Some(mut child_id) = hierarchy_item.first_child_id(parent_dom_id) else {
-        return children;
-    };
-
-    children.push(child_id);
-    while let Some(hierarchy_item) = hierarchy_container.get(child_id) {
-        let Some(next) = hierarchy_item.next_sibling_id() else {
-            break;
-        };
-        children.push(next);
-        child_id = next;
+    let mut next_child = hierarchy_item.first_child_id(parent_dom_id);
+    while let Some(child_id) = next_child {
+        if get_display_type(styled_dom, child_id) != LayoutDisplay::None {
+            children.push(child_id);
+        }
+        next_child = hierarchy_container
+            .get(child_id)
+            .and_then(|item| item.next_sibling_id());
     }
 
     children
@@ -1746,6 +1745,17 @@ pub fn calculate_layout_for_subtree<T: ParsedFontTrait>(
         None => MultiValue::Auto, // Anonymous boxes have auto height
     };
 
+    // CSS 2.2 § 10.5: a percentage height without a definite basis computes to 'auto'
+    let css_height = match (dom_id, &css_height) {
+        (Some(id), MultiValue::Exact(LayoutHeight::Px(px)))
+            if px.to_percent().is_some()
+                && !has_definite_percentage_height_basis(ctx.styled_dom, id) =>
+        {
+            MultiValue::Auto
+        }
+        _ => css_height,
+    };
+
     // Check if this node is a scroll container (overflow: scroll/auto).
     // Scroll containers must NOT expand to fit content — their height is
     // determined by the containing block, and overflow is scrollable.
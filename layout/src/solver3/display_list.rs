@@ -49,6 +49,7 @@ use crate::{
         UnifiedLayout,
     },
     solver3::{
+        geometry::clip_rect_per_axis,
         getters::{
             get_background_color, get_background_contents, get_border_info, get_border_radius,
             get_break_after, get_break_before, get_caret_style, get_overflow_x, get_overflow_y,
@@ -750,7 +751,7 @@ pub enum DisplayListItem {
 }
 
 // Helper structs for the DisplayList
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
 pub struct BorderRadius {
     pub top_left: f32,
     pub top_right: f32,
@@ -765,6 +766,56 @@ impl BorderRadius {
             && self.bottom_left == 0.0
             && self.bottom_right == 0.0
     }
+
+    /// Returns whether `point` lies inside `rect` with its corners rounded by
+    /// these radii, i.e. clicks in the cut-off corner areas miss.
+    ///
+    /// Radii that don't fit the box are scaled down proportionally as per
+    /// CSS Backgrounds 3 § 5.5 (overlapping curves).
+    pub fn contains_point(&self, rect: &LogicalRect, point: LogicalPosition) -> bool {
+        let LogicalRect { origin, size } = *rect;
+        if point.x < origin.x
+            || point.y < origin.y
+            || point.x > origin.x + size.width
+            || point.y > origin.y + size.height
+        {
+            return false;
+        }
+        if self.is_zero() {
+            return true;
+        }
+
+        let fit = |side: f32, a: f32, b: f32| if a + b > side { side / (a + b) } else { 1.0 };
+        let scale = fit(size.width, self.top_left, self.top_right)
+            .min(fit(size.width, self.bottom_left, self.bottom_right))
+            .min(fit(size.height, self.top_left, self.bottom_left))
+            .min(fit(size.height, self.top_right, self.bottom_right));
+
+        let x = point.x - origin.x;
+        let y = point.y - origin.y;
+        // (radius, x and y of the box corner)
+        let corners = [
+            (self.top_left * scale, 0.0, 0.0),
+            (self.top_right * scale, size.width, 0.0),
+            (self.bottom_left * scale, 0.0, size.height),
+            (self.bottom_right * scale, size.width, size.height),
+        ];
+        corners.iter().all(|&(r, corner_x, corner_y)| {
+            if r <= 0.0 {
+                return true;
+            }
+            // Center of the corner's circle
+            let cx = if corner_x == 0.0 { r } else { corner_x - r };
+            let cy = if corner_y == 0.0 { r } else { corner_y - r };
+            let in_corner_x = if corner_x == 0.0 { x < cx } else { x > cx };
+            let in_corner_y = if corner_y == 0.0 { y < cy } else { y > cy };
+            if !(in_corner_x && in_corner_y) {
+                return true;
+            }
+            let (dx, dy) = (x - cx, y - cy);
+            dx * dx + dy * dy <= r * r
+        })
+    }
 }
 
 // Dummy types for compilation
@@ -2426,7 +2477,13 @@ where
                 builder.push_scroll_frame(clip_rect, content_size, scroll_id);
             }
         } else {
-            // Simple clip for hidden/clip
+            // Simple clip for hidden/clip, only on the axes that actually clip
+            let clip_rect = clip_rect_per_axis(
+                clip_rect,
+                overflow_x.is_clipped(),
+                overflow_y.is_clipped(),
+            )
+            .unwrap_or(clip_rect);
             builder.push_clip(clip_rect, border_radius);
         }
 
@@ -3709,55 +3766,52 @@ where
         let Some(dom_id) = node.dom_node_id else {
             return false;
         };
+        establishes_stacking_context(self.ctx.styled_dom, dom_id)
+    }
+}
 
-        let position = get_position_type(self.ctx.styled_dom, Some(dom_id));
-        if position == LayoutPosition::Absolute || position == LayoutPosition::Fixed {
-            return true;
-        }
-
-        let z_index = get_z_index(self.ctx.styled_dom, Some(dom_id));
-        if position == LayoutPosition::Relative && z_index != 0 {
-            return true;
-        }
-
-        if let Some(styled_node) = self.ctx.styled_dom.styled_nodes.as_container().get(dom_id) {
-            let node_data = &self.ctx.styled_dom.node_data.as_container()[dom_id];
-            let node_state =
-                &self.ctx.styled_dom.styled_nodes.as_container()[dom_id].styled_node_state;
-
-            // Opacity < 1
-            let opacity = self
-                .ctx
-                .styled_dom
-                .css_property_cache
-                .ptr
-                .get_opacity(node_data, &dom_id, node_state)
-                .and_then(|v| v.get_property())
-                .map(|v| v.inner.normalized())
-                .unwrap_or(1.0);
-
-            if opacity < 1.0 {
-                return true;
-            }
-
-            // Transform != none
-            let has_transform = self
-                .ctx
-                .styled_dom
-                .css_property_cache
-                .ptr
-                .get_transform(node_data, &dom_id, node_state)
-                .and_then(|v| v.get_property())
-                .map(|v| !v.is_empty())
-                .unwrap_or(false);
+/// Returns whether the DOM node establishes a new stacking context: absolute /
+/// fixed positioning, relative positioning with a non-zero `z-index`,
+/// `opacity < 1` or a non-empty `transform`.
+pub(crate) fn establishes_stacking_context(styled_dom: &StyledDom, dom_id: NodeId) -> bool {
+    let position = get_position_type(styled_dom, Some(dom_id));
+    if position == LayoutPosition::Absolute || position == LayoutPosition::Fixed {
+        return true;
+    }
 
-            if has_transform {
-                return true;
-            }
-        }
+    let z_index = get_z_index(styled_dom, Some(dom_id));
+    if position == LayoutPosition::Relative && z_index != 0 {
+        return true;
+    }
 
-        false
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let Some(styled_node) = styled_nodes.get(dom_id) else {
+        return false;
+    };
+    let node_data = &styled_dom.node_data.as_container()[dom_id];
+    let node_state = &styled_node.styled_node_state;
+
+    // Opacity < 1
+    let opacity = styled_dom
+        .css_property_cache
+        .ptr
+        .get_opacity(node_data, &dom_id, node_state)
+        .and_then(|v| v.get_property())
+        .map(|v| v.inner.normalized())
+        .unwrap_or(1.0);
+
+    if opacity < 1.0 {
+        return true;
     }
+
+    // Transform != none
+    styled_dom
+        .css_property_cache
+        .ptr
+        .get_transform(node_data, &dom_id, node_state)
+        .and_then(|v| v.get_property())
+        .map(|v| !v.is_empty())
+        .unwrap_or(false)
 }
 
 /// Helper struct to pass layout results to the display list generator.
@@ -44,8 +44,8 @@ use azul_css::{
             ColorU, PhysicalSize, PropertyContext, ResolutionContext, SizeMetric,
         },
         layout::{
-            ColumnCount, LayoutBorderSpacing, LayoutClear, LayoutDisplay, LayoutFloat,
-            LayoutHeight, LayoutJustifyContent, LayoutOverflow, LayoutPosition, LayoutTableLayout,
+            ColumnCount, DirectionalOverflowInfo, LayoutBorderSpacing, LayoutClear, LayoutDisplay,
+            LayoutFloat, LayoutHeight, LayoutJustifyContent, LayoutOverflow, LayoutPosition, LayoutTableLayout,
             LayoutTextJustify, LayoutWidth, LayoutWritingMode, ShapeInside, ShapeOutside,
             StyleBorderCollapse, StyleCaptionSide,
         },
@@ -139,6 +139,17 @@ impl OverflowBehavior {
     pub fn is_scroll(&self) -> bool {
         matches!(self, Self::Scroll | Self::Auto)
     }
+
+    /// Converts back to the CSS `overflow` value.
+    pub const fn to_layout_overflow(&self) -> LayoutOverflow {
+        match self {
+            Self::Visible => LayoutOverflow::Visible,
+            Self::Hidden => LayoutOverflow::Hidden,
+            Self::Clip => LayoutOverflow::Clip,
+            Self::Scroll => LayoutOverflow::Scroll,
+            Self::Auto => LayoutOverflow::Auto,
+        }
+    }
 }
 
 /// Input constraints for a layout function.
@@ -2902,6 +2913,8 @@ fn translate_to_text3_constraints<'a, T: ParsedFontTrait>(
         text_wrap,
         text_combine_upright,
         segment_alignment: SegmentAlignment::Total,
+        // No text-overflow property yet, overflowing no-wrap text is clipped
+        overflow_ellipsis: false,
         overflow: match overflow_behaviour {
             LayoutOverflow::Visible => text3::cache::OverflowBehavior::Visible,
             LayoutOverflow::Hidden | LayoutOverflow::Clip => text3::cache::OverflowBehavior::Hidden,
@@ -6140,17 +6153,17 @@ pub fn check_scrollbar_necessity(
     // Note: scrollbar_width_px can be 0 for overlay scrollbars (e.g. macOS),
     // but we still need to register scroll nodes so that scrolling works —
     // overlay scrollbars just don't reserve any layout space.
-    let mut needs_horizontal = match overflow_x {
-        OverflowBehavior::Visible | OverflowBehavior::Hidden | OverflowBehavior::Clip => false,
-        OverflowBehavior::Scroll => true,
-        OverflowBehavior::Auto => content_size.width > container_size.width + EPSILON,
+    // Overflow within EPSILON doesn't count, so the amount is measured from
+    // the tolerance and rounded up: any overflow past it is at least 1px.
+    let overflow_info = |overflow: OverflowBehavior, content: f32, container: f32| {
+        let amount = (content - container - EPSILON).ceil() as isize;
+        DirectionalOverflowInfo::from_css_and_amount(overflow.to_layout_overflow(), Some(amount))
     };
 
-    let mut needs_vertical = match overflow_y {
-        OverflowBehavior::Visible | OverflowBehavior::Hidden | OverflowBehavior::Clip => false,
-        OverflowBehavior::Scroll => true,
-        OverflowBehavior::Auto => content_size.height > container_size.height + EPSILON,
-    };
+    let mut needs_horizontal =
+        overflow_info(overflow_x, content_size.width, container_size.width).needs_scrollbar();
+    let mut needs_vertical =
+        overflow_info(overflow_y, content_size.height, container_size.height).needs_scrollbar();
 
     // A classic layout problem: a vertical scrollbar can reduce horizontal space,
     // causing a horizontal scrollbar to appear, which can reduce vertical space...
@@ -6,9 +6,11 @@ use azul_core::{
 };
 use azul_css::props::{
     basic::{pixel::PixelValue, PhysicalSize, PropertyContext, ResolutionContext, SizeMetric},
-    layout::LayoutWritingMode,
+    layout::{LayoutOverflow, LayoutWritingMode},
 };
 
+use crate::solver3::display_list::BorderRadius;
+
 /// Represents the CSS `box-sizing` property.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BoxSizing {
@@ -27,6 +29,70 @@ pub struct PositionedRectangle {
     pub border: ResolvedOffsets,
     /// Padding of the rectangle.
     pub padding: ResolvedOffsets,
+    /// Resolved corner radii of the border box.
+    pub border_radius: BorderRadius,
+    /// Computed `overflow-x` of the node.
+    pub overflow_x: LayoutOverflow,
+    /// Computed `overflow-y` of the node.
+    pub overflow_y: LayoutOverflow,
+}
+
+impl PositionedRectangle {
+    /// Returns whether the point hits the rectangle, taking rounded corners
+    /// into account.
+    pub fn contains_point(&self, point: LogicalPosition) -> bool {
+        self.border_radius.contains_point(&self.bounds, point)
+    }
+
+    /// Returns the rect that the children of this node are clipped to: the
+    /// padding box on every axis whose overflow clips, unbounded on axes
+    /// with `overflow: visible`. `None` if neither axis clips.
+    pub fn get_clip_rect(&self) -> Option<LogicalRect> {
+        let padding_box = LogicalRect::new(
+            LogicalPosition::new(
+                self.bounds.origin.x + self.border.left,
+                self.bounds.origin.y + self.border.top,
+            ),
+            LogicalSize::new(
+                (self.bounds.size.width - self.border.left - self.border.right).max(0.0),
+                (self.bounds.size.height - self.border.top - self.border.bottom).max(0.0),
+            ),
+        );
+        clip_rect_per_axis(
+            padding_box,
+            self.overflow_x.is_clipped(),
+            self.overflow_y.is_clipped(),
+        )
+    }
+}
+
+/// Extent used for the unclipped axis of a clip rect. Large enough to never
+/// cut off content, but finite so the renderer can still do math with it.
+const UNCLIPPED_EXTENT: f32 = 1.0e9;
+
+/// Widens `rect` to `UNCLIPPED_EXTENT` on the axes that don't clip, so that
+/// e.g. `overflow-x: hidden; overflow-y: visible` only cuts off content on the
+/// left and right. Returns `None` if neither axis clips.
+pub fn clip_rect_per_axis(rect: LogicalRect, clip_x: bool, clip_y: bool) -> Option<LogicalRect> {
+    if !clip_x && !clip_y {
+        return None;
+    }
+
+    let (x, width) = if clip_x {
+        (rect.origin.x, rect.size.width)
+    } else {
+        (-UNCLIPPED_EXTENT, 2.0 * UNCLIPPED_EXTENT)
+    };
+    let (y, height) = if clip_y {
+        (rect.origin.y, rect.size.height)
+    } else {
+        (-UNCLIPPED_EXTENT, 2.0 * UNCLIPPED_EXTENT)
+    };
+
+    Some(LogicalRect::new(
+        LogicalPosition::new(x, y),
+        LogicalSize::new(width, height),
+    ))
 }
 
 /// Represents the four edges of a box for properties like margin, padding, border.
@@ -305,6 +371,9 @@ pub struct IntrinsicSizes {
     pub max_content_height: f32,
     /// The height specified by CSS properties, if any.
     pub preferred_height: Option<f32>,
+    /// The preferred width / height ratio, if any (e.g. the natural ratio of
+    /// an image). Used to derive one dimension when only the other is given.
+    pub aspect_ratio: Option<f32>,
 }
 
 impl IntrinsicSizes {
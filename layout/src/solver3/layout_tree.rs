@@ -605,6 +605,12 @@ impl LayoutNode {
 
         content_size
     }
+
+    /// Returns true if the node's box has zero width or height (or was not sized).
+    pub fn is_empty(&self) -> bool {
+        self.used_size
+            .map_or(true, |size| size.width <= 0.0 || size.height <= 0.0)
+    }
 }
 
 /// CSS pseudo-elements that can be generated
@@ -800,6 +806,19 @@ impl LayoutTree {
 
         None
     }
+
+    /// Returns true if painting this node could produce anything: either its
+    /// box has a non-zero size or its IFC lays out (possibly overflowing) inline
+    /// content. Used to cull nodes during painting and hit testing.
+    pub fn has_visible_content(&self, layout_index: usize) -> bool {
+        let Some(layout_node) = self.nodes.get(layout_index) else {
+            return false;
+        };
+        !layout_node.is_empty()
+            || self
+                .get_inline_layout_for_node(layout_index)
+                .map_or(false, |layout| !layout.items.is_empty())
+    }
 }
 
 /// Generate layout tree from styled DOM with proper anonymous box generation
@@ -1102,6 +1121,11 @@ impl LayoutTreeBuilder {
 
             let child_display = get_display_type(styled_dom, child_id);
 
+            // display: none children generate no box, not even an anonymous wrapper
+            if child_display == LayoutDisplay::None {
+                continue;
+            }
+
             // CSS 2.2 Section 17.2.1, Stage 2:
             // "Generate missing child wrappers"
             if child_display == LayoutDisplay::TableCell {
@@ -1188,6 +1212,11 @@ impl LayoutTreeBuilder {
 
             let child_display = get_display_type(styled_dom, child_id);
 
+            // display: none children generate no box, not even an anonymous wrapper
+            if child_display == LayoutDisplay::None {
+                continue;
+            }
+
             // CSS 2.2 Section 17.2.1, Stage 2:
             // "If a child C of a table-row parent P is not a table-cell, then generate
             // an anonymous table-cell box around C"
@@ -416,3 +416,206 @@ fn find_absolute_containing_block_rect(
 
     Ok(viewport) // Fallback to the initial containing block.
 }
+
+/// Returns the offset of a node relative to the margin-box origin of its nearest
+/// `position: absolute` / `position: fixed` ancestor (or the layout root if there is none).
+///
+/// Walks up the ancestor chain summing the per-step offsets between each node and its
+/// parent. The walk stops at the first out-of-flow ancestor, since that ancestor starts
+/// a new accumulation base: moving it moves the whole subtree without changing the
+/// offsets inside of it.
+///
+/// Nodes without a calculated position contribute a zero offset.
+pub fn get_accumulated_offset(
+    tree: &LayoutTree,
+    calculated_positions: &super::PositionVec,
+    node_index: usize,
+) -> LogicalPosition {
+    let mut offset = LogicalPosition::zero();
+    let mut current = node_index;
+
+    while let Some(parent_index) = tree.get(current).and_then(|n| n.parent) {
+        let node_pos = super::pos_get(calculated_positions, current).unwrap_or_default();
+        let parent_pos = super::pos_get(calculated_positions, parent_index).unwrap_or_default();
+        offset.x += node_pos.x - parent_pos.x;
+        offset.y += node_pos.y - parent_pos.y;
+
+        let parent_is_out_of_flow = tree.get(parent_index).map_or(false, |p| {
+            matches!(
+                p.computed_style.position,
+                LayoutPosition::Absolute | LayoutPosition::Fixed
+            )
+        });
+
+        if parent_is_out_of_flow {
+            break;
+        }
+
+        current = parent_index;
+    }
+
+    offset
+}
+
+/// Returns how far a `position: sticky` node has to be shifted from its in-flow
+/// position to stay inside the scrollport of its nearest scroll container, given
+/// the current scroll offset of that container.
+///
+/// CSS Positioned Layout 3 § 3.4: the `top` / `right` / `bottom` / `left` insets
+/// shrink the scrollport into the sticky view rectangle, and the node is never
+/// shifted out of its containing block. Layout itself is scroll-independent, so
+/// this offset is applied on top of `calculated_positions` by the caller.
+///
+/// Returns a zero offset for non-sticky nodes and for sticky nodes without a
+/// scroll container ancestor.
+pub fn get_sticky_offset(
+    styled_dom: &StyledDom,
+    tree: &LayoutTree,
+    calculated_positions: &super::PositionVec,
+    node_index: usize,
+    scroll_offset: impl Fn(NodeId) -> Option<LogicalPosition>,
+) -> LogicalPosition {
+    let Some(node) = tree.get(node_index) else {
+        return LogicalPosition::zero();
+    };
+    if node.computed_style.position != LayoutPosition::Sticky {
+        return LogicalPosition::zero();
+    }
+
+    let Some(parent_index) = node.parent else {
+        return LogicalPosition::zero();
+    };
+    let mut container_index = parent_index;
+    while !is_scroll_container(tree, container_index) {
+        match tree.get(container_index).and_then(|n| n.parent) {
+            Some(p) => container_index = p,
+            None => return LogicalPosition::zero(),
+        }
+    }
+
+    let (Some(rect), Some(scrollport), Some(mut containing_block)) = (
+        border_box(tree, calculated_positions, node_index),
+        padding_box(tree, calculated_positions, container_index),
+        content_box(tree, calculated_positions, parent_index),
+    ) else {
+        return LogicalPosition::zero();
+    };
+
+    // A direct child of the scroll container may travel through the whole
+    // scrollable area, not only the part that is currently visible
+    if parent_index == container_index {
+        let content_size = tree.nodes[container_index].get_content_size();
+        containing_block.size.width = containing_block.size.width.max(content_size.width);
+        containing_block.size.height = containing_block.size.height.max(content_size.height);
+    }
+
+    let scroll = tree.nodes[container_index]
+        .dom_node_id
+        .and_then(&scroll_offset)
+        .unwrap_or_default();
+    let view = LogicalRect::new(
+        LogicalPosition::new(scrollport.origin.x + scroll.x, scrollport.origin.y + scroll.y),
+        scrollport.size,
+    );
+    let insets = resolve_position_offsets(styled_dom, node.dom_node_id, scrollport.size);
+
+    LogicalPosition::new(
+        sticky_shift(
+            (rect.origin.x, rect.origin.x + rect.size.width),
+            (view.origin.x, view.origin.x + view.size.width),
+            (
+                containing_block.origin.x,
+                containing_block.origin.x + containing_block.size.width,
+            ),
+            (insets.left, insets.right),
+        ),
+        sticky_shift(
+            (rect.origin.y, rect.origin.y + rect.size.height),
+            (view.origin.y, view.origin.y + view.size.height),
+            (
+                containing_block.origin.y,
+                containing_block.origin.y + containing_block.size.height,
+            ),
+            (insets.top, insets.bottom),
+        ),
+    )
+}
+
+/// Shift along one axis, all arguments are `(start, end)` pairs. The start
+/// inset wins if both insets apply, same as `top` over `bottom`.
+fn sticky_shift(
+    node: (f32, f32),
+    view: (f32, f32),
+    containing_block: (f32, f32),
+    insets: (Option<f32>, Option<f32>),
+) -> f32 {
+    if let Some(start) = insets.0 {
+        let min_start = view.0 + start;
+        if node.0 < min_start {
+            let max_shift = (containing_block.1 - node.1).max(0.0);
+            return (min_start - node.0).min(max_shift);
+        }
+    }
+    if let Some(end) = insets.1 {
+        let max_end = view.1 - end;
+        if node.1 > max_end {
+            let max_shift = (containing_block.0 - node.0).min(0.0);
+            return (max_end - node.1).max(max_shift);
+        }
+    }
+    0.0
+}
+
+/// A node establishes a scroll container if it does not let its content overflow
+/// visibly on either axis (`overflow: clip` does not scroll, so it does not count)
+fn is_scroll_container(tree: &LayoutTree, node_index: usize) -> bool {
+    use azul_css::props::layout::LayoutOverflow;
+
+    tree.get(node_index).map_or(false, |n| {
+        let scrolls =
+            |o: LayoutOverflow| !matches!(o, LayoutOverflow::Visible | LayoutOverflow::Clip);
+        scrolls(n.computed_style.overflow_x) || scrolls(n.computed_style.overflow_y)
+    })
+}
+
+fn border_box(
+    tree: &LayoutTree,
+    calculated_positions: &super::PositionVec,
+    node_index: usize,
+) -> Option<LogicalRect> {
+    let origin = super::pos_get(calculated_positions, node_index)?;
+    let size = tree.get(node_index)?.used_size?;
+    Some(LogicalRect::new(origin, size))
+}
+
+fn padding_box(
+    tree: &LayoutTree,
+    calculated_positions: &super::PositionVec,
+    node_index: usize,
+) -> Option<LogicalRect> {
+    let rect = border_box(tree, calculated_positions, node_index)?;
+    let border = &tree.get(node_index)?.box_props.border;
+    Some(LogicalRect::new(
+        LogicalPosition::new(rect.origin.x + border.left, rect.origin.y + border.top),
+        LogicalSize::new(
+            (rect.size.width - border.left - border.right).max(0.0),
+            (rect.size.height - border.top - border.bottom).max(0.0),
+        ),
+    ))
+}
+
+fn content_box(
+    tree: &LayoutTree,
+    calculated_positions: &super::PositionVec,
+    node_index: usize,
+) -> Option<LogicalRect> {
+    let rect = padding_box(tree, calculated_positions, node_index)?;
+    let padding = &tree.get(node_index)?.box_props.padding;
+    Some(LogicalRect::new(
+        LogicalPosition::new(rect.origin.x + padding.left, rect.origin.y + padding.top),
+        LogicalSize::new(
+            (rect.size.width - padding.left - padding.right).max(0.0),
+            (rect.size.height - padding.top - padding.bottom).max(0.0),
+        ),
+    ))
+}
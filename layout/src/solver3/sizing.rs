@@ -110,6 +110,49 @@ pub fn resolve_percentage_with_box_model(
     (containing_block_dimension * percentage).max(0.0)
 }
 
+/// Returns whether a percentage `height` on `node_id` has a definite basis.
+///
+/// CSS 2.2 § 10.5: "If the height of the containing block is not specified
+/// explicitly (i.e., it depends on content height), and this element is not
+/// absolutely positioned, the value computes to 'auto'." Resolving against
+/// the provisional height of an auto-height parent would be circular.
+///
+/// Only block containers are checked: flex and grid items are sized by Taffy,
+/// which has its own definiteness rules, and the root resolves against the
+/// (always definite) initial containing block.
+pub fn has_definite_percentage_height_basis(styled_dom: &StyledDom, node_id: NodeId) -> bool {
+    if matches!(
+        get_position_type(styled_dom, Some(node_id)),
+        LayoutPosition::Absolute | LayoutPosition::Fixed
+    ) {
+        return true;
+    }
+
+    let Some(parent_id) = styled_dom.node_hierarchy.as_container()[node_id].parent_id() else {
+        return true;
+    };
+
+    match get_display_property(styled_dom, Some(parent_id)).unwrap_or_default() {
+        LayoutDisplay::Block
+        | LayoutDisplay::FlowRoot
+        | LayoutDisplay::ListItem
+        | LayoutDisplay::InlineBlock => {}
+        _ => return true,
+    }
+
+    let parent_state = &styled_dom.styled_nodes.as_container()[parent_id].styled_node_state;
+    match get_css_height(styled_dom, parent_id, parent_state).unwrap_or_default() {
+        LayoutHeight::Px(px) if px.to_percent().is_some() => {
+            has_definite_percentage_height_basis(styled_dom, parent_id)
+        }
+        LayoutHeight::Px(_) => true,
+        LayoutHeight::Auto
+        | LayoutHeight::MinContent
+        | LayoutHeight::MaxContent
+        | LayoutHeight::Calc(_) => false,
+    }
+}
+
 /// Phase 2a: Calculate intrinsic sizes (bottom-up pass)
 pub fn calculate_intrinsic_sizes<T: ParsedFontTrait>(
     ctx: &mut LayoutContext<'_, T>,
@@ -201,6 +244,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
                     min_content_height: 150.0,
                     max_content_height: 150.0,
                     preferred_height: None, // Will be determined by CSS or flex-grow
+                    aspect_ratio: None,
                 });
             }
             
@@ -209,6 +253,13 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
                 let size = image_ref.get_size();
                 let width = if size.width > 0.0 { size.width } else { 100.0 };
                 let height = if size.height > 0.0 { size.height } else { 100.0 };
+                // A degenerate image has no natural aspect ratio, don't derive
+                // one from the fallback size
+                let aspect_ratio = if size.width > 0.0 && size.height > 0.0 {
+                    Some(size.width / size.height)
+                } else {
+                    None
+                };
                 return Ok(IntrinsicSizes {
                     min_content_width: width,
                     max_content_width: width,
@@ -216,6 +267,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
                     min_content_height: height,
                     max_content_height: height,
                     preferred_height: Some(height),
+                    aspect_ratio,
                 });
             }
         }
@@ -426,6 +478,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
                     min_content_height: 20.0,
                     max_content_height: 20.0,
                     preferred_height: None,
+                    aspect_ratio: None,
                 });
             }
         };
@@ -452,33 +505,27 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
             Err(_) => min_layout.clone(),
         };
 
-        let min_width = min_layout
+        // `bounds()` walks every positioned item, so only compute it once per layout
+        let min_bounds = min_layout
             .fragment_layouts
             .get("min")
-            .map(|l| l.bounds().width)
-            .unwrap_or(0.0);
-
-        let max_width = max_layout
+            .map(|l| l.bounds())
+            .unwrap_or_default();
+        let max_bounds = max_layout
             .fragment_layouts
             .get("max")
-            .map(|l| l.bounds().width)
-            .unwrap_or(0.0);
+            .map(|l| l.bounds())
+            .unwrap_or_default();
+
+        let min_width = min_bounds.width;
+        let max_width = max_bounds.width;
 
         // CSS Intrinsic & Extrinsic Sizing Module Level 3:
         // min-content height is the height when content is laid out at min-content width
         // max-content height is the height when content is laid out at max-content width
         // These can differ when text wraps differently at different widths.
-        let min_content_height = min_layout
-            .fragment_layouts
-            .get("min")
-            .map(|l| l.bounds().height)
-            .unwrap_or(0.0);
-
-        let max_content_height = max_layout
-            .fragment_layouts
-            .get("max")
-            .map(|l| l.bounds().height)
-            .unwrap_or(0.0);
+        let min_content_height = min_bounds.height;
+        let max_content_height = max_bounds.height;
 
         Ok(IntrinsicSizes {
             min_content_width: min_width,
@@ -487,6 +534,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
             min_content_height,
             max_content_height,
             preferred_height: None,
+            aspect_ratio: None,
         })
     }
 
@@ -556,6 +604,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
             min_content_height: min_height,
             max_content_height: max_height,
             preferred_height: None,
+            aspect_ratio: None,
         })
     }
 
@@ -623,6 +672,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
                     min_content_height: 20.0, // Arbitrary fallback height
                     max_content_height: 20.0,
                     preferred_height: None,
+                    aspect_ratio: None,
                 });
             }
         };
@@ -658,24 +708,21 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
             }
         };
 
+        // `bounds()` walks every positioned item, so only compute it once per layout
         let min_width = min_layout
             .fragment_layouts
             .get("min")
             .map(|l| l.bounds().width)
             .unwrap_or(0.0);
-
-        let max_width = max_layout
+        let max_bounds = max_layout
             .fragment_layouts
             .get("max")
-            .map(|l| l.bounds().width)
-            .unwrap_or(0.0);
+            .map(|l| l.bounds())
+            .unwrap_or_default();
 
+        let max_width = max_bounds.width;
         // The height is typically calculated at the max_content_width.
-        let height = max_layout
-            .fragment_layouts
-            .get("max")
-            .map(|l| l.bounds().height)
-            .unwrap_or(0.0);
+        let height = max_bounds.height;
 
         Ok(IntrinsicSizes {
             min_content_width: min_width,
@@ -684,6 +731,7 @@ impl<'a, 'b, T: ParsedFontTrait> IntrinsicSizeCalculator<'a, 'b, T> {
             min_content_height: height, // Height can change with width, but this is a common model.
             max_content_height: height,
             preferred_height: None,
+            aspect_ratio: None,
         })
     }
 
@@ -1018,6 +1066,7 @@ fn calculate_node_intrinsic_sizes_stub<T: ParsedFontTrait>(
             max_content_height: max_height,
             preferred_width: None,
             preferred_height: None,
+            aspect_ratio: None,
         }
     } else {
         // Vertical stacking (Block): width is widest child, height is sum of children
@@ -1028,6 +1077,7 @@ fn calculate_node_intrinsic_sizes_stub<T: ParsedFontTrait>(
             max_content_height: total_height, // all children stacked
             preferred_width: None,
             preferred_height: None,
+            aspect_ratio: None,
         }
     }
 }
@@ -1072,6 +1122,10 @@ pub fn calculate_used_size_for_node(
     let css_height = get_css_height(styled_dom, id, node_state);
     let writing_mode = get_writing_mode(styled_dom, id, node_state);
     let display = get_display_property(styled_dom, Some(id));
+    let width_is_auto =
+        !css_width.is_exact() || matches!(css_width, MultiValue::Exact(LayoutWidth::Auto));
+    let height_is_auto =
+        !css_height.is_exact() || matches!(css_height, MultiValue::Exact(LayoutHeight::Auto));
 
     // Step 1: Resolve the CSS `width` property into a concrete pixel value.
     // Percentage values for `width` are resolved against the containing block's width.
@@ -1186,14 +1240,17 @@ pub fn calculate_used_size_for_node(
             match pixels_opt {
                 Some(pixels) => pixels,
                 None => match px.to_percent() {
-                    Some(p) => resolve_percentage_with_box_model(
-                        containing_block_size.height,
-                        p.get(),
-                        (_box_props.margin.top, _box_props.margin.bottom),
-                        (_box_props.border.top, _box_props.border.bottom),
-                        (_box_props.padding.top, _box_props.padding.bottom),
-                    ),
-                    None => intrinsic.max_content_height,
+                    Some(p) if has_definite_percentage_height_basis(styled_dom, id) => {
+                        resolve_percentage_with_box_model(
+                            containing_block_size.height,
+                            p.get(),
+                            (_box_props.margin.top, _box_props.margin.bottom),
+                            (_box_props.border.top, _box_props.border.bottom),
+                            (_box_props.padding.top, _box_props.padding.bottom),
+                        )
+                    }
+                    // Indefinite basis: the percentage behaves as `auto`
+                    _ => intrinsic.max_content_height,
                 },
             }
         }
@@ -1202,6 +1259,15 @@ pub fn calculate_used_size_for_node(
         LayoutHeight::Calc(_) => intrinsic.max_content_height, // TODO: resolve calc
     };
 
+    // Step 2b: If only one dimension is given and the node has a preferred aspect
+    // ratio (e.g. an image), derive the other one from it (CSS Sizing 4 § 5.1).
+    let (resolved_width, resolved_height) =
+        match (intrinsic.aspect_ratio, width_is_auto, height_is_auto) {
+            (Some(ratio), false, true) if ratio > 0.0 => (resolved_width, resolved_width / ratio),
+            (Some(ratio), true, false) if ratio > 0.0 => (resolved_height * ratio, resolved_height),
+            _ => (resolved_width, resolved_height),
+        };
+
     // Step 3: Apply min/max constraints (CSS 2.2 § 10.4 and § 10.7)
     // "The tentative used width is calculated (without 'min-width' and 'max-width')
     // ...If the tentative used width is greater than 'max-width', the rules above are
@@ -533,6 +533,7 @@ fn pixel_to_lp(pv: PixelValue) -> taffy::LengthPercentage {
 /// Slow path for flex-basis: full property cache lookup + decode.
 /// Extracted to avoid duplicating the logic in the compact fast-path fallback.
 fn flex_basis_slow_path(
+    styled_dom: &StyledDom,
     cache: &azul_core::prop_cache::CssPropertyCache,
     node_data: &azul_core::dom::NodeData,
     id: &NodeId,
@@ -545,6 +546,16 @@ fn flex_basis_slow_path(
             if let CssProperty::FlexBasis(v) = p {
                 let basis = match v.get_property_or_default().unwrap_or_default() {
                     LayoutFlexBasis::Auto => taffy::Dimension::auto(),
+                    LayoutFlexBasis::Content => {
+                        // `content` is `auto` with the main size ignored, so Taffy falls
+                        // back to the max-content size of the item
+                        if parent_main_axis_is_row(styled_dom, *id) {
+                            taffy_style.size.width = taffy::Dimension::auto();
+                        } else {
+                            taffy_style.size.height = taffy::Dimension::auto();
+                        }
+                        taffy::Dimension::auto()
+                    }
                     LayoutFlexBasis::Exact(pv) => pixel_value_to_pixels_fallback(&pv)
                         .map(taffy::Dimension::length)
                         .or_else(|| pv.to_percent().map(|p| taffy::Dimension::percent(p.get())))
@@ -563,6 +574,18 @@ fn flex_basis_slow_path(
         .unwrap_or_else(taffy::Dimension::auto)
 }
 
+/// Whether the flex container around `id` lays out its items horizontally
+fn parent_main_axis_is_row(styled_dom: &StyledDom, id: NodeId) -> bool {
+    let Some(parent_id) = styled_dom.node_hierarchy.as_container()[id].parent_id() else {
+        return true;
+    };
+    let parent_state = &styled_dom.styled_nodes.as_container()[parent_id].styled_node_state;
+    !matches!(
+        get_flex_direction(styled_dom, parent_id, parent_state),
+        MultiValue::Exact(LayoutFlexDirection::Column | LayoutFlexDirection::ColumnReverse)
+    )
+}
+
 /// The bridge struct that implements Taffy's traits.
 /// It holds mutable references to the solver's data structures, allowing Taffy
 /// to read styles and write layout results back into our `LayoutTree`.
@@ -731,7 +754,11 @@ impl<'a, 'b, T: ParsedFontTrait> TaffyBridge<'a, 'b, T> {
         };
 
         // Grid & gap properties
-        taffy_style.gap = cache
+        //
+        // The parser expands the `gap` shorthand into `row-gap` / `column-gap`,
+        // a `Gap` property only exists if it was set on the node directly.
+        // Gap can use %, em, rem - convert properly
+        let gap = cache
             .get_property(node_data, &id, node_state, &CssPropertyType::Gap)
             .and_then(|p| {
                 if let CssProperty::Gap(v) = p {
@@ -740,16 +767,19 @@ impl<'a, 'b, T: ParsedFontTrait> TaffyBridge<'a, 'b, T> {
                     None
                 }
             })
-            .map(|v| {
-                let val = v.get_property_or_default().unwrap_or_default().inner;
-                // Gap can use %, em, rem - convert properly
-                let gap_lp = pixel_to_lp(val);
-                Size {
-                    width: gap_lp,
-                    height: gap_lp,
-                }
-            })
-            .unwrap_or_else(Size::zero);
+            .map(|v| pixel_to_lp(v.get_property_or_default().unwrap_or_default().inner));
+        let row_gap = cache
+            .get_row_gap(node_data, &id, node_state)
+            .and_then(|v| v.get_property())
+            .map(|v| pixel_to_lp(v.inner));
+        let column_gap = cache
+            .get_column_gap(node_data, &id, node_state)
+            .and_then(|v| v.get_property())
+            .map(|v| pixel_to_lp(v.inner));
+        taffy_style.gap = Size {
+            width: column_gap.or(gap).unwrap_or(taffy::LengthPercentage::ZERO),
+            height: row_gap.or(gap).unwrap_or(taffy::LengthPercentage::ZERO),
+        };
 
         // Grid template rows - convert GridTemplate to Vec<GridTemplateComponent>
         taffy_style.grid_template_rows = cache
@@ -1011,7 +1041,7 @@ impl<'a, 'b, T: ParsedFontTrait> TaffyBridge<'a, 'b, T> {
                     azul_css::compact_cache::U32_SENTINEL
                     | azul_css::compact_cache::U32_INHERIT => {
                         // Sentinel/inherit: fall through to slow path
-                        flex_basis_slow_path(cache, node_data, &id, node_state, &mut taffy_style)
+                        flex_basis_slow_path(styled_dom, cache, node_data, &id, node_state, &mut taffy_style)
                     }
                     _ => {
                         // Try to decode the PixelValue from compact u32
@@ -1030,10 +1060,10 @@ impl<'a, 'b, T: ParsedFontTrait> TaffyBridge<'a, 'b, T> {
                     }
                 }
             } else {
-                flex_basis_slow_path(cache, node_data, &id, node_state, &mut taffy_style)
+                flex_basis_slow_path(styled_dom, cache, node_data, &id, node_state, &mut taffy_style)
             }
         } else {
-            flex_basis_slow_path(cache, node_data, &id, node_state, &mut taffy_style)
+            flex_basis_slow_path(styled_dom, cache, node_data, &id, node_state, &mut taffy_style)
         };
         taffy_style.align_self = cache
             .get_property(node_data, &id, node_state, &CssPropertyType::AlignSelf)
@@ -1,9 +1,10 @@
 use std::{
     any::{Any, TypeId},
+    borrow::Cow,
     cmp::Ordering,
     collections::{
         hash_map::{DefaultHasher, Entry, HashMap},
-        BTreeSet,
+        BTreeSet, VecDeque,
     },
     hash::{Hash, Hasher},
     mem::discriminant,
@@ -669,6 +670,9 @@ pub struct CursorBoundsError {
 /// - `text_wrap`: \u2705 balance, pretty, stable
 /// - `line_clamp`: \u2705 Max number of lines
 ///
+/// ## CSS Overflow Module Level 3
+/// - `overflow_ellipsis`: \u2705 `text-overflow: ellipsis` for no-wrap lines
+///
 /// ## CSS Writing Modes Level 4
 /// - `text_combine_upright`: \u2705 Tate-chu-yoko for vertical text
 ///
@@ -708,6 +712,8 @@ pub struct UnifiedConstraints {
     // Overflow handling
     pub overflow: OverflowBehavior,
     pub segment_alignment: SegmentAlignment,
+    // text-overflow: ellipsis, only applies with TextWrap::NoWrap
+    pub overflow_ellipsis: bool,
 
     // Advanced features
     pub text_combine_upright: Option<TextCombineUpright>,
@@ -748,6 +754,7 @@ impl Default for UnifiedConstraints {
             vertical_align: VerticalAlign::default(),
             overflow: OverflowBehavior::default(),
             segment_alignment: SegmentAlignment::default(),
+            overflow_ellipsis: false,
             text_combine_upright: None,
             exclusion_margin: 0.0,
             hyphenation: false,
@@ -780,6 +787,7 @@ impl Hash for UnifiedConstraints {
         (self.line_height.round() as usize).hash(state);
         self.vertical_align.hash(state);
         self.overflow.hash(state);
+        self.overflow_ellipsis.hash(state);
         self.text_combine_upright.hash(state);
         (self.exclusion_margin.round() as usize).hash(state);
         self.hyphenation.hash(state);
@@ -808,6 +816,7 @@ impl PartialEq for UnifiedConstraints {
             && round_eq(self.line_height, other.line_height)
             && self.vertical_align == other.vertical_align
             && self.overflow == other.overflow
+            && self.overflow_ellipsis == other.overflow_ellipsis
             && self.text_combine_upright == other.text_combine_upright
             && round_eq(self.exclusion_margin, other.exclusion_margin)
             && self.hyphenation == other.hyphenation
@@ -2439,6 +2448,52 @@ pub enum TextTransform {
     Capitalize,
 }
 
+impl TextTransform {
+    /// Applies the transform to `text` (locale-insensitive).
+    ///
+    /// A character is only replaced if its case mapping is a single character
+    /// of the same UTF-8 length (e.g. `ß` stays `ß` instead of becoming `SS`),
+    /// so byte offsets into the transformed text are valid for the original
+    /// text as well. `Capitalize` uppercases the first letter or digit after
+    /// whitespace and leaves the rest of the word untouched.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        fn map_same_len(c: char, mut mapped: impl Iterator<Item = char>) -> char {
+            match (mapped.next(), mapped.next()) {
+                (Some(m), None) if m.len_utf8() == c.len_utf8() => m,
+                _ => c,
+            }
+        }
+
+        match self {
+            TextTransform::None => Cow::Borrowed(text),
+            TextTransform::Uppercase => {
+                Cow::Owned(text.chars().map(|c| map_same_len(c, c.to_uppercase())).collect())
+            }
+            TextTransform::Lowercase => {
+                Cow::Owned(text.chars().map(|c| map_same_len(c, c.to_lowercase())).collect())
+            }
+            TextTransform::Capitalize => {
+                let mut at_word_start = true;
+                Cow::Owned(
+                    text.chars()
+                        .map(|c| {
+                            if c.is_whitespace() {
+                                at_word_start = true;
+                                c
+                            } else if at_word_start && c.is_alphanumeric() {
+                                at_word_start = false;
+                                map_same_len(c, c.to_uppercase())
+                            } else {
+                                c
+                            }
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
 // Type alias for OpenType feature tags
 pub type FourCc = [u8; 4];
 
@@ -2572,6 +2627,7 @@ impl Hash for StyleProperties {
         self.writing_mode.hash(state);
         self.text_orientation.hash(state);
         self.text_combine_upright.hash(state);
+        self.text_transform.hash(state);
         self.letter_spacing.hash(state);
         self.word_spacing.hash(state);
 
@@ -2599,42 +2655,54 @@ impl StyleProperties {
     /// This allows the layout cache to reuse layouts when only rendering
     /// properties change (e.g., color changes on hover).
     pub fn layout_hash(&self) -> u64 {
-        use std::hash::Hasher;
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        
+        self.hash_layout_properties(&mut hasher);
+        // Spacing (affects glyph positions)
+        self.letter_spacing.hash(&mut hasher);
+        self.word_spacing.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the `layout_hash` without `letter-spacing` and `word-spacing`.
+    ///
+    /// Spacing is added on top of the shaped advances when measuring clusters,
+    /// so two styles with the same shaping hash produce identical glyphs.
+    pub fn shaping_hash(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash_layout_properties(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Hashes the layout-affecting properties except for the spacing.
+    fn hash_layout_properties<H: Hasher>(&self, hasher: &mut H) {
         // Font selection (affects shaping and metrics)
-        self.font_stack.hash(&mut hasher);
-        (self.font_size_px.round() as usize).hash(&mut hasher);
-        self.font_features.hash(&mut hasher);
+        self.font_stack.hash(hasher);
+        (self.font_size_px.round() as usize).hash(hasher);
+        self.font_features.hash(hasher);
         // font_variations affects glyph outlines
         for (tag, value) in &self.font_variations {
-            tag.hash(&mut hasher);
-            (value.round() as i32).hash(&mut hasher);
+            tag.hash(hasher);
+            (value.round() as i32).hash(hasher);
         }
-        
-        // Spacing (affects glyph positions)
-        self.letter_spacing.hash(&mut hasher);
-        self.word_spacing.hash(&mut hasher);
-        (self.line_height.round() as usize).hash(&mut hasher);
-        (self.tab_size.round() as usize).hash(&mut hasher);
-        
+
+        (self.line_height.round() as usize).hash(hasher);
+        (self.tab_size.round() as usize).hash(hasher);
+
         // Writing mode (affects layout direction)
-        self.writing_mode.hash(&mut hasher);
-        self.text_orientation.hash(&mut hasher);
-        self.text_combine_upright.hash(&mut hasher);
-        
+        self.writing_mode.hash(hasher);
+        self.text_orientation.hash(hasher);
+        self.text_combine_upright.hash(hasher);
+
         // Text transform (affects which characters are used)
-        self.text_transform.hash(&mut hasher);
-        
+        self.text_transform.hash(hasher);
+
         // Font variants (affect glyph selection)
-        self.font_variant_caps.hash(&mut hasher);
-        self.font_variant_numeric.hash(&mut hasher);
-        self.font_variant_ligatures.hash(&mut hasher);
-        self.font_variant_east_asian.hash(&mut hasher);
-        
-        hasher.finish()
+        self.font_variant_caps.hash(hasher);
+        self.font_variant_numeric.hash(hasher);
+        self.font_variant_ligatures.hash(hasher);
+        self.font_variant_east_asian.hash(hasher);
     }
-    
+
     /// Check if two StyleProperties have the same layout-affecting properties.
     /// 
     /// Returns true if the layouts would be identical (only rendering differs).
@@ -3294,6 +3362,63 @@ impl UnifiedLayout {
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
+
+    /// Returns the number of lines that contain text from the given DOM node.
+    pub fn line_count_for_node(&self, node_id: NodeId) -> usize {
+        let lines: BTreeSet<usize> = self
+            .items
+            .iter()
+            .filter(|item| match &item.item {
+                ShapedItem::Cluster(c) => c.source_node_id == Some(node_id),
+                _ => false,
+            })
+            .map(|item| item.line_index)
+            .collect();
+        lines.len()
+    }
+
+    /// Mirrors the words of one line within `0..line_width`, so that the first
+    /// word ends up rightmost. Clusters inside a word keep their relative
+    /// positions, separators are mirrored one by one.
+    ///
+    /// Only a building block for RTL lines, it does not reorder mixed-direction runs.
+    pub fn reverse_line_words(&mut self, line_index: usize, line_width: f32) {
+        let indices: Vec<usize> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.line_index == line_index)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let mut start = 0;
+        while start < indices.len() {
+            let mut end = start + 1;
+            if !is_word_separator(&self.items[indices[start]].item) {
+                while end < indices.len() && !is_word_separator(&self.items[indices[end]].item) {
+                    end += 1;
+                }
+            }
+
+            let run = &indices[start..end];
+            let run_start = run
+                .iter()
+                .map(|i| self.items[*i].position.x)
+                .fold(f32::MAX, f32::min);
+            let run_end = run
+                .iter()
+                .map(|i| self.items[*i].position.x + self.items[*i].item.bounds().width)
+                .fold(f32::MIN, f32::max);
+
+            // [run_start, run_end] -> [line_width - run_end, line_width - run_start]
+            let shift = line_width - run_end - run_start;
+            for i in run {
+                self.items[*i].position.x += shift;
+            }
+            start = end;
+        }
+    }
+
     pub fn last_baseline(&self) -> Option<f32> {
         self.items
             .iter()
@@ -3301,6 +3426,46 @@ impl UnifiedLayout {
             .find_map(|item| get_baseline_for_item(&item.item))
     }
 
+    /// Returns the y-position of the first line's baseline, relative to the
+    /// layout's origin, or `None` if no line contains text or atomic inlines.
+    pub fn first_line_baseline(&self) -> Option<f32> {
+        self.items
+            .iter()
+            .filter(|item| get_baseline_for_item(&item.item).is_some())
+            .min_by_key(|item| item.line_index)
+            .map(positioned_item_baseline)
+    }
+
+    /// Returns the y-position of the last line's baseline, relative to the
+    /// layout's origin.
+    pub fn last_line_baseline(&self) -> Option<f32> {
+        self.items
+            .iter()
+            .filter(|item| get_baseline_for_item(&item.item).is_some())
+            .max_by_key(|item| item.line_index)
+            .map(positioned_item_baseline)
+    }
+
+    /// Returns the baselines of the first and last line containing text from
+    /// the given DOM node, relative to the layout's origin.
+    pub fn line_baselines_for_node(&self, node_id: NodeId) -> Option<(f32, f32)> {
+        let mut clusters = self.items.iter().filter(|item| match &item.item {
+            ShapedItem::Cluster(c) => c.source_node_id == Some(node_id) && !c.glyphs.is_empty(),
+            _ => false,
+        });
+        let first = clusters.next()?;
+        let (first, last) = clusters.fold((first, first), |(first, last), item| {
+            (
+                if item.line_index < first.line_index { item } else { first },
+                if item.line_index >= last.line_index { item } else { last },
+            )
+        });
+        Some((
+            positioned_item_baseline(first),
+            positioned_item_baseline(last),
+        ))
+    }
+
     /// Takes a point relative to the layout's origin and returns the closest
     /// logical cursor position.
     ///
@@ -3382,6 +3547,64 @@ impl UnifiedLayout {
         })
     }
 
+    /// Takes a point relative to the layout's origin and returns the caret
+    /// index under it, counted in grapheme clusters in logical order: `0` is
+    /// before the first cluster, `n` after the last one.
+    ///
+    /// Uses the same closest-cluster search as `hittest_cursor`, a point in
+    /// the trailing half of a cluster (or past the end of a line) places the
+    /// caret after it. Returns `None` for a layout without text.
+    pub fn hit_test_char(&self, point: LogicalPosition) -> Option<usize> {
+        let cursor = self.hittest_cursor(point)?;
+        let index = self
+            .logical_cluster_ids()
+            .binary_search(&cursor.cluster_id)
+            .ok()?;
+        Some(match cursor.affinity {
+            CursorAffinity::Leading => index,
+            CursorAffinity::Trailing => index + 1,
+        })
+    }
+
+    /// Selection rectangles for the caret range `start_char..end_char`
+    /// (caret indices as returned by `hit_test_char`), one per visual line.
+    ///
+    /// Reversed ranges are normalized and indices past the end are clamped.
+    /// An empty range yields no rectangles. See `get_selection_rects`.
+    pub fn selection_rects(&self, start_char: usize, end_char: usize) -> Vec<LogicalRect> {
+        let clusters = self.logical_cluster_ids();
+        let start = start_char.min(end_char).min(clusters.len());
+        let end = start_char.max(end_char).min(clusters.len());
+        if start == end {
+            return Vec::new();
+        }
+
+        // Leading edge of the first selected cluster to the trailing edge of
+        // the last one, so a range ending at a line break stays on its line
+        let range = SelectionRange {
+            start: TextCursor {
+                cluster_id: clusters[start],
+                affinity: CursorAffinity::Leading,
+            },
+            end: TextCursor {
+                cluster_id: clusters[end - 1],
+                affinity: CursorAffinity::Trailing,
+            },
+        };
+        self.get_selection_rects(&range)
+    }
+
+    /// Cluster IDs of all clusters in the layout, in logical (source) order
+    fn logical_cluster_ids(&self) -> Vec<GraphemeClusterId> {
+        let mut clusters: Vec<GraphemeClusterId> = self
+            .items
+            .iter()
+            .filter_map(|item| Some(item.item.as_cluster()?.source_cluster_id))
+            .collect();
+        clusters.sort();
+        clusters
+    }
+
     /// Given a logical selection range, returns a vector of visual rectangles
     /// that cover the selected text, in the layout's coordinate space.
     pub fn get_selection_rects(&self, range: &SelectionRange) -> Vec<LogicalRect> {
@@ -3525,6 +3748,112 @@ impl UnifiedLayout {
         rects
     }
 
+    /// Returns the plain text covered by a logical selection range, e.g. for
+    /// copying to the clipboard.
+    ///
+    /// Lines that were soft-wrapped are joined without a separator, while hard
+    /// line breaks (`<br>`, or a preserved `\n`) are emitted as `\n`.
+    pub fn selected_text(&self, range: &SelectionRange) -> String {
+        let (start, end) = if range.start.cluster_id > range.end.cluster_id
+            || (range.start.cluster_id == range.end.cluster_id
+                && range.start.affinity > range.end.affinity)
+        {
+            (range.end, range.start)
+        } else {
+            (range.start, range.end)
+        };
+
+        // A cursor on the leading edge of a cluster selects it, a cursor on the
+        // trailing edge does not (and vice versa for the end of the range).
+        let is_selected = |id: GraphemeClusterId| {
+            let after_start = match start.affinity {
+                CursorAffinity::Leading => id >= start.cluster_id,
+                CursorAffinity::Trailing => id > start.cluster_id,
+            };
+            let before_end = match end.affinity {
+                CursorAffinity::Leading => id < end.cluster_id,
+                CursorAffinity::Trailing => id <= end.cluster_id,
+            };
+            after_start && before_end
+        };
+
+        let selected = |item: &PositionedItem| {
+            item.item
+                .as_cluster()
+                .map_or(false, |c| is_selected(c.source_cluster_id))
+        };
+
+        let Some(first) = self.items.iter().position(selected) else {
+            return String::new();
+        };
+        let last = self.items.iter().rposition(selected).unwrap_or(first);
+
+        let mut text = String::new();
+        for item in &self.items[first..=last] {
+            match &item.item {
+                ShapedItem::Cluster(c) if is_selected(c.source_cluster_id) => {
+                    text.push_str(&c.text);
+                }
+                ShapedItem::Break { .. } => text.push('\n'),
+                ShapedItem::Tab { .. } => text.push('\t'),
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Returns the rectangle covering the `word_index`-th word of the layout
+    /// (counting from zero in logical order).
+    ///
+    /// A word is a run of adjacent non-whitespace clusters on one line, so a
+    /// word never spans lines. Returns `None` if `word_index` is out of range.
+    pub fn bounds_of_word(&self, word_index: usize) -> Option<LogicalRect> {
+        let mut current_word = None::<usize>;
+        let mut words_seen = 0;
+        let mut bounds = None::<(f32, f32, f32, f32)>;
+        let mut prev_line = None;
+
+        for item in &self.items {
+            let in_word = item.item.as_cluster().is_some() && !is_word_separator(&item.item);
+            let same_line = prev_line == Some(item.line_index);
+            prev_line = Some(item.line_index);
+
+            if !in_word || !same_line {
+                if current_word == Some(word_index) {
+                    break;
+                }
+                current_word = None;
+            }
+            if !in_word {
+                continue;
+            }
+            if current_word.is_none() {
+                current_word = Some(words_seen);
+                words_seen += 1;
+            }
+            if current_word != Some(word_index) {
+                continue;
+            }
+
+            let size = item.item.bounds();
+            let (x0, y0) = (item.position.x, item.position.y);
+            let (x1, y1) = (x0 + size.width, y0 + size.height);
+            bounds = Some(match bounds {
+                None => (x0, y0, x1, y1),
+                Some((a, b, c, d)) => (a.min(x0), b.min(y0), c.max(x1), d.max(y1)),
+            });
+        }
+
+        let (x0, y0, x1, y1) = bounds?;
+        Some(LogicalRect {
+            origin: LogicalPosition { x: x0, y: y0 },
+            size: LogicalSize {
+                width: x1 - x0,
+                height: y1 - y0,
+            },
+        })
+    }
+
     /// Calculates the visual rectangle for a cursor at a given logical position.
     pub fn get_cursor_rect(&self, cursor: &TextCursor) -> Option<LogicalRect> {
         // Find the item and glyph corresponding to the cursor's cluster ID.
@@ -4274,6 +4603,12 @@ impl UnifiedLayout {
     }
 }
 
+/// Items are placed so that `position.y + ascent` sits on the line's baseline
+/// (see `position_one_line`).
+fn positioned_item_baseline(item: &PositionedItem) -> f32 {
+    item.position.y + get_item_vertical_metrics(&item.item).0
+}
+
 fn get_baseline_for_item(item: &ShapedItem) -> Option<f32> {
     match item {
         ShapedItem::CombinedBlock {
@@ -4355,6 +4690,9 @@ pub struct FlowLayout {
     pub remaining_items: Vec<ShapedItem>,
 }
 
+/// Maximum number of entries kept in the spacing-agnostic shaping cache
+const MAX_SHAPED_ITEMS_BY_GLYPHS: usize = 256;
+
 pub struct LayoutCache {
     // Stage 1 Cache: InlineContent -> LogicalItems
     logical_items: HashMap<CacheId, Arc<Vec<LogicalItem>>>,
@@ -4364,6 +4702,13 @@ pub struct LayoutCache {
     shaped_items: HashMap<CacheId, Arc<Vec<ShapedItem>>>,
     // Stage 4 Cache: ShapedItems + Constraints -> Final Layout (now strongly typed)
     layouts: HashMap<CacheId, Arc<UnifiedLayout>>,
+    // Stage 3 fallback: spacing-agnostic key -> ShapedItems, used to skip re-shaping
+    // when only letter-spacing / word-spacing changed
+    shaped_items_by_glyphs: HashMap<CacheId, Arc<Vec<ShapedItem>>>,
+    // Insertion order of `shaped_items_by_glyphs`, oldest first, for eviction
+    shaped_items_by_glyphs_order: VecDeque<CacheId>,
+    // Number of times stage 3 actually ran the shaper (cache misses)
+    shaping_runs: usize,
 }
 
 impl LayoutCache {
@@ -4373,9 +4718,32 @@ impl LayoutCache {
             visual_items: HashMap::new(),
             shaped_items: HashMap::new(),
             layouts: HashMap::new(),
+            shaped_items_by_glyphs: HashMap::new(),
+            shaped_items_by_glyphs_order: VecDeque::new(),
+            shaping_runs: 0,
         }
     }
 
+    /// Stores shaped items under their spacing-agnostic key, evicting the oldest
+    /// entries once `MAX_SHAPED_ITEMS_BY_GLYPHS` is exceeded
+    fn insert_shaped_items_by_glyphs(&mut self, id: CacheId, items: Arc<Vec<ShapedItem>>) {
+        if self.shaped_items_by_glyphs.insert(id, items).is_some() {
+            return;
+        }
+        self.shaped_items_by_glyphs_order.push_back(id);
+        while self.shaped_items_by_glyphs_order.len() > MAX_SHAPED_ITEMS_BY_GLYPHS {
+            if let Some(oldest) = self.shaped_items_by_glyphs_order.pop_front() {
+                self.shaped_items_by_glyphs.remove(&oldest);
+            }
+        }
+    }
+
+    /// Returns how many times text had to be re-shaped (i.e. the shaping
+    /// stage missed both the exact and the spacing-only cache)
+    pub fn get_shaping_runs(&self) -> usize {
+        self.shaping_runs
+    }
+
     /// Get a layout from the cache by its ID
     pub fn get_layout(&self, cache_id: &CacheId) -> Option<&Arc<UnifiedLayout>> {
         self.layouts.get(cache_id)
@@ -4526,6 +4894,66 @@ impl ShapedItemsKey {
     }
 }
 
+/// Computes a shaping key that ignores `letter-spacing` / `word-spacing`.
+///
+/// Returns `None` if the items contain anything other than text, since only
+/// text runs can be re-spaced without re-shaping.
+fn spacing_agnostic_shaping_id(visual_items: &[VisualItem]) -> Option<CacheId> {
+    let mut hasher = DefaultHasher::new();
+    let mut prev_layout_hash = None;
+    for item in visual_items.iter() {
+        let style = match &item.logical_source {
+            LogicalItem::Text { style, source, marker_position_outside, .. } => {
+                source.hash(&mut hasher);
+                marker_position_outside.hash(&mut hasher);
+                style
+            }
+            _ => return None,
+        };
+        item.text.hash(&mut hasher);
+        item.bidi_level.hash(&mut hasher);
+        (item.script as u8).hash(&mut hasher);
+        style.shaping_hash().hash(&mut hasher);
+        // shape_visual_items coalesces neighbouring runs by layout_hash, so the
+        // grouping has to stay the same for the cached glyphs to be reusable
+        let layout_hash = style.layout_hash();
+        (prev_layout_hash == Some(layout_hash)).hash(&mut hasher);
+        prev_layout_hash = Some(layout_hash);
+    }
+    Some(hasher.finish())
+}
+
+/// Re-applies the styles of `visual_items` to previously shaped items.
+///
+/// Used when only the spacing changed: glyphs and advances are still valid,
+/// but the positioning stage reads the spacing from each cluster's style.
+fn respace_shaped_items(shaped: &[ShapedItem], visual_items: &[VisualItem]) -> Vec<ShapedItem> {
+    let styles: HashMap<ContentIndex, &Arc<StyleProperties>> = visual_items
+        .iter()
+        .filter_map(|item| match &item.logical_source {
+            LogicalItem::Text { style, source, .. } => Some((*source, style)),
+            _ => None,
+        })
+        .collect();
+
+    shaped
+        .iter()
+        .map(|item| match item {
+            ShapedItem::Cluster(cluster) => {
+                let mut cluster = cluster.clone();
+                if let Some(style) = styles.get(&cluster.source_content_index) {
+                    cluster.style = (*style).clone();
+                    for glyph in &mut cluster.glyphs {
+                        glyph.style = (*style).clone();
+                    }
+                }
+                ShapedItem::Cluster(cluster)
+            }
+            other => other.clone(),
+        })
+        .collect()
+}
+
 /// Key for the final layout stage.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct LayoutKey {
@@ -4666,14 +5094,30 @@ impl LayoutCache {
                 cached.clone()
             }
             None => {
-                let items = Arc::new(shape_visual_items(
-                    &visual_items,
-                    font_chain_cache,
-                    fc_cache,
-                    loaded_fonts,
-                    debug_messages,
-                )?);
+                // If only letter-spacing / word-spacing changed, the glyphs from the
+                // previous shaping run are still valid - only the styles need updating
+                let glyphs_id = spacing_agnostic_shaping_id(&visual_items);
+                let respaced = glyphs_id
+                    .and_then(|id| self.shaped_items_by_glyphs.get(&id))
+                    .map(|cached| Arc::new(respace_shaped_items(cached, &visual_items)));
+
+                let items = match respaced {
+                    Some(items) => items,
+                    None => {
+                        self.shaping_runs += 1;
+                        Arc::new(shape_visual_items(
+                            &visual_items,
+                            font_chain_cache,
+                            fc_cache,
+                            loaded_fonts,
+                            debug_messages,
+                        )?)
+                    }
+                };
                 self.shaped_items.insert(shaped_items_id, items.clone());
+                if let Some(id) = glyphs_id {
+                    self.insert_shaped_items_by_glyphs(id, items.clone());
+                }
                 items
             }
         };
@@ -5449,6 +5893,7 @@ pub fn shape_visual_items<T: ParsedFontTrait>(
                 text,
             } => {
                 let language = script_to_language(item.script, &item.text);
+                let transformed = style.text_transform.apply(text);
 
                 // Shape CombinedText using either FontRef directly or fontconfig-resolved font
                 let glyphs: Vec<Glyph> = match &style.font_stack {
@@ -5461,7 +5906,7 @@ pub fn shape_visual_items<T: ParsedFontTrait>(
                             )));
                         }
                         font_ref.shape_text(
-                            text,
+                            &transformed,
                             item.script,
                             language,
                             BidiDirection::Ltr,
@@ -5504,7 +5949,7 @@ pub fn shape_visual_items<T: ParsedFontTrait>(
                         match loaded_fonts.get(&font_id) {
                             Some(font) => {
                                 font.shape_text(
-                                    text,
+                                    &transformed,
                                     item.script,
                                     language,
                                     BidiDirection::Ltr,
@@ -5608,7 +6053,10 @@ fn shape_text_correctly<T: ParsedFontTrait>(
     source_index: ContentIndex,
     source_node_id: Option<NodeId>,
 ) -> Result<Vec<ShapedCluster>, LayoutError> {
-    let glyphs = font.shape_text(text, script, language, direction, style.as_ref())?;
+    // Shape the transformed text, but keep the original text in the clusters
+    // (for selection / copy). `apply` keeps byte offsets identical.
+    let transformed = style.text_transform.apply(text);
+    let glyphs = font.shape_text(&transformed, script, language, direction, style.as_ref())?;
 
     if glyphs.is_empty() {
         return Ok(Vec::new());
@@ -6084,6 +6532,15 @@ pub fn perform_fragment_layout<T: ParsedFontTrait>(
         )));
     }
 
+    // text-overflow: ellipsis - a no-wrap line is only broken at hard breaks
+    // and then cut down to the line box (see `truncate_with_ellipsis`)
+    let ellipsize = fragment_constraints.overflow_ellipsis
+        && fragment_constraints.text_wrap == TextWrap::NoWrap
+        && matches!(fragment_constraints.available_width, AvailableSpace::Definite(_));
+    // white-space: pre / nowrap - only hard breaks (preserved newlines, <br>)
+    // end a line, the text overflows the line box instead of wrapping
+    let no_soft_wrap = fragment_constraints.text_wrap == TextWrap::NoWrap;
+
     'column_loop: while current_column < num_columns {
         if let Some(msgs) = debug_messages {
             msgs.push(LayoutDebugMessage::info(format!(
@@ -6214,8 +6671,15 @@ pub fn perform_fragment_layout<T: ParsedFontTrait>(
             // https://www.w3.org/TR/css-text-3/#line-breaking
             // "When an inline box exceeds the logical width of a line box, it is split
             // into several fragments, which are partitioned across multiple line boxes."
-            let (mut line_items, was_hyphenated) =
-                break_one_line(cursor, &line_constraints, false, hyphenator.as_ref(), fonts);
+            let (mut line_items, was_hyphenated) = if ellipsize || no_soft_wrap {
+                let unbounded = LineConstraints {
+                    total_available: f32::MAX / 2.0,
+                    ..line_constraints.clone()
+                };
+                break_one_line(cursor, &unbounded, false, None, fonts)
+            } else {
+                break_one_line(cursor, &line_constraints, false, hyphenator.as_ref(), fonts)
+            };
             if line_items.is_empty() {
                 if let Some(msgs) = debug_messages {
                     msgs.push(LayoutDebugMessage::info(
@@ -6238,6 +6702,10 @@ pub fn perform_fragment_layout<T: ParsedFontTrait>(
                 )));
             }
 
+            if ellipsize {
+                truncate_with_ellipsis(&mut line_items, line_constraints.total_available, fonts);
+            }
+
             let (mut line_pos_items, line_height) = position_one_line(
                 line_items,
                 &line_constraints,
@@ -6287,6 +6755,97 @@ pub fn perform_fragment_layout<T: ParsedFontTrait>(
     Ok(layout)
 }
 
+/// Cuts a line that is wider than `available` down so that it fits together
+/// with a trailing ellipsis, which is shaped in the style of the first cluster
+/// on the line. Lines that already fit are left untouched.
+///
+/// Truncates at the visual end of the line, so this is only correct for LTR text.
+fn truncate_with_ellipsis<T: ParsedFontTrait>(
+    line_items: &mut Vec<ShapedItem>,
+    available: f32,
+    fonts: &LoadedFonts<T>,
+) {
+    let width: f32 = line_items.iter().map(|i| get_item_measure(i, false)).sum();
+    if width <= available {
+        return;
+    }
+
+    let ellipsis = match line_items
+        .iter()
+        .find_map(|i| i.as_cluster())
+        .and_then(|c| shape_ellipsis(c, fonts))
+    {
+        Some(s) => s,
+        None => return,
+    };
+    let ellipsis_width: f32 = ellipsis.iter().map(|c| c.advance).sum();
+
+    // Keep a trailing hard break, it still ends the line
+    let hard_break = match line_items.last() {
+        Some(ShapedItem::Break { .. }) => line_items.pop(),
+        _ => None,
+    };
+
+    let mut used = 0.0;
+    let fitting = line_items
+        .iter()
+        .take_while(|item| {
+            used += get_item_measure(item, false);
+            used + ellipsis_width <= available
+        })
+        .count();
+    line_items.truncate(fitting);
+    // "foo …" looks broken, the ellipsis goes right after the last glyph
+    while line_items.last().map_or(false, is_word_separator) {
+        line_items.pop();
+    }
+
+    line_items.extend(ellipsis.into_iter().map(ShapedItem::Cluster));
+    line_items.extend(hard_break);
+}
+
+/// Shapes "…" (or "..." if the font has no ellipsis glyph) with the font and
+/// style of `like`
+fn shape_ellipsis<T: ParsedFontTrait>(
+    like: &ShapedCluster,
+    fonts: &LoadedFonts<T>,
+) -> Option<Vec<ShapedCluster>> {
+    fn shape_with<F: ParsedFontTrait>(
+        font: &F,
+        style: &Arc<StyleProperties>,
+    ) -> Option<Vec<ShapedCluster>> {
+        let text = if font.has_glyph('\u{2026}' as u32) {
+            "\u{2026}"
+        } else {
+            "..."
+        };
+        // Generated content, same as the hyphen inserted by the line breaker
+        let source = ContentIndex {
+            run_index: u32::MAX,
+            item_index: u32::MAX,
+        };
+        shape_text_correctly(
+            text,
+            Script::Latin,
+            script_to_language(Script::Latin, text),
+            BidiDirection::Ltr,
+            font,
+            style,
+            source,
+            None,
+        )
+        .ok()
+    }
+
+    match &like.style.font_stack {
+        FontStack::Ref(font) => shape_with(font, &like.style),
+        FontStack::Stack(_) => {
+            let font = fonts.get_by_hash(like.glyphs.first()?.font_hash)?;
+            shape_with(font, &like.style)
+        }
+    }
+}
+
 /// Breaks a single line of items to fit within the given geometric constraints,
 /// handling multi-segment lines and hyphenation.
 /// Break a single line from the current cursor position.
@@ -6732,14 +7291,20 @@ pub fn position_one_line<T: ParsedFontTrait>(
             break;
         }
 
-        // 1. Collect all items that fit into the current segment.
+        // 1. Collect all items that fit into the current segment. The last segment
+        // takes whatever is left, so unwrappable lines (white-space: pre / nowrap)
+        // overflow the line box instead of losing items.
+        let is_last_segment = segment_idx + 1 == line_constraints.segments.len();
         let mut segment_items = Vec::new();
         let mut current_segment_width = 0.0;
         while item_cursor < line_items.len() {
             let item = &line_items[item_cursor];
             let item_measure = get_item_measure(item, is_vertical);
             // Put at least one item in the segment to avoid getting stuck.
-            if current_segment_width + item_measure > segment.width && !segment_items.is_empty() {
+            if current_segment_width + item_measure > segment.width
+                && !segment_items.is_empty()
+                && !is_last_segment
+            {
                 break;
             }
             segment_items.push(item.clone());
@@ -6772,7 +7337,7 @@ pub fn position_one_line<T: ParsedFontTrait>(
         };
 
         // Kashida justification needs to be segment-aware if used.
-        let justified_segment_items = if constraints.text_justify == JustifyContent::Kashida
+        let mut justified_segment_items = if constraints.text_justify == JustifyContent::Kashida
             && (!is_last_line || constraints.text_align == TextAlign::JustifyAll)
         {
             let segment_line_constraints = LineConstraints {
@@ -6790,6 +7355,12 @@ pub fn position_one_line<T: ParsedFontTrait>(
             segment_items
         };
 
+        // Shaping and line breaking work in logical order, the pen below
+        // walks in visual order.
+        if !is_vertical {
+            reverse_rtl_runs(&mut justified_segment_items);
+        }
+
         // Recalculate width in case kashida changed the item list
         let final_segment_width: f32 = justified_segment_items
             .iter()
@@ -6955,22 +7526,9 @@ pub fn position_one_line<T: ParsedFontTrait>(
             if !is_outside_marker && extra_char_spacing > 0.0 && can_justify_after(&item) {
                 main_axis_pen += extra_char_spacing;
             }
-            if let ShapedItem::Cluster(c) = &item {
-                if !is_outside_marker {
-                    let letter_spacing_px = match c.style.letter_spacing {
-                        Spacing::Px(px) => px as f32,
-                        Spacing::Em(em) => em * c.style.font_size_px,
-                    };
-                    main_axis_pen += letter_spacing_px;
-                    if is_word_separator(&item) {
-                        let word_spacing_px = match c.style.word_spacing {
-                            Spacing::Px(px) => px as f32,
-                            Spacing::Em(em) => em * c.style.font_size_px,
-                        };
-                        main_axis_pen += word_spacing_px;
-                        main_axis_pen += extra_word_spacing;
-                    }
-                }
+            // letter-spacing / word-spacing are already part of item_measure
+            if !is_outside_marker && is_word_separator(&item) {
+                main_axis_pen += extra_word_spacing;
             }
         }
     }
@@ -6978,6 +7536,30 @@ pub fn position_one_line<T: ParsedFontTrait>(
     (positioned, line_box_height)
 }
 
+/// Reverses every run of consecutive right-to-left clusters in place, turning
+/// the logical order of a line into its visual order (UAX #9, rule L2).
+///
+/// Runs are reversed one by one, the order of the runs themselves is left to
+/// the paragraph-level reordering in `reorder_logical_items`.
+fn reverse_rtl_runs(items: &mut [ShapedItem]) {
+    let is_rtl = |item: &ShapedItem| {
+        matches!(item, ShapedItem::Cluster(c) if c.direction == BidiDirection::Rtl)
+    };
+    let mut start = 0;
+    while start < items.len() {
+        if !is_rtl(&items[start]) {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 1;
+        while end < items.len() && is_rtl(&items[end]) {
+            end += 1;
+        }
+        items[start..end].reverse();
+        start = end;
+    }
+}
+
 /// Calculates the starting pen offset to achieve the desired text alignment.
 fn calculate_alignment_offset(
     items: &[ShapedItem],
@@ -7352,7 +7934,7 @@ pub fn get_item_measure(item: &ShapedItem, is_vertical: bool) -> f32 {
             // Kerning is stored separately in glyphs for inspection, but the total
             // cluster width must include it for correct layout positioning
             let total_kerning: f32 = c.glyphs.iter().map(|g| g.kerning).sum();
-            c.advance + total_kerning
+            c.advance + total_kerning + get_cluster_spacing(item, c)
         }
         ShapedItem::Object { bounds, .. }
         | ShapedItem::CombinedBlock { bounds, .. }
@@ -7367,6 +7949,22 @@ pub fn get_item_measure(item: &ShapedItem, is_vertical: bool) -> f32 {
     }
 }
 
+/// Extra advance added after a cluster by `letter-spacing` and, for word
+/// separators, `word-spacing` (CSS Text Module Level 3 § 8).
+///
+/// Part of the cluster measure, so line breaking sees the spaced width.
+fn get_cluster_spacing(item: &ShapedItem, cluster: &ShapedCluster) -> f32 {
+    let to_px = |spacing: Spacing| match spacing {
+        Spacing::Px(px) => px as f32,
+        Spacing::Em(em) => em * cluster.style.font_size_px,
+    };
+    let mut spacing = to_px(cluster.style.letter_spacing);
+    if is_word_separator(item) {
+        spacing += to_px(cluster.style.word_spacing);
+    }
+    spacing
+}
+
 /// Helper to get the final positioned bounds of an item.
 fn get_item_bounds(item: &PositionedItem) -> Rect {
     let measure = get_item_measure(&item.item, false); // for simplicity, use horizontal
@@ -7413,8 +8011,8 @@ fn get_line_constraints(
             AvailableSpace::MaxContent => f32::MAX / 2.0, // For intrinsic max-content sizing
             AvailableSpace::MinContent => f32::MAX / 2.0, // For intrinsic min-content sizing
         };
-        // Note: TextWrap::NoWrap is handled by the LineBreaker in break_one_line()
-        // to prevent soft wraps. The text will simply overflow if it exceeds segment_width.
+        // Note: TextWrap::NoWrap is handled by breaking lines against an unbounded
+        // width in the line loop, to prevent soft wraps. The text will simply overflow if it exceeds segment_width.
         available_segments.push(LineSegment {
             start_x: 0.0,
             width: segment_width,
@@ -20,7 +20,8 @@ use azul_core::{
     animation::UpdateImageType,
     callbacks::{FocusTarget, HidpiAdjustedBounds, VirtualViewCallbackReason, Update},
     dom::{
-        AccessibilityAction, AttributeType, Dom, DomId, DomIdVec, DomNodeId, NodeId, NodeType, On,
+        AccessibilityAction, AttributeType, Dom, DomId, DomIdVec, DomNodeHash, DomNodeId, NodeData,
+        NodeId, NodeType, On,
     },
     events::{EasingFunction, EventFilter, FocusEventFilter, HoverEventFilter},
     geom::{LogicalPosition, LogicalRect, LogicalSize, OptionLogicalPosition},
@@ -51,7 +52,8 @@ use azul_css::{
     css::Css,
     props::{
         basic::FontRef,
-        property::{CssProperty, CssPropertyVec},
+        layout::LayoutDisplay,
+        property::{CssProperty, CssPropertyType, CssPropertyVec},
     },
     AzString, LayoutDebugMessage, OptionString,
 };
@@ -64,6 +66,7 @@ use crate::{
         Callback, ExternalSystemCallbacks, MenuCallback,
     },
     managers::{
+        a11y::AccessibilityNode,
         gpu_state::GpuStateManager,
         virtual_view::VirtualViewManager,
         scroll_state::{ScrollManager, ScrollStates},
@@ -234,6 +237,591 @@ pub struct DomLayoutResult {
     /// Mapping from scroll IDs to DOM NodeIds for hit testing
     /// This allows us to map WebRender scroll IDs back to DOM nodes
     pub scroll_id_to_node_id: HashMap<u64, NodeId>,
+    /// Incremented on every relayout or restyle of this DOM, see `generation()`
+    generation: u64,
+}
+
+impl DomLayoutResult {
+    /// Monotonic counter that changes whenever this layout result is modified.
+    ///
+    /// Caches (hit testing, dirty rects, ...) can store the generation they
+    /// were computed against and compare it instead of diffing the layout.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Bumps the generation. Call after mutating the styled DOM, layout tree
+    /// or display list in place.
+    pub fn mark_changed(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Position and used size of the first layout box generated by `node_id`
+    fn node_rect(&self, node_id: NodeId) -> Option<LogicalRect> {
+        let layout_index = *self.layout_tree.dom_to_layout.get(&node_id)?.first()?;
+        let size = self.layout_tree.get(layout_index)?.used_size?;
+        let origin = crate::solver3::pos_get(&self.calculated_positions, layout_index)?;
+        Some(LogicalRect::new(origin, size))
+    }
+
+    /// Returns all nodes reachable via keyboard (Tab) navigation together with
+    /// their tab index, in tab order: positive indices ascending first, then
+    /// all `tabindex = 0` / auto nodes, ties broken by document order.
+    ///
+    /// Nodes without a tab index, with `NoKeyboardFocus` (-1), hidden via
+    /// `display: none` or marked as unavailable (disabled) are excluded.
+    pub fn focusable_nodes(&self) -> Vec<(NodeId, isize)> {
+        use azul_core::a11y::AccessibilityState;
+
+        let node_data = self.styled_dom.node_data.as_container();
+
+        let mut nodes: Vec<(NodeId, isize)> = (0..node_data.len())
+            .map(NodeId::new)
+            .filter_map(|node_id| {
+                let data = &node_data[node_id];
+                let tab_index = data.get_effective_tabindex()? as isize;
+                if tab_index < 0
+                    || self.layout_tree.dom_to_layout.get(&node_id).is_none()
+                    || self.is_display_none(node_id)
+                {
+                    return None;
+                }
+                let disabled = data.get_accessibility_info().map_or(false, |a11y| {
+                    a11y.states
+                        .as_ref()
+                        .iter()
+                        .any(|s| matches!(s, AccessibilityState::Unavailable))
+                });
+                (!disabled).then_some((node_id, tab_index))
+            })
+            .collect();
+
+        // sort_by_key is stable, so equal tab indices keep document order
+        nodes.sort_by_key(|(_, tab_index)| if *tab_index == 0 { isize::MAX } else { *tab_index });
+        nodes
+    }
+
+    /// Renders the laid-out tree as an indented box-drawing string, one node per
+    /// line with its absolute position and used size, e.g.:
+    ///
+    /// ```text
+    /// div [x:0 y:0 w:100 h:50]
+    /// ├─ div [x:0 y:0 w:100 h:20]
+    /// │ └─ text [x:0 y:0 w:30 h:16]
+    /// └─ div [x:0 y:20 w:100 h:30]
+    /// ```
+    ///
+    /// Anonymous boxes are printed as `anonymous`. The output only depends on
+    /// the layout, so it can be used for snapshot tests.
+    pub fn print_tree(&self) -> String {
+        fn print_node(
+            result: &DomLayoutResult,
+            index: usize,
+            prefix: &str,
+            connector: &str,
+            child_prefix: &str,
+            out: &mut String,
+        ) {
+            let Some(node) = result.layout_tree.get(index) else {
+                return;
+            };
+            let node_data = result.styled_dom.node_data.as_container();
+            let name = match node.dom_node_id {
+                Some(id) => node_data[id].node_type.get_path().to_string(),
+                None => "anonymous".to_string(),
+            };
+            let pos = crate::solver3::pos_get(&result.calculated_positions, index)
+                .unwrap_or_default();
+            let size = node.used_size.unwrap_or_default();
+            out.push_str(&format!(
+                "{prefix}{connector}{name} [x:{} y:{} w:{} h:{}]\n",
+                pos.x, pos.y, size.width, size.height
+            ));
+
+            let children = result.layout_tree.children(index);
+            for (i, child) in children.iter().enumerate() {
+                let is_last = i + 1 == children.len();
+                let (connector, extension) = if is_last {
+                    ("└─ ", "  ")
+                } else {
+                    ("├─ ", "│ ")
+                };
+                print_node(
+                    result,
+                    *child,
+                    child_prefix,
+                    connector,
+                    &format!("{child_prefix}{extension}"),
+                    out,
+                );
+            }
+        }
+
+        let mut out = String::new();
+        print_node(self, self.layout_tree.root, "", "", "", &mut out);
+        out
+    }
+
+    /// Derives a screen-reader tree from the laid-out DOM.
+    ///
+    /// The hierarchy mirrors the DOM, but purely decorative nodes (generic
+    /// containers without accessibility info, text or focus) are skipped and
+    /// their children attached to the nearest accessible ancestor. Nodes that
+    /// were not laid out (e.g. `display: none`) are omitted with their subtree.
+    ///
+    /// Buttons and links without an explicit accessible name take their label
+    /// from their text content; their text children are not repeated.
+    pub fn accessibility_tree(&self) -> AccessibilityNode {
+        use azul_core::a11y::AccessibilityRole;
+
+        use crate::managers::a11y::node_type_to_accessibility_role;
+
+        fn bounds_of(result: &DomLayoutResult, node_id: NodeId) -> Option<LogicalRect> {
+            let index = *result.layout_tree.dom_to_layout.get(&node_id)?.first()?;
+            let size = result.layout_tree.get(index)?.used_size.unwrap_or_default();
+            let origin = crate::solver3::pos_get(&result.calculated_positions, index)
+                .unwrap_or_default();
+            Some(LogicalRect { origin, size })
+        }
+
+        fn collect_text(result: &DomLayoutResult, node_id: NodeId, out: &mut String) {
+            let hierarchy = result.styled_dom.node_hierarchy.as_container();
+            let node_data = result.styled_dom.node_data.as_container();
+            if let NodeType::Text(t) = node_data[node_id].get_node_type() {
+                out.push_str(t.as_str());
+            }
+            for child in node_id.az_children(&hierarchy) {
+                collect_text(result, child, out);
+            }
+        }
+
+        // Pushes the accessible node for `node_id` onto `out`, or, if the node
+        // is decorative, its accessible descendants.
+        fn build(
+            result: &DomLayoutResult,
+            node_id: NodeId,
+            is_root: bool,
+            out: &mut Vec<AccessibilityNode>,
+        ) {
+            let Some(bounds) = bounds_of(result, node_id) else {
+                return;
+            };
+            let node_data = result.styled_dom.node_data.as_container();
+            let data = &node_data[node_id];
+            let a11y_info = data.get_accessibility_info();
+            let role = a11y_info.map_or_else(
+                || node_type_to_accessibility_role(data.get_node_type()),
+                |info| info.role,
+            );
+
+            let explicit_label = a11y_info
+                .and_then(|info| info.accessibility_name.as_option())
+                .map(|name| name.as_str().to_string());
+            let name_from_content = explicit_label.is_none()
+                && matches!(
+                    role,
+                    AccessibilityRole::PushButton
+                        | AccessibilityRole::Link
+                        | AccessibilityRole::StaticText
+                );
+            let label = explicit_label
+                .or_else(|| {
+                    name_from_content.then(|| {
+                        let mut text = String::new();
+                        collect_text(result, node_id, &mut text);
+                        text.trim().to_string()
+                    })
+                })
+                .filter(|label| !label.is_empty());
+
+            // Whitespace-only text carries no information
+            if matches!(data.get_node_type(), NodeType::Text(_)) && label.is_none() {
+                return;
+            }
+
+            let mut children = Vec::new();
+            if !name_from_content || label.is_none() {
+                let hierarchy = result.styled_dom.node_hierarchy.as_container();
+                for child in node_id.az_children(&hierarchy) {
+                    build(result, child, false, &mut children);
+                }
+            }
+
+            let is_focusable = data.is_focusable();
+            let is_decorative = !is_root
+                && a11y_info.is_none()
+                && role == AccessibilityRole::Nothing
+                && !is_focusable;
+            if is_decorative {
+                out.extend(children);
+            } else {
+                out.push(AccessibilityNode {
+                    node_id,
+                    role,
+                    label,
+                    bounds,
+                    is_focusable,
+                    children,
+                });
+            }
+        }
+
+        let root_id = NodeId::new(0);
+        let mut out = Vec::new();
+        build(self, root_id, true, &mut out);
+        out.pop().unwrap_or(AccessibilityNode {
+            node_id: root_id,
+            role: AccessibilityRole::Nothing,
+            label: None,
+            bounds: LogicalRect::zero(),
+            is_focusable: false,
+            children: Vec::new(),
+        })
+    }
+
+    /// Returns the absolute y-position of the first baseline of the node's box.
+    ///
+    /// For text, this is the baseline of the first line the text appears on.
+    /// Containers take the first baseline of their first in-flow child that
+    /// has one (recursively). Returns `None` if the box contains no line boxes.
+    pub fn first_baseline(&self, node_id: NodeId) -> Option<f32> {
+        let index = *self.layout_tree.dom_to_layout.get(&node_id)?.first()?;
+        self.baseline_of(index, false)
+    }
+
+    /// Returns the absolute y-position of the last baseline of the node's box,
+    /// see [`first_baseline`](Self::first_baseline).
+    pub fn last_baseline(&self, node_id: NodeId) -> Option<f32> {
+        let index = *self.layout_tree.dom_to_layout.get(&node_id)?.first()?;
+        self.baseline_of(index, true)
+    }
+
+    fn baseline_of(&self, index: usize, last: bool) -> Option<f32> {
+        use azul_css::props::layout::LayoutPosition;
+
+        use crate::solver3::{pos_get, positioning::get_position_type};
+
+        let node = self.layout_tree.get(index)?;
+
+        // Line baselines are relative to the content box of the IFC root
+        let content_top = |root: usize| -> Option<f32> {
+            let root_node = self.layout_tree.get(root)?;
+            let pos = pos_get(&self.calculated_positions, root)?;
+            Some(pos.y + root_node.box_props.border.top + root_node.box_props.padding.top)
+        };
+
+        if let Some(inline) = &node.inline_layout_result {
+            let layout = inline.get_layout();
+            let baseline = if last {
+                layout.last_line_baseline()
+            } else {
+                layout.first_line_baseline()
+            };
+            return Some(content_top(index)? + baseline?);
+        }
+
+        if let (Some(membership), Some(dom_id)) = (&node.ifc_membership, node.dom_node_id) {
+            let layout = self.layout_tree.get_inline_layout_for_node(index)?;
+            let (first, last_line) = layout.line_baselines_for_node(dom_id)?;
+            let baseline = if last { last_line } else { first };
+            return Some(content_top(membership.ifc_root_layout_index)? + baseline);
+        }
+
+        let in_flow = |child: &&usize| {
+            let dom_id = self.layout_tree.get(**child).and_then(|c| c.dom_node_id);
+            !matches!(
+                get_position_type(&self.styled_dom, dom_id),
+                LayoutPosition::Absolute | LayoutPosition::Fixed
+            )
+        };
+        let children = self.layout_tree.children(index);
+        if last {
+            children
+                .iter()
+                .rev()
+                .filter(in_flow)
+                .find_map(|child| self.baseline_of(*child, last))
+        } else {
+            children
+                .iter()
+                .filter(in_flow)
+                .find_map(|child| self.baseline_of(*child, last))
+        }
+    }
+
+    /// Returns true if the node or one of its ancestors has `display: none`,
+    /// i.e. the node is not rendered at all.
+    pub fn is_display_none(&self, node_id: NodeId) -> bool {
+        let hierarchy = self.styled_dom.node_hierarchy.as_container();
+
+        let mut current = Some(node_id);
+        while let Some(id) = current {
+            let display = crate::solver3::getters::get_display_property(&self.styled_dom, Some(id));
+            if display.exact() == Some(LayoutDisplay::None) {
+                return true;
+            }
+            current = hierarchy[id].parent_id();
+        }
+        false
+    }
+
+    /// Returns the cumulative opacity of the node, i.e. the product of its own
+    /// `opacity` and that of all its ancestors. `0.0` means the node is fully
+    /// transparent and its subtree can be culled.
+    pub fn effective_opacity(&self, node_id: NodeId) -> f32 {
+        let hierarchy = self.styled_dom.node_hierarchy.as_container();
+        let styled_nodes = self.styled_dom.styled_nodes.as_container();
+
+        let mut opacity = 1.0;
+        let mut current = Some(node_id);
+        while let Some(id) = current {
+            opacity *= crate::solver3::getters::get_opacity(
+                &self.styled_dom,
+                id,
+                &styled_nodes[id].styled_node_state,
+            );
+            if opacity <= 0.0 {
+                return 0.0;
+            }
+            current = hierarchy[id].parent_id();
+        }
+        opacity
+    }
+
+    /// Returns the resolved layout-affecting CSS properties of a node, e.g. for
+    /// a devtools "computed" panel.
+    ///
+    /// Sizes, margins, paddings and border widths are the used pixel values of
+    /// the solved layout, so `width: 50%` or `auto` is reported in `px`.
+    /// `display` and `position` are always present; the remaining keyword and
+    /// flex properties only if the cascade sets them. Returns an empty map if
+    /// the node did not generate a box.
+    pub fn computed_style(&self, node_id: NodeId) -> BTreeMap<CssPropertyType, CssProperty> {
+        use azul_css::props::{
+            layout::{
+                LayoutBoxSizing, LayoutHeight, LayoutMarginBottom, LayoutMarginLeft,
+                LayoutMarginRight, LayoutMarginTop, LayoutPaddingBottom, LayoutPaddingLeft,
+                LayoutPaddingRight, LayoutPaddingTop, LayoutWidth,
+            },
+            style::border::{
+                LayoutBorderBottomWidth, LayoutBorderLeftWidth, LayoutBorderRightWidth,
+                LayoutBorderTopWidth,
+            },
+        };
+
+        use crate::solver3::{
+            getters::get_css_box_sizing, layout_tree::get_display_type,
+            positioning::get_position_type,
+        };
+
+        let mut computed = BTreeMap::new();
+        let Some(node) = self
+            .layout_tree
+            .dom_to_layout
+            .get(&node_id)
+            .and_then(|indices| indices.first())
+            .and_then(|index| self.layout_tree.get(*index))
+        else {
+            return computed;
+        };
+
+        let node_data = &self.styled_dom.node_data.as_container()[node_id];
+        let node_state = &self.styled_dom.styled_nodes.as_container()[node_id].styled_node_state;
+        let cache = self.styled_dom.get_css_property_cache();
+
+        for property_type in [
+            CssPropertyType::BoxSizing,
+            CssPropertyType::Top,
+            CssPropertyType::Right,
+            CssPropertyType::Bottom,
+            CssPropertyType::Left,
+            CssPropertyType::FlexDirection,
+            CssPropertyType::FlexWrap,
+            CssPropertyType::FlexGrow,
+            CssPropertyType::FlexShrink,
+            CssPropertyType::FlexBasis,
+        ] {
+            if let Some(property) =
+                cache.get_property(node_data, &node_id, node_state, &property_type)
+            {
+                computed.insert(property_type, property.clone());
+            }
+        }
+        computed.insert(
+            CssPropertyType::Display,
+            get_display_type(&self.styled_dom, node_id).into(),
+        );
+        computed.insert(
+            CssPropertyType::Position,
+            get_position_type(&self.styled_dom, Some(node_id)).into(),
+        );
+
+        let props = &node.box_props;
+        let mut size = node.used_size.unwrap_or_default();
+        let border_box = get_css_box_sizing(&self.styled_dom, node_id, node_state)
+            .unwrap_or_default()
+            == LayoutBoxSizing::BorderBox;
+        if !border_box {
+            size.width -= props.padding.left
+                + props.padding.right
+                + props.border.left
+                + props.border.right;
+            size.height -= props.padding.top
+                + props.padding.bottom
+                + props.border.top
+                + props.border.bottom;
+        }
+
+        let resolved: [CssProperty; 14] = [
+            LayoutWidth::px(size.width.max(0.0)).into(),
+            LayoutHeight::px(size.height.max(0.0)).into(),
+            LayoutMarginTop::px(props.margin.top).into(),
+            LayoutMarginRight::px(props.margin.right).into(),
+            LayoutMarginBottom::px(props.margin.bottom).into(),
+            LayoutMarginLeft::px(props.margin.left).into(),
+            LayoutPaddingTop::px(props.padding.top).into(),
+            LayoutPaddingRight::px(props.padding.right).into(),
+            LayoutPaddingBottom::px(props.padding.bottom).into(),
+            LayoutPaddingLeft::px(props.padding.left).into(),
+            LayoutBorderTopWidth::px(props.border.top).into(),
+            LayoutBorderRightWidth::px(props.border.right).into(),
+            LayoutBorderBottomWidth::px(props.border.bottom).into(),
+            LayoutBorderLeftWidth::px(props.border.left).into(),
+        ];
+        for property in resolved {
+            computed.insert(property.get_type(), property);
+        }
+
+        computed
+    }
+
+    /// Compares the scroll frames of this layout against the previous layout
+    /// of the same DOM, so that compositor scroll frames can be registered and
+    /// dropped incrementally instead of rebuilding all of them.
+    ///
+    /// Frames are matched by their stable scroll ID (see
+    /// `LayoutWindow::compute_scroll_ids`); a frame counts as changed if its
+    /// container rect or content size differs.
+    pub fn diff_scroll_nodes(&self, prev: &DomLayoutResult) -> ScrollNodeDiff {
+        let current = self.scroll_frames();
+        let previous = prev.scroll_frames();
+
+        let mut diff = ScrollNodeDiff::default();
+        for (scroll_id, geometry) in &current {
+            match previous.get(scroll_id) {
+                None => {
+                    diff.added.insert(*scroll_id);
+                }
+                Some(prev_geometry) if prev_geometry != geometry => {
+                    diff.changed.insert(*scroll_id);
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed = previous
+            .keys()
+            .filter(|scroll_id| !current.contains_key(scroll_id))
+            .copied()
+            .collect();
+        diff
+    }
+
+    /// Scroll ID -> (container rect, content size) of every scroll frame
+    fn scroll_frames(&self) -> BTreeMap<u64, (LogicalRect, LogicalSize)> {
+        self.scroll_ids
+            .iter()
+            .filter_map(|(&layout_idx, &scroll_id)| {
+                let node = self.layout_tree.get(layout_idx)?;
+                let origin = crate::solver3::pos_get(&self.calculated_positions, layout_idx)
+                    .unwrap_or_default();
+                let rect = LogicalRect::new(origin, node.used_size.unwrap_or_default());
+                Some((scroll_id, (rect, node.get_content_size())))
+            })
+            .collect()
+    }
+
+    /// Compares the VirtualView hosts of this layout against the previous
+    /// layout of the same DOM and returns the hosts whose size changed, i.e.
+    /// the hosts whose nested DOM (see `VirtualViewManager::get_nested_dom_id`)
+    /// has to be laid out again.
+    ///
+    /// Hosts are matched by NodeId. A host that did not exist in the previous
+    /// layout is not listed, it gets an `InitialRender` invocation instead.
+    pub fn get_virtual_views_to_relayout(&self, prev: &DomLayoutResult) -> Vec<NodeId> {
+        self.resized_nodes(prev, NodeData::is_virtual_view_node)
+    }
+
+    /// Compares the GL texture nodes (images backed by a `RenderImageCallback`)
+    /// of this layout against the previous layout of the same DOM and returns
+    /// the nodes whose size changed, i.e. whose callback has to be re-invoked
+    /// to render a texture of the new size.
+    ///
+    /// Nodes are matched by NodeId, same as `get_virtual_views_to_relayout`.
+    pub fn get_gl_textures_to_redraw(&self, prev: &DomLayoutResult) -> Vec<NodeId> {
+        self.resized_nodes(prev, |node_data| match node_data.get_node_type() {
+            NodeType::Image(image_ref) => image_ref.is_callback(),
+            _ => false,
+        })
+    }
+
+    /// Nodes matching `filter` that exist in both layouts with a different used size
+    fn resized_nodes(
+        &self,
+        prev: &DomLayoutResult,
+        filter: impl Fn(&NodeData) -> bool,
+    ) -> Vec<NodeId> {
+        let previous = prev.node_sizes(&filter);
+        self.node_sizes(&filter)
+            .into_iter()
+            .filter(|(node_id, size)| previous.get(node_id).map_or(false, |prev| prev != size))
+            .map(|(node_id, _)| node_id)
+            .collect()
+    }
+
+    /// NodeId -> used size of every laid-out node matching `filter`
+    fn node_sizes(&self, filter: &impl Fn(&NodeData) -> bool) -> BTreeMap<NodeId, LogicalSize> {
+        let node_data = self.styled_dom.node_data.as_container();
+        self.layout_tree
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let node_id = node.dom_node_id?;
+                if !filter(node_data.get(node_id)?) {
+                    return None;
+                }
+                Some((node_id, node.used_size.unwrap_or_default()))
+            })
+            .collect()
+    }
+}
+
+/// Result of `DomLayoutResult::diff_scroll_nodes`, all sets contain scroll IDs
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScrollNodeDiff {
+    /// Scroll frames that did not exist in the previous layout
+    pub added: BTreeSet<u64>,
+    /// Scroll frames of the previous layout that no longer exist
+    pub removed: BTreeSet<u64>,
+    /// Scroll frames whose container rect or content size changed
+    pub changed: BTreeSet<u64>,
+}
+
+/// Metadata of a single scrollable node, returned by
+/// `LayoutWindow::get_scrollable_nodes`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollableNodeInfo {
+    /// The scroll container
+    pub node_id: DomNodeId,
+    /// Stable scroll ID, see `LayoutWindow::compute_scroll_ids`
+    pub scroll_id: u64,
+    /// Hash of the node data, stable across DOM regenerations
+    pub node_hash: DomNodeHash,
+    /// Layout rect of the scroll container
+    pub container_rect: LogicalRect,
+    /// Size of the (overflowing) content
+    pub content_size: LogicalSize,
+    /// Current scroll offset, zero if the node was never scrolled
+    pub current_offset: LogicalPosition,
 }
 
 /// State for tracking scrollbar drag interaction
@@ -299,6 +887,9 @@ pub struct LayoutWindow {
     pub image_cache: ImageCache,
     /// Cached layout results for all DOMs (root + virtualized views)
     pub layout_results: BTreeMap<DomId, DomLayoutResult>,
+    /// Results of the previous full relayout while a new one is running, so
+    /// the generation and lifecycle events can be diffed against them
+    previous_layout_results: BTreeMap<DomId, DomLayoutResult>,
     /// Scroll state manager for all nodes across all DOMs
     pub scroll_manager: ScrollManager,
     /// Gesture and drag manager for multi-frame interactions (moved from FullWindowState)
@@ -350,6 +941,8 @@ pub struct LayoutWindow {
     currently_dragging_thumb: Option<ScrollbarDragState>,
     /// Text input manager - centralizes all text editing logic
     pub text_input_manager: crate::managers::text_input::TextInputManager,
+    /// Mount / unmount / resize events of component callbacks from the last relayout
+    pub lifecycle_manager: crate::managers::lifecycle::LifecycleManager,
     /// Undo/Redo manager for text editing operations
     pub undo_redo_manager: crate::managers::undo_redo::UndoRedoManager,
     /// Cached text layout constraints for each node
@@ -433,6 +1026,7 @@ impl LayoutWindow {
             font_manager: FontManager::new(fc_cache)?,
             image_cache: ImageCache::default(),
             layout_results: BTreeMap::new(),
+            previous_layout_results: BTreeMap::new(),
             scroll_manager: ScrollManager::new(),
             gesture_drag_manager: crate::managers::gesture::GestureAndDragManager::new(),
             focus_manager: crate::managers::focus_cursor::FocusManager::new(),
@@ -460,6 +1054,7 @@ impl LayoutWindow {
             gl_texture_cache: GlTextureCache::default(),
             currently_dragging_thumb: None,
             text_input_manager: crate::managers::text_input::TextInputManager::new(),
+            lifecycle_manager: crate::managers::lifecycle::LifecycleManager::new(),
             undo_redo_manager: crate::managers::undo_redo::UndoRedoManager::new(),
             text_constraints_cache: TextConstraintsCache {
                 constraints: BTreeMap::new(),
@@ -507,6 +1102,7 @@ impl LayoutWindow {
             font_manager: FontManager::new(fc_cache)?,
             image_cache: ImageCache::default(),
             layout_results: BTreeMap::new(),
+            previous_layout_results: BTreeMap::new(),
             scroll_manager: ScrollManager::new(),
             gesture_drag_manager: crate::managers::gesture::GestureAndDragManager::new(),
             focus_manager: crate::managers::focus_cursor::FocusManager::new(),
@@ -534,6 +1130,7 @@ impl LayoutWindow {
             gl_texture_cache: GlTextureCache::default(),
             currently_dragging_thumb: None,
             text_input_manager: crate::managers::text_input::TextInputManager::new(),
+            lifecycle_manager: crate::managers::lifecycle::LifecycleManager::new(),
             undo_redo_manager: crate::managers::undo_redo::UndoRedoManager::new(),
             text_constraints_cache: TextConstraintsCache {
                 constraints: BTreeMap::new(),
@@ -573,8 +1170,9 @@ impl LayoutWindow {
         system_callbacks: &ExternalSystemCallbacks,
         debug_messages: &mut Option<Vec<LayoutDebugMessage>>,
     ) -> Result<(), solver3::LayoutError> {
-        // Clear previous results for a full relayout
-        self.layout_results.clear();
+        // Clear previous results for a full relayout, keeping them aside until
+        // each DOM has been diffed against its new layout
+        self.previous_layout_results = core::mem::take(&mut self.layout_results);
 
         // CRITICAL: Reset VirtualView invocation flags so check_reinvoke() returns
         // InitialRender for every tracked VirtualView. Without this, the VirtualViewManager
@@ -597,6 +1195,7 @@ impl LayoutWindow {
             system_callbacks,
             debug_messages,
         );
+        self.previous_layout_results.clear();
 
         if let Err(ref e) = result {
             if let Some(msgs) = debug_messages.as_mut() {
@@ -639,6 +1238,13 @@ impl LayoutWindow {
             }
         }
 
+        // Keep the visible content of scrolled containers in place, then remember
+        // the new child positions for the next relayout
+        if result.is_ok() {
+            self.apply_scroll_anchoring();
+            self.record_scroll_anchor_candidates();
+        }
+
         // After layout, automatically scroll cursor into view if there's a focused text input
         if result.is_ok() {
             self.scroll_focused_cursor_into_view();
@@ -647,6 +1253,66 @@ impl LayoutWindow {
         result
     }
 
+    /// Shifts scroll offsets by how far each container's anchor node moved
+    /// during the relayout (see `ScrollManager::apply_scroll_anchoring`)
+    fn apply_scroll_anchoring(&mut self) {
+        for (dom_id, layout_result) in &self.layout_results {
+            let top_of = |node_id: NodeId| {
+                let index = *layout_result.layout_tree.dom_to_layout.get(&node_id)?.first()?;
+                solver3::pos_get(&layout_result.calculated_positions, index).map(|p| p.y)
+            };
+            self.scroll_manager
+                .apply_scroll_anchoring(*dom_id, |container, anchor| {
+                    Some(top_of(anchor)? - top_of(container)?)
+                });
+        }
+    }
+
+    /// Records the children of every overflowing scroll container with their
+    /// position relative to the container, as scroll anchor candidates
+    fn record_scroll_anchor_candidates(&mut self) {
+        use crate::managers::scroll_state::ScrollAnchorCandidate;
+
+        self.scroll_manager.clear_anchor_candidates();
+        for (dom_id, layout_result) in &self.layout_results {
+            let tree = &layout_result.layout_tree;
+            let hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+            for (index, node) in tree.nodes.iter().enumerate() {
+                let Some(container) = node.dom_node_id else {
+                    continue;
+                };
+                let overflowing = node
+                    .scrollbar_info
+                    .as_ref()
+                    .map_or(false, |s| s.needs_vertical || s.needs_horizontal);
+                if !overflowing {
+                    continue;
+                }
+                let Some(origin) = solver3::pos_get(&layout_result.calculated_positions, index)
+                else {
+                    continue;
+                };
+                let candidates = container
+                    .az_children(&hierarchy)
+                    .filter_map(|child| {
+                        let child_index = *tree.dom_to_layout.get(&child)?.first()?;
+                        let pos =
+                            solver3::pos_get(&layout_result.calculated_positions, child_index)?;
+                        let height = tree.get(child_index)?.used_size?.height;
+                        let top = pos.y - origin.y;
+                        Some(ScrollAnchorCandidate {
+                            node_id: child,
+                            top,
+                            bottom: top + height,
+                        })
+                    })
+                    .collect();
+                self.scroll_manager
+                    .set_anchor_candidates(*dom_id, container, candidates);
+            }
+        }
+    }
+
     fn layout_dom_recursive(
         &mut self,
         mut styled_dom: StyledDom,
@@ -957,7 +1623,12 @@ impl LayoutWindow {
         }
 
         // Store the final layout result for this DOM
-        self.layout_results.insert(
+        let generation = self
+            .layout_results
+            .get(&dom_id)
+            .or_else(|| self.previous_layout_results.get(&dom_id))
+            .map_or(1, |previous| previous.generation + 1);
+        let previous_layout = self.layout_results.insert(
             dom_id,
             DomLayoutResult {
                 styled_dom: styled_dom_clone,
@@ -967,12 +1638,111 @@ impl LayoutWindow {
                 display_list,
                 scroll_ids,
                 scroll_id_to_node_id,
+                generation,
             },
         );
 
+        let previous_layout =
+            previous_layout.or_else(|| self.previous_layout_results.remove(&dom_id));
+        self.record_lifecycle_events(dom_id, previous_layout.as_ref());
+
         Ok(())
     }
 
+    /// Compares the freshly inserted layout of `dom_id` against `previous`
+    /// (`None` on the first layout of the DOM) and records the mounted,
+    /// unmounted and resized nodes that have a matching component callback.
+    fn record_lifecycle_events(&mut self, dom_id: DomId, previous: Option<&DomLayoutResult>) {
+        use azul_core::events::{ComponentEventFilter, EventType};
+
+        fn has_component_callbacks(node_data: &[NodeData]) -> bool {
+            node_data.iter().any(|nd| {
+                nd.get_callbacks()
+                    .iter()
+                    .any(|cb| matches!(cb.event, EventFilter::Component(_)))
+            })
+        }
+
+        let current = match self.layout_results.get(&dom_id) {
+            Some(c) => c,
+            None => return,
+        };
+
+        let new_node_data = current.styled_dom.node_data.as_ref();
+        let old_node_data = previous.map_or(&[][..], |p| p.styled_dom.node_data.as_ref());
+        if !has_component_callbacks(new_node_data) && !has_component_callbacks(old_node_data) {
+            return;
+        }
+
+        // Layout maps are left empty: bounds are looked up below, and resizes
+        // are detected from the node moves instead of the diff events
+        let diff = azul_core::diff::reconcile_dom(
+            old_node_data,
+            new_node_data,
+            &FastHashMap::default(),
+            &FastHashMap::default(),
+            dom_id,
+            Instant::now(),
+        );
+
+        for event in &diff.events {
+            let node_id = match event.target.node.into_crate_internal() {
+                Some(n) => n,
+                None => continue,
+            };
+            match event.event_type {
+                EventType::Mount => {
+                    let bounds = current.node_rect(node_id).unwrap_or_default();
+                    self.lifecycle_manager.record_mount(dom_id, node_id, bounds);
+                }
+                EventType::Unmount => {
+                    let bounds = previous
+                        .and_then(|p| p.node_rect(node_id))
+                        .unwrap_or_default();
+                    let callbacks = old_node_data[node_id.index()]
+                        .get_callbacks()
+                        .iter()
+                        .filter(|cb| {
+                            cb.event == EventFilter::Component(ComponentEventFilter::BeforeUnmount)
+                        })
+                        .cloned()
+                        .collect();
+                    self.lifecycle_manager
+                        .record_unmount(dom_id, node_id, bounds, callbacks);
+                }
+                _ => {}
+            }
+        }
+
+        let previous = match previous {
+            Some(p) => p,
+            None => return,
+        };
+
+        for node_move in &diff.node_moves {
+            let has_resize_callback = new_node_data[node_move.new_node_id.index()]
+                .get_callbacks()
+                .iter()
+                .any(|cb| cb.event == EventFilter::Component(ComponentEventFilter::NodeResized));
+            if !has_resize_callback {
+                continue;
+            }
+
+            let old_rect = previous.node_rect(node_move.old_node_id);
+            let new_rect = current.node_rect(node_move.new_node_id);
+            if let (Some(old_rect), Some(new_rect)) = (old_rect, new_rect) {
+                if old_rect.size != new_rect.size {
+                    self.lifecycle_manager.record_resize(
+                        dom_id,
+                        node_move.new_node_id,
+                        old_rect,
+                        new_rect,
+                    );
+                }
+            }
+        }
+    }
+
     fn scan_for_virtual_views(
         &self,
         styled_dom: &StyledDom,
@@ -1368,6 +2138,135 @@ impl LayoutWindow {
         Some(*position)
     }
 
+    /// Get the offset of a node relative to its nearest absolute / fixed
+    /// positioned ancestor (or the root, if there is none)
+    pub fn get_node_accumulated_offset(&self, node_id: DomNodeId) -> Option<LogicalPosition> {
+        let layout_result = self.layout_results.get(&node_id.dom)?;
+        let nid = node_id.node.into_crate_internal()?;
+        let layout_indices = layout_result.layout_tree.dom_to_layout.get(&nid)?;
+        let layout_index = *layout_indices.first()?;
+        Some(crate::solver3::positioning::get_accumulated_offset(
+            &layout_result.layout_tree,
+            &layout_result.calculated_positions,
+            layout_index,
+        ))
+    }
+
+    /// Get the rectangle a node covers on screen, after applying the scroll
+    /// offsets of its scrollable ancestors, the CSS transforms of the node
+    /// and its ancestors and the shift of `position: sticky` nodes.
+    ///
+    /// Transforms are applied relative to the origin of the transformed node,
+    /// same as the reference frames pushed in the display list. The result is
+    /// the axis-aligned bounding box of the transformed node.
+    pub fn get_node_screen_rect(&self, node_id: DomNodeId) -> Option<LogicalRect> {
+        let layout_result = self.layout_results.get(&node_id.dom)?;
+        let nid = node_id.node.into_crate_internal()?;
+        let layout_indices = layout_result.layout_tree.dom_to_layout.get(&nid)?;
+        let layout_index = *layout_indices.first()?;
+
+        let nodes = &layout_result.layout_tree.nodes;
+        let positions = &layout_result.calculated_positions;
+        let gpu_cache = self.gpu_state_manager.caches.get(&node_id.dom);
+
+        let mut rect = LogicalRect::new(
+            *positions.get(layout_index)?,
+            nodes.get(layout_index)?.used_size?,
+        );
+
+        // Innermost first: each ancestor's transform applies to everything
+        // inside it, including the scroll offsets of its descendants
+        let mut current = Some(layout_index);
+        while let Some(i) = current {
+            if let Some(dom_node_id) = nodes[i].dom_node_id {
+                // Scrolling moves the content of a node, not the node itself
+                if i != layout_index {
+                    if let Some(offset) =
+                        self.scroll_manager.get_current_offset(node_id.dom, dom_node_id)
+                    {
+                        rect.origin.x -= offset.x;
+                        rect.origin.y -= offset.y;
+                    }
+                }
+
+                let transform =
+                    gpu_cache.and_then(|c| c.css_current_transform_values.get(&dom_node_id));
+                if let (Some(transform), Some(origin)) = (transform, positions.get(i)) {
+                    let local = LogicalRect::new(
+                        LogicalPosition::new(rect.origin.x - origin.x, rect.origin.y - origin.y),
+                        rect.size,
+                    );
+                    let transformed = transform.transform_bounding_rect(local)?;
+                    rect = LogicalRect::new(
+                        LogicalPosition::new(
+                            transformed.origin.x + origin.x,
+                            transformed.origin.y + origin.y,
+                        ),
+                        transformed.size,
+                    );
+                }
+            }
+
+            let sticky = crate::solver3::positioning::get_sticky_offset(
+                &layout_result.styled_dom,
+                &layout_result.layout_tree,
+                positions,
+                i,
+                |nid| self.scroll_manager.get_current_offset(node_id.dom, nid),
+            );
+            rect.origin.x += sticky.x;
+            rect.origin.y += sticky.y;
+
+            current = nodes[i].parent;
+        }
+
+        Some(rect)
+    }
+
+    /// Get the nearest ancestor (or the node itself) that establishes a
+    /// stacking context, i.e. the node whose layer this node is painted into.
+    ///
+    /// The root node always establishes one, so this only returns `None` for
+    /// unknown nodes.
+    pub fn get_stacking_context_root(&self, node_id: DomNodeId) -> Option<DomNodeId> {
+        let layout_result = self.layout_results.get(&node_id.dom)?;
+        let styled_dom = &layout_result.styled_dom;
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+
+        let mut current = node_id.node.into_crate_internal()?;
+        node_hierarchy.get(current)?;
+        loop {
+            let parent = node_hierarchy.get(current).and_then(|h| h.parent_id());
+            if parent.is_none()
+                || crate::solver3::display_list::establishes_stacking_context(styled_dom, current)
+            {
+                return Some(DomNodeId {
+                    dom: node_id.dom,
+                    node: NodeHierarchyItemId::from_crate_internal(Some(current)),
+                });
+            }
+            current = parent?;
+        }
+    }
+
+    /// Get the number of laid-out lines of a text node.
+    ///
+    /// Reads the already computed inline layout of the node's IFC, so no layout
+    /// pass is needed. Returns `None` if the node is not a text node.
+    pub fn get_text_line_count(&self, node_id: DomNodeId) -> Option<usize> {
+        use azul_core::dom::NodeType;
+
+        let layout_result = self.layout_results.get(&node_id.dom)?;
+        let nid = node_id.node.into_crate_internal()?;
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        if !matches!(node_data.get(nid)?.get_node_type(), NodeType::Text(_)) {
+            return None;
+        }
+
+        let layout = self.get_inline_layout_for_node(node_id.dom, nid)?;
+        Some(layout.line_count_for_node(nid))
+    }
+
     /// Get the hit test bounds of a node from the display list
     ///
     /// This is more reliable than get_node_position + get_node_size because
@@ -2302,6 +3201,54 @@ impl LayoutWindow {
         (scroll_ids, scroll_id_to_node_id)
     }
 
+    /// Lists every scrollable node of all DOMs together with its scroll ID,
+    /// node hash, geometry and current scroll offset, sorted by DOM and node.
+    ///
+    /// Useful for scroll-position debuggers or for persisting scroll positions
+    /// across sessions (match the `node_hash` when restoring).
+    pub fn get_scrollable_nodes(&self) -> Vec<ScrollableNodeInfo> {
+        let mut result = Vec::new();
+
+        for (dom_id, layout_result) in self.layout_results.iter() {
+            let node_data = layout_result.styled_dom.node_data.as_container();
+            for (&layout_idx, &scroll_id) in layout_result.scroll_ids.iter() {
+                let Some(layout_node) = layout_result.layout_tree.nodes.get(layout_idx) else {
+                    continue;
+                };
+                let Some(nid) = layout_node.dom_node_id else {
+                    continue;
+                };
+                let Some(data) = node_data.get(nid) else {
+                    continue;
+                };
+                let origin = layout_result
+                    .calculated_positions
+                    .get(layout_idx)
+                    .copied()
+                    .unwrap_or_default();
+                let size = layout_node.used_size.unwrap_or_default();
+
+                result.push(ScrollableNodeInfo {
+                    node_id: DomNodeId {
+                        dom: *dom_id,
+                        node: NodeHierarchyItemId::from_crate_internal(Some(nid)),
+                    },
+                    scroll_id,
+                    node_hash: data.calculate_node_data_hash(),
+                    container_rect: LogicalRect::new(origin, size),
+                    content_size: layout_node.get_content_size(),
+                    current_offset: self
+                        .scroll_manager
+                        .get_current_offset(*dom_id, nid)
+                        .unwrap_or_default(),
+                });
+            }
+        }
+
+        result.sort_by_key(|info| (info.node_id.dom, info.node_id.node));
+        result
+    }
+
     /// Get the layout rectangle for a specific DOM node in logical coordinates
     ///
     /// This is useful in callbacks to get the position and size of the hit node
@@ -4681,6 +5628,7 @@ impl LayoutWindow {
         // 4. Update the layout cache with the new layout
         // Use the ifc_layout_index we found earlier (correct layout tree index)
         if let Some(layout_result) = self.layout_results.get_mut(&dom_id) {
+            layout_result.mark_changed();
             if let Some(layout_node) = layout_result.layout_tree.get_mut(ifc_layout_index) {
                 // Check if size changed (needs repaint)
                 let old_size = layout_node.used_size;
@@ -4800,6 +5748,7 @@ impl LayoutWindow {
             Ok(display_list) => {
                 if let Some(layout_result) = self.layout_results.get_mut(&dom_id) {
                     layout_result.display_list = display_list;
+                    layout_result.mark_changed();
                 }
             }
             Err(e) => {
@@ -9,9 +9,11 @@ use alloc::collections::BTreeMap;
 use azul_core::{
     callbacks::LayoutCallback,
     dom::DomId,
+    geom::{LogicalPosition, LogicalSize},
     window::{
-        DebugState, ImePosition, KeyboardState, Monitor, MouseState, PlatformSpecificOptions,
-        RendererOptions, TouchState, WindowFlags, WindowPosition, WindowSize, WindowTheme,
+        DebugState, ImePosition, KeyRepeatState, KeyboardState, Monitor, MouseState,
+        PlatformSpecificOptions, RendererOptions, TouchState, TrackpadGestureState, WindowFlags,
+        WindowPosition, WindowSize, WindowTheme,
     },
 };
 use azul_css::{
@@ -81,6 +83,10 @@ pub struct FullWindowState {
     pub layout_callback: LayoutCallback,
     pub position: WindowPosition,
     pub touch_state: TouchState,
+    /// Platform trackpad magnify / rotate gesture state
+    pub gesture_state: TrackpadGestureState,
+    /// Initial delay / interval and timing of synthesized key repeats
+    pub key_repeat: KeyRepeatState,
     pub size: WindowSize,
     pub flags: WindowFlags,
     pub mouse_state: MouseState,
@@ -113,6 +119,8 @@ impl Default for FullWindowState {
             layout_callback: LayoutCallback::default(),
             position: WindowPosition::default(),
             touch_state: TouchState::default(),
+            gesture_state: TrackpadGestureState::default(),
+            key_repeat: KeyRepeatState::default(),
             size: WindowSize::default(),
             flags: WindowFlags::default(),
             mouse_state: MouseState::default(),
@@ -126,3 +134,25 @@ impl Default for FullWindowState {
         }
     }
 }
+
+impl FullWindowState {
+    /// Returns how far the mouse cursor moved since `previous`.
+    ///
+    /// Returns `None` if the cursor is outside the window in either frame.
+    pub fn get_mouse_delta(&self, previous: &FullWindowState) -> Option<LogicalPosition> {
+        let current = self.mouse_state.cursor_position.get_position()?;
+        let previous = previous.mouse_state.cursor_position.get_position()?;
+        Some(LogicalPosition::new(
+            current.x - previous.x,
+            current.y - previous.y,
+        ))
+    }
+
+    /// Returns how much the logical window size changed since `previous`.
+    pub fn get_size_delta(&self, previous: &FullWindowState) -> LogicalSize {
+        LogicalSize::new(
+            self.size.dimensions.width - previous.size.dimensions.width,
+            self.size.dimensions.height - previous.size.dimensions.height,
+        )
+    }
+}
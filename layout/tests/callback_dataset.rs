@@ -0,0 +1,97 @@
+//! Tests for reading node datasets from inside callbacks
+
+use std::sync::Arc;
+
+use azul_core::{
+    callbacks::Update,
+    dom::{Dom, DomId, DomNodeId, NodeId},
+    geom::LogicalSize,
+    gl::OptionGlContextPtr,
+    refany::{OptionRefAny, RefAny},
+    resources::RendererResources,
+    styled_dom::{NodeHierarchyItemId, StyledDom},
+    window::RawWindowHandle,
+};
+use azul_css::system::SystemStyle;
+use azul_layout::{
+    callbacks::{Callback, CallbackInfo, ExternalSystemCallbacks},
+    window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+/// Dataset attached to each list item
+struct ListItem {
+    index: usize,
+}
+
+/// Callback data: which list item was clicked
+struct Clicked {
+    index: Option<usize>,
+}
+
+fn item_node(id: usize) -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+    }
+}
+
+extern "C" fn on_item_click(mut data: RefAny, mut info: CallbackInfo) -> Update {
+    let Some(mut dataset) = info.get_dataset(item_node(2)) else {
+        return Update::DoNothing;
+    };
+    let Some(item) = dataset.downcast_ref::<ListItem>() else {
+        return Update::DoNothing;
+    };
+    if let Some(mut clicked) = data.downcast_mut::<Clicked>() {
+        clicked.index = Some(item.index);
+    }
+    Update::DoNothing
+}
+
+#[test]
+fn test_click_callback_reads_node_dataset() {
+    // list (0) -> [item 0 (1), item 1 (2)]
+    let item = |index| {
+        Dom::create_div().with_dataset(OptionRefAny::Some(RefAny::new(ListItem { index })))
+    };
+    let mut dom = Dom::create_div().with_child(item(0)).with_child(item(1));
+    let (css, _) = azul_css::parser2::new_from_str("div { height: 20px; }");
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+    let renderer_resources = RendererResources::default();
+    let system_callbacks = ExternalSystemCallbacks::rust_internal();
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &renderer_resources,
+            &system_callbacks,
+            &mut None,
+        )
+        .unwrap();
+
+    let mut callback = Callback {
+        cb: on_item_click,
+        ctx: OptionRefAny::None,
+    };
+    let mut data = RefAny::new(Clicked { index: None });
+    let (_, update) = window.invoke_single_callback(
+        &mut callback,
+        &mut data,
+        &RawWindowHandle::Unsupported,
+        &OptionGlContextPtr::None,
+        Arc::new(SystemStyle::default()),
+        &system_callbacks,
+        &None,
+        &window_state,
+        &renderer_resources,
+    );
+
+    assert_eq!(update, Update::DoNothing);
+    assert_eq!(data.downcast_ref::<Clicked>().unwrap().index, Some(1));
+}
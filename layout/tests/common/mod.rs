@@ -0,0 +1,61 @@
+//! Fixtures shared by the integration tests, pulled in with `mod common;`
+
+#![allow(dead_code)]
+
+use azul_core::{
+    dom::{Dom, DomId, DomNodeId, IdOrClass, NodeId},
+    geom::LogicalSize,
+    resources::RendererResources,
+    styled_dom::{NodeHierarchyItemId, StyledDom},
+};
+use azul_css::props::basic::FontRef;
+use azul_layout::{
+    callbacks::ExternalSystemCallbacks, text3::default::font_ref_from_bytes, window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+/// Styles `dom` with `css` and lays it out into a fresh window of the given size
+pub fn layout_dom(dom: Dom, css: &str, width: f32, height: f32) -> LayoutWindow {
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    relayout(&mut window, dom, css, width, height);
+    window
+}
+
+/// Lays out `dom` again into an existing window, keeping its managers' state
+pub fn relayout(window: &mut LayoutWindow, mut dom: Dom, css: &str, width: f32, height: f32) {
+    let (css, _) = azul_css::parser2::new_from_str(css);
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(width, height);
+    let mut debug_messages = Some(Vec::new());
+
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &RendererResources::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+            &mut debug_messages,
+        )
+        .unwrap();
+}
+
+pub fn div(class: &str) -> Dom {
+    Dom::create_div().with_ids_and_classes(vec![IdOrClass::Class(class.to_string().into())].into())
+}
+
+pub fn node(id: usize) -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+    }
+}
+
+/// Source Serif Pro from the example assets. It has Latin letters and the
+/// ellipsis glyph, so text tests don't depend on the installed system fonts.
+pub fn test_font() -> FontRef {
+    let bytes = include_bytes!("../../../examples/assets/fonts/SourceSerifPro-Regular.ttf");
+    font_ref_from_bytes(bytes, 0, false).expect("failed to parse the test font")
+}
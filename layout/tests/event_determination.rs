@@ -1,73 +1,631 @@
-//! Event determination tests - currently disabled pending API export
+//! Event determination tests
 //!
-//! These tests require types that are not currently exported.
+//! Tests for the events `determine_all_events` derives from the difference
+//! between the previous and the current window state.
 
-// Disabled: functions and types not exported
-#![cfg(feature = "DISABLED_event_tests")]
+use azul_core::{
+    dom::{DomId, DomNodeId, NodeId},
+    events::{
+        event_type_to_filters, EventData, EventFilter, EventType, SyntheticEvent, WindowEventData,
+        WindowEventFilter,
+    },
+    styled_dom::NodeHierarchyItemId,
+    task::{Instant, SystemTick},
+    window::{GesturePhase, KeyboardState, VirtualKeyCode, WindowSize, WindowTheme},
+};
+use azul_css::{corety::OptionU32, AzString, OptionString};
+use azul_layout::{
+    event_determination::{clipboard_shortcut, determine_all_events, theme_change_event},
+    managers::{file_drop::FileDropManager, focus_cursor::FocusManager, hover::HoverManager},
+    window_state::FullWindowState,
+};
+
+/// Determines the events of one frame going from `previous` to `current` at
+/// `tick`, with `focused` holding the keyboard focus
+fn determine(
+    previous: &FullWindowState,
+    current: &FullWindowState,
+    focused: Option<DomNodeId>,
+    tick: u64,
+) -> Vec<SyntheticEvent> {
+    let mut focus_manager = FocusManager::new();
+    focus_manager.set_focused_node(focused);
+
+    determine_all_events(
+        current,
+        previous,
+        &HoverManager::new(),
+        &focus_manager,
+        &FileDropManager::new(),
+        None,
+        &[],
+        Instant::Tick(SystemTick::new(tick)),
+    )
+}
+
+fn count(events: &[SyntheticEvent], event_type: EventType) -> usize {
+    events.iter().filter(|e| e.event_type == event_type).count()
+}
 
-use azul_layout::event_determination::*;
+// =============================================================================
+// Mouse button chords
+// =============================================================================
 
 #[test]
-fn test_detect_window_resize() {
-    let timestamp = Instant::Tick(SystemTick::new(0));
-    let mut prev_state = FullWindowState::default();
-    let mut curr_state = FullWindowState::default();
+fn test_chord_press_left_then_right_release_left() {
+    let idle = FullWindowState::default();
+    assert_eq!(idle.mouse_state.buttons(), 0);
+
+    let mut left = idle.clone();
+    left.mouse_state.left_down = true;
+    assert_eq!(left.mouse_state.buttons(), 1);
+    let events = determine(&idle, &left, None, 0);
+    assert_eq!(count(&events, EventType::MouseButtonsChange), 1);
+
+    let mut both = left.clone();
+    both.mouse_state.right_down = true;
+    assert_eq!(both.mouse_state.buttons(), 1 | 2);
+    let events = determine(&left, &both, None, 0);
+    assert_eq!(count(&events, EventType::MouseButtonsChange), 1);
+
+    let mut right = both.clone();
+    right.mouse_state.left_down = false;
+    assert_eq!(right.mouse_state.buttons(), 2);
+    let events = determine(&both, &right, None, 0);
+    assert_eq!(count(&events, EventType::MouseButtonsChange), 1);
+
+    // No change, no chord event
+    let events = determine(&right, &right, None, 0);
+    assert_eq!(count(&events, EventType::MouseButtonsChange), 0);
+}
+
+#[test]
+fn test_chord_two_buttons_down_in_same_frame() {
+    let idle = FullWindowState::default();
+    let mut both = idle.clone();
+    both.mouse_state.left_down = true;
+    both.mouse_state.right_down = true;
+
+    let events = determine(&idle, &both, None, 0);
+
+    // One MouseDown per button, but a single chord transition
+    assert_eq!(count(&events, EventType::MouseDown), 2);
+    assert_eq!(count(&events, EventType::MouseButtonsChange), 1);
+}
+
+#[test]
+fn test_chord_event_maps_to_window_filter() {
+    assert_eq!(
+        event_type_to_filters(EventType::MouseButtonsChange, &EventData::None),
+        vec![EventFilter::Window(WindowEventFilter::MouseButtonsChanged)]
+    );
+}
 
-    prev_state.size = azul_core::window::WindowSize {
-        dimensions: azul_core::geom::LogicalSize {
-            width: 800.0,
-            height: 600.0,
+// =============================================================================
+// IME composition
+// =============================================================================
+
+fn with_preedit(state: &FullWindowState, preedit: Option<&str>) -> FullWindowState {
+    let mut state = state.clone();
+    state.keyboard_state.ime_composition = preedit.map(AzString::from).into();
+    state
+}
+
+fn composition_events(previous: &FullWindowState, current: &FullWindowState) -> Vec<EventType> {
+    determine(previous, current, None, 0)
+        .into_iter()
+        .map(|e| e.event_type)
+        .filter(|e| {
+            matches!(
+                e,
+                EventType::CompositionStart
+                    | EventType::CompositionUpdate
+                    | EventType::CompositionEnd
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_composition_sequence_update_then_commit() {
+    let idle = FullWindowState::default();
+
+    let started = with_preedit(&idle, Some("n"));
+    assert_eq!(
+        composition_events(&idle, &started),
+        vec![EventType::CompositionStart, EventType::CompositionUpdate]
+    );
+
+    let updated = with_preedit(&started, Some("にほ"));
+    assert_eq!(
+        composition_events(&started, &updated),
+        vec![EventType::CompositionUpdate]
+    );
+    assert_eq!(
+        updated.keyboard_state.ime_composition,
+        OptionString::Some(AzString::from("にほ"))
+    );
+
+    // Unchanged preedit: nothing to report
+    assert!(composition_events(&updated, &updated).is_empty());
+
+    // Commit: the preedit goes away, the committed text arrives as text input
+    let committed = with_preedit(&updated, None);
+    assert_eq!(
+        composition_events(&updated, &committed),
+        vec![EventType::CompositionEnd]
+    );
+    assert_eq!(committed.keyboard_state.ime_composition, OptionString::None);
+}
+
+#[test]
+fn test_composition_events_map_to_window_filters() {
+    for (event, filter) in [
+        (
+            EventType::CompositionStart,
+            WindowEventFilter::CompositionStart,
+        ),
+        (
+            EventType::CompositionUpdate,
+            WindowEventFilter::CompositionUpdate,
+        ),
+        (EventType::CompositionEnd, WindowEventFilter::CompositionEnd),
+    ] {
+        assert!(
+            event_type_to_filters(event, &EventData::None).contains(&EventFilter::Window(filter))
+        );
+    }
+}
+
+// =============================================================================
+// DPI changes
+// =============================================================================
+
+fn on_monitor(monitor_id: u32, dpi: u32) -> FullWindowState {
+    FullWindowState {
+        monitor_id: OptionU32::Some(monitor_id),
+        size: WindowSize {
+            dpi,
+            ..Default::default()
         },
-        dpi: 96,
-        min_dimensions: Default::default(),
-        max_dimensions: Default::default(),
+        ..Default::default()
+    }
+}
+
+fn dpi_changed_factor(events: &[SyntheticEvent]) -> Option<f32> {
+    events
+        .iter()
+        .find(|e| e.event_type == EventType::WindowDpiChanged)
+        .and_then(|e| match &e.data {
+            EventData::Window(w) => w.hidpi_factor,
+            _ => None,
+        })
+}
+
+#[test]
+fn test_moving_to_hidpi_monitor_reports_new_factor() {
+    let low_dpi = on_monitor(0, 96);
+    let high_dpi = on_monitor(1, 192);
+
+    assert_eq!(low_dpi.size.get_hidpi_factor().inner.get(), 1.0);
+    assert_eq!(high_dpi.size.get_hidpi_factor().inner.get(), 2.0);
+
+    let events = determine(&low_dpi, &high_dpi, None, 0);
+    assert_eq!(dpi_changed_factor(&events), Some(2.0));
+
+    // And back again
+    let events = determine(&high_dpi, &low_dpi, None, 0);
+    assert_eq!(dpi_changed_factor(&events), Some(1.0));
+}
+
+#[test]
+fn test_same_dpi_monitor_does_not_report_dpi_change() {
+    let events = determine(&on_monitor(0, 96), &on_monitor(1, 96), None, 0);
+    assert_eq!(count(&events, EventType::WindowDpiChanged), 0);
+}
+
+// =============================================================================
+// Clipboard shortcuts
+// =============================================================================
+
+fn keys(modifier: VirtualKeyCode, key: VirtualKeyCode) -> KeyboardState {
+    KeyboardState {
+        current_virtual_keycode: Some(key).into(),
+        pressed_virtual_keycodes: vec![modifier, key].into(),
+        ..Default::default()
+    }
+}
+
+fn focused_node() -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(3))),
+    }
+}
+
+#[test]
+fn test_ctrl_shortcuts_on_non_mac() {
+    let ctrl_c = keys(VirtualKeyCode::LControl, VirtualKeyCode::C);
+    assert_eq!(clipboard_shortcut(&ctrl_c, false), Some(EventType::Copy));
+    assert_eq!(
+        clipboard_shortcut(&keys(VirtualKeyCode::RControl, VirtualKeyCode::X), false),
+        Some(EventType::Cut)
+    );
+    assert_eq!(
+        clipboard_shortcut(&keys(VirtualKeyCode::LControl, VirtualKeyCode::V), false),
+        Some(EventType::Paste)
+    );
+
+    // Cmd+C is not a shortcut outside of macOS, Ctrl+C is not one on macOS
+    let cmd_c = keys(VirtualKeyCode::LWin, VirtualKeyCode::C);
+    assert_eq!(clipboard_shortcut(&cmd_c, false), None);
+    assert_eq!(clipboard_shortcut(&ctrl_c, true), None);
+}
+
+#[test]
+fn test_cmd_shortcuts_on_mac() {
+    let cmd_c = keys(VirtualKeyCode::LWin, VirtualKeyCode::C);
+    assert_eq!(clipboard_shortcut(&cmd_c, true), Some(EventType::Copy));
+    assert_eq!(
+        clipboard_shortcut(&keys(VirtualKeyCode::RWin, VirtualKeyCode::V), true),
+        Some(EventType::Paste)
+    );
+
+    // Plain "c" without modifier
+    let plain = KeyboardState {
+        current_virtual_keycode: Some(VirtualKeyCode::C).into(),
+        pressed_virtual_keycodes: vec![VirtualKeyCode::C].into(),
+        ..Default::default()
     };
-    curr_state.size = azul_core::window::WindowSize {
-        dimensions: azul_core::geom::LogicalSize {
-            width: 1024.0,
-            height: 768.0,
-        },
-        dpi: 96,
-        min_dimensions: Default::default(),
-        max_dimensions: Default::default(),
+    assert_eq!(clipboard_shortcut(&plain, true), None);
+    assert_eq!(clipboard_shortcut(&plain, false), None);
+}
+
+#[test]
+fn test_copy_event_routes_to_focused_node() {
+    let modifier = if cfg!(target_os = "macos") {
+        VirtualKeyCode::LWin
+    } else {
+        VirtualKeyCode::LControl
     };
 
-    let events = detect_window_state_events(&curr_state, &prev_state, timestamp);
+    let previous = FullWindowState::default();
+    let current = FullWindowState {
+        keyboard_state: keys(modifier, VirtualKeyCode::C),
+        ..previous.clone()
+    };
 
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].event_type, EventType::WindowResize);
+    let events = determine(&previous, &current, Some(focused_node()), 0);
+    let copy = events
+        .iter()
+        .find(|e| e.event_type == EventType::Copy)
+        .expect("no Copy event");
+    assert_eq!(copy.target, focused_node());
+    assert_eq!(copy.data, EventData::None);
+    assert!(event_type_to_filters(EventType::Copy, &copy.data)
+        .contains(&EventFilter::Window(WindowEventFilter::Copy)));
+
+    // Without a focused node, no semantic clipboard event is generated
+    let events = determine(&previous, &current, None, 0);
+    assert_eq!(count(&events, EventType::Copy), 0);
+}
+
+// =============================================================================
+// Trackpad gestures
+// =============================================================================
+
+fn assert_approx(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-5,
+        "expected {expected}, got {actual}"
+    );
 }
 
 #[test]
-fn test_determine_events_deduplicates() {
-    struct DummyManager;
-    impl EventProvider for DummyManager {
-        fn get_pending_events(&self, timestamp: Instant) -> Vec<SyntheticEvent> {
-            // Return duplicate event
-            vec![SyntheticEvent::new(
-                EventType::Input,
-                EventSource::User,
-                DomNodeId {
-                    dom: DomId { inner: 0 },
-                    node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)),
-                },
-                timestamp,
-                EventData::None,
-            )]
-        }
+fn test_magnify_sequence_accumulates_zoom() {
+    let mut previous = FullWindowState::default();
+    let mut current = previous.clone();
+    let mut magnify_events = 0;
+
+    let sequence = [
+        (GesturePhase::Began, 0.1),
+        (GesturePhase::Changed, 0.1),
+        (GesturePhase::Changed, -0.5),
+        (GesturePhase::Ended, 0.0),
+    ];
+    for (phase, delta) in sequence {
+        current.gesture_state.update_magnify(phase, delta);
+        let events = determine(&previous, &current, None, 0);
+        magnify_events += count(&events, EventType::Magnify);
+        assert_eq!(count(&events, EventType::Rotate), 0);
+        previous = current.clone();
     }
 
-    let timestamp = Instant::Tick(SystemTick::new(0));
-    let prev_state = FullWindowState::default();
-    let curr_state = FullWindowState::default();
+    // Every begin / change / end update fires exactly one event
+    assert_eq!(magnify_events, 4);
+    assert_eq!(current.gesture_state.magnify_phase, GesturePhase::Ended);
+    assert_approx(current.gesture_state.magnification, 1.1 * 1.1 * 0.5);
 
-    let manager1 = DummyManager;
-    let manager2 = DummyManager;
-    let managers: Vec<&dyn EventProvider> = vec![&manager1, &manager2];
+    // Idle frame after the gesture ended: no further events
+    let events = determine(&previous, &current, None, 0);
+    assert_eq!(count(&events, EventType::Magnify), 0);
 
-    let events = determine_events_from_managers(&curr_state, &prev_state, &managers, timestamp);
+    // A new gesture starts again from identity zoom
+    current
+        .gesture_state
+        .update_magnify(GesturePhase::Began, 0.2);
+    assert_approx(current.gesture_state.magnification, 1.2);
+    let events = determine(&previous, &current, None, 0);
+    assert_eq!(count(&events, EventType::Magnify), 1);
+}
+
+#[test]
+fn test_rotate_sequence_accumulates_rotation() {
+    let mut previous = FullWindowState::default();
+    let mut current = previous.clone();
+    let mut rotate_events = 0;
+
+    let sequence = [
+        (GesturePhase::Began, 0.25),
+        (GesturePhase::Changed, 0.5),
+        (GesturePhase::Changed, -0.125),
+        (GesturePhase::Ended, 0.0),
+    ];
+    for (phase, radians) in sequence {
+        current.gesture_state.update_rotate(phase, radians);
+        let events = determine(&previous, &current, None, 0);
+        rotate_events += count(&events, EventType::Rotate);
+        assert_eq!(count(&events, EventType::Magnify), 0);
+        previous = current.clone();
+    }
+
+    assert_eq!(rotate_events, 4);
+    assert_approx(current.gesture_state.rotation, 0.625);
+    assert_approx(current.gesture_state.rotate_delta, 0.0);
+
+    current
+        .gesture_state
+        .update_rotate(GesturePhase::Began, -0.1);
+    assert_approx(current.gesture_state.rotation, -0.1);
+}
+
+#[test]
+fn test_gesture_events_route_to_window_filters() {
+    assert_eq!(
+        event_type_to_filters(EventType::Magnify, &EventData::None),
+        vec![EventFilter::Window(WindowEventFilter::Magnify)]
+    );
+    assert_eq!(
+        event_type_to_filters(EventType::Rotate, &EventData::None),
+        vec![EventFilter::Window(WindowEventFilter::Rotate)]
+    );
+}
+
+// =============================================================================
+// Theme changes
+// =============================================================================
+
+fn with_theme(theme: WindowTheme) -> FullWindowState {
+    FullWindowState {
+        theme,
+        ..Default::default()
+    }
+}
+
+fn theme_changes(events: &[SyntheticEvent]) -> Vec<Option<WindowTheme>> {
+    events
+        .iter()
+        .filter(|e| e.event_type == EventType::ThemeChange)
+        .map(|e| match &e.data {
+            EventData::Window(WindowEventData { theme, .. }) => *theme,
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_light_to_dark_carries_dark_theme() {
+    let previous = with_theme(WindowTheme::LightMode);
+    let current = with_theme(WindowTheme::DarkMode);
+
+    let events = determine(&previous, &current, None, 0);
+    assert_eq!(theme_changes(&events), vec![Some(WindowTheme::DarkMode)]);
+}
+
+#[test]
+fn test_unchanged_theme_emits_nothing() {
+    let previous = with_theme(WindowTheme::DarkMode);
+    let current = previous.clone();
+
+    let events = determine(&previous, &current, None, 0);
+    assert!(theme_changes(&events).is_empty());
+}
+
+#[test]
+fn test_initial_theme_event() {
+    let event = theme_change_event(WindowTheme::DarkMode, Instant::Tick(SystemTick::new(0)));
+    assert_eq!(event.event_type, EventType::ThemeChange);
+    assert_eq!(theme_changes(&[event]), vec![Some(WindowTheme::DarkMode)]);
+}
+
+// =============================================================================
+// Key repeat
+// =============================================================================
+
+fn holding(key: Option<VirtualKeyCode>) -> KeyboardState {
+    KeyboardState {
+        current_virtual_keycode: key.into(),
+        pressed_virtual_keycodes: key.into_iter().collect::<Vec<_>>().into(),
+        ..Default::default()
+    }
+}
+
+/// Runs one frame at `tick` with `key` held, returns the event types it produced
+fn frame(previous: &mut FullWindowState, key: Option<VirtualKeyCode>, tick: u64) -> Vec<EventType> {
+    let mut current = previous.clone();
+    current.keyboard_state = holding(key);
+    current.key_repeat.advance(
+        key,
+        previous
+            .keyboard_state
+            .current_virtual_keycode
+            .into_option(),
+        Instant::Tick(SystemTick::new(tick)),
+    );
+
+    let events = determine(previous, &current, None, tick);
+    *previous = current;
+    events.into_iter().map(|e| e.event_type).collect()
+}
+
+fn new_window() -> FullWindowState {
+    let mut state = FullWindowState::default();
+    state.key_repeat.initial_delay_ms = 500;
+    state.key_repeat.interval_ms = 100;
+    state
+}
 
-    // Should deduplicate the two identical Input events
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].event_type, EventType::Input);
+#[test]
+fn test_key_repeat_waits_for_initial_delay_then_follows_interval() {
+    let mut state = new_window();
+    let a = Some(VirtualKeyCode::A);
+
+    let events = frame(&mut state, a, 0);
+    assert!(events.contains(&EventType::KeyDown));
+    assert!(!events.contains(&EventType::KeyRepeat));
+
+    // Held, but still within the initial delay
+    for tick in [100, 200, 300, 400] {
+        let events = frame(&mut state, a, tick);
+        assert!(
+            !events.contains(&EventType::KeyRepeat),
+            "repeat at {}",
+            tick
+        );
+        assert!(!events.contains(&EventType::KeyDown));
+    }
+
+    // Past the delay: one repeat per interval
+    for tick in [500, 600, 700] {
+        let events = frame(&mut state, a, tick);
+        assert!(
+            events.contains(&EventType::KeyRepeat),
+            "no repeat at {}",
+            tick
+        );
+    }
+
+    // Two frames within the same interval only repeat once
+    assert!(!frame(&mut state, a, 750).contains(&EventType::KeyRepeat));
+    assert!(frame(&mut state, a, 800).contains(&EventType::KeyRepeat));
+
+    let events = frame(&mut state, None, 900);
+    assert!(events.contains(&EventType::KeyUp));
+    assert!(!events.contains(&EventType::KeyRepeat));
+}
+
+#[test]
+fn test_key_repeat_restarts_when_key_changes() {
+    let mut state = new_window();
+
+    frame(&mut state, Some(VirtualKeyCode::A), 0);
+    assert!(frame(&mut state, Some(VirtualKeyCode::A), 600).contains(&EventType::KeyRepeat));
+
+    // Switching keys restarts the initial delay
+    let events = frame(&mut state, Some(VirtualKeyCode::B), 700);
+    assert!(events.contains(&EventType::KeyDown));
+    assert!(!events.contains(&EventType::KeyRepeat));
+    assert!(!frame(&mut state, Some(VirtualKeyCode::B), 1100).contains(&EventType::KeyRepeat));
+    assert!(frame(&mut state, Some(VirtualKeyCode::B), 1200).contains(&EventType::KeyRepeat));
+}
+
+#[test]
+fn test_key_repeat_disabled_with_zero_interval() {
+    let mut state = new_window();
+    state.key_repeat.interval_ms = 0;
+
+    frame(&mut state, Some(VirtualKeyCode::A), 0);
+    for tick in [500, 1000, 5000] {
+        assert!(!frame(&mut state, Some(VirtualKeyCode::A), tick).contains(&EventType::KeyRepeat));
+    }
+}
+
+#[test]
+fn test_key_repeat_maps_to_window_filter() {
+    let filters = event_type_to_filters(EventType::KeyRepeat, &EventData::None);
+    assert!(filters.contains(&EventFilter::Window(WindowEventFilter::VirtualKeyRepeat)));
+}
+
+// =============================================================================
+// Disabled: functions and types not exported
+// =============================================================================
+
+#[cfg(feature = "DISABLED_event_tests")]
+mod disabled {
+    use azul_layout::event_determination::*;
+
+    #[test]
+    fn test_detect_window_resize() {
+        let timestamp = Instant::Tick(SystemTick::new(0));
+        let mut prev_state = FullWindowState::default();
+        let mut curr_state = FullWindowState::default();
+
+        prev_state.size = azul_core::window::WindowSize {
+            dimensions: azul_core::geom::LogicalSize {
+                width: 800.0,
+                height: 600.0,
+            },
+            dpi: 96,
+            min_dimensions: Default::default(),
+            max_dimensions: Default::default(),
+        };
+        curr_state.size = azul_core::window::WindowSize {
+            dimensions: azul_core::geom::LogicalSize {
+                width: 1024.0,
+                height: 768.0,
+            },
+            dpi: 96,
+            min_dimensions: Default::default(),
+            max_dimensions: Default::default(),
+        };
+
+        let events = detect_window_state_events(&curr_state, &prev_state, timestamp);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::WindowResize);
+    }
+
+    #[test]
+    fn test_determine_events_deduplicates() {
+        struct DummyManager;
+        impl EventProvider for DummyManager {
+            fn get_pending_events(&self, timestamp: Instant) -> Vec<SyntheticEvent> {
+                // Return duplicate event
+                vec![SyntheticEvent::new(
+                    EventType::Input,
+                    EventSource::User,
+                    DomNodeId {
+                        dom: DomId { inner: 0 },
+                        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)),
+                    },
+                    timestamp,
+                    EventData::None,
+                )]
+            }
+        }
+
+        let timestamp = Instant::Tick(SystemTick::new(0));
+        let prev_state = FullWindowState::default();
+        let curr_state = FullWindowState::default();
+
+        let manager1 = DummyManager;
+        let manager2 = DummyManager;
+        let managers: Vec<&dyn EventProvider> = vec![&manager1, &manager2];
+
+        let events = determine_events_from_managers(&curr_state, &prev_state, &managers, timestamp);
+
+        // Should deduplicate the two identical Input events
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::Input);
+    }
 }
@@ -5,7 +5,7 @@
 
 use azul_core::{
     dom::{Dom, DomId, DomNodeId, IdOrClass, NodeId},
-    geom::LogicalSize,
+    geom::{LogicalRect, LogicalSize},
     resources::RendererResources,
     styled_dom::{NodeHierarchyItemId, StyledDom},
 };
@@ -332,6 +332,130 @@ fn test_flexbox_flex_wrap() {
         .expect("container rect");
 }
 
+fn get_node_id(index: usize) -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(index))),
+    }
+}
+
+/// Three 100px wide, non-shrinking items in a flex container of the given
+/// width, with extra `container` declarations. Returns the rects of the
+/// container and the three items.
+///
+/// The container is nested in a plain div: an auto-height root is sized to
+/// the viewport, which would stretch wrapped lines over its full height.
+fn layout_three_items(container_width: f32, container: &str) -> [LogicalRect; 4] {
+    let item =
+        || Dom::create_div().with_ids_and_classes(vec![IdOrClass::Class("item".into())].into());
+    let dom = Dom::create_div().with_child(
+        Dom::create_div()
+            .with_ids_and_classes(vec![IdOrClass::Class("container".into())].into())
+            .with_child(item())
+            .with_child(item())
+            .with_child(item()),
+    );
+
+    let css = format!(
+        r#"
+        .container {{
+            display: flex;
+            width: {container_width}px;
+            {container}
+        }}
+        .item {{
+            width: 100px;
+            height: 50px;
+            flex-shrink: 0;
+        }}
+    "#
+    );
+
+    let layout_window = layout_dom(dom, &css, 1024.0, 768.0);
+    let rect = |i: usize| {
+        layout_window
+            .get_node_layout_rect(get_node_id(i + 1))
+            .expect("node rect")
+    };
+    [rect(0), rect(1), rect(2), rect(3)]
+}
+
+#[test]
+fn test_flexbox_flex_wrap_breaks_into_rows() {
+    let rects = layout_three_items(250.0, "flex-wrap: wrap;");
+    let rect = |i: usize| rects[i];
+    let container = rect(0);
+
+    // [1][2] on the first row, [3] wrapped onto the second one
+    let row_of = |i| ((rect(i).origin.y - container.origin.y) / 50.0).round();
+    assert_eq!([row_of(1), row_of(2), row_of(3)], [0.0, 0.0, 1.0]);
+    assert_eq!(rect(1).origin.x, container.origin.x);
+    assert_eq!(rect(2).origin.x, container.origin.x + 100.0);
+    assert_eq!(rect(3).origin.x, container.origin.x);
+
+    // The container grows to fit both rows
+    assert_eq!(container.size.height, 100.0);
+}
+
+#[test]
+fn test_flexbox_nowrap_keeps_single_row() {
+    let rects = layout_three_items(250.0, "flex-wrap: nowrap;");
+    let rect = |i: usize| rects[i];
+    let container = rect(0);
+
+    for i in 1..=3 {
+        assert_eq!(rect(i).origin.y, container.origin.y);
+    }
+    assert_eq!(rect(3).origin.x, container.origin.x + 200.0);
+    assert_eq!(container.size.height, 50.0);
+}
+
+/// x offsets of the three items relative to the container
+fn justified_item_offsets(justify_content: &str) -> [f32; 3] {
+    let css = format!("justify-content: {justify_content};");
+    let rects = layout_three_items(500.0, &css);
+    let x = |i: usize| rects[i].origin.x;
+    [x(1) - x(0), x(2) - x(0), x(3) - x(0)]
+}
+
+fn assert_offsets(justify_content: &str, expected: [f32; 3]) {
+    let actual = justified_item_offsets(justify_content);
+    assert!(
+        actual.iter().zip(expected.iter()).all(|(a, e)| (a - e).abs() < 0.01),
+        "justify-content: {justify_content}: expected {expected:?}, got {actual:?}"
+    );
+}
+
+// 500px container, 3 * 100px items: 200px of free space on the main axis
+
+#[test]
+fn test_justify_content_flex_start() {
+    assert_offsets("flex-start", [0.0, 100.0, 200.0]);
+}
+
+#[test]
+fn test_justify_content_center() {
+    assert_offsets("center", [100.0, 200.0, 300.0]);
+}
+
+#[test]
+fn test_justify_content_space_between() {
+    // All free space in the two gaps
+    assert_offsets("space-between", [0.0, 200.0, 400.0]);
+}
+
+#[test]
+fn test_justify_content_space_around() {
+    // 200 / 3 around each item, so half of that at both ends
+    let around = 200.0 / 3.0;
+    assert_offsets("space-around", [around / 2.0, 100.0 + around * 1.5, 200.0 + around * 2.5]);
+}
+
+#[test]
+fn test_justify_content_space_evenly() {
+    assert_offsets("space-evenly", [50.0, 200.0, 350.0]);
+}
+
 #[test]
 fn test_flexbox_gap() {
     // Test gap property
@@ -367,6 +491,43 @@ fn test_flexbox_gap() {
         .expect("container rect");
 }
 
+#[test]
+fn test_flexbox_gap_spaces_items_on_main_axis() {
+    let rects = layout_three_items(400.0, "gap: 10px;");
+    let x = |i: usize| rects[i].origin.x;
+    assert_eq!([x(1) - x(0), x(2) - x(0), x(3) - x(0)], [0.0, 110.0, 220.0]);
+}
+
+#[test]
+fn test_flexbox_gap_reduces_space_for_flex_grow() {
+    // 400px - 2 * 10px gaps = 380px, shared by three growing items
+    let grow =
+        || Dom::create_div().with_ids_and_classes(vec![IdOrClass::Class("grow".into())].into());
+    let dom = Dom::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class("container".into())].into())
+        .with_child(grow())
+        .with_child(grow())
+        .with_child(grow());
+    let css = r#"
+        .container { display: flex; width: 400px; gap: 10px; }
+        .grow { flex-grow: 1; flex-basis: 0px; height: 50px; }
+    "#;
+    let grown = layout_dom(dom, css, 1024.0, 768.0);
+    let width = grown.get_node_layout_rect(get_node_id(1)).unwrap().size.width;
+    assert!((width - 380.0 / 3.0).abs() < 0.01, "{width}");
+}
+
+#[test]
+fn test_flexbox_row_and_column_gap_on_wrapped_lines() {
+    let rects = layout_three_items(250.0, "flex-wrap: wrap; gap: 20px 10px;");
+    let rect = |i: usize| rects[i];
+    let container = rect(0);
+
+    // column-gap between items of a line, row-gap between the lines
+    assert_eq!(rect(2).origin.x - container.origin.x, 110.0);
+    assert_eq!(rect(3).origin.y - container.origin.y, 70.0);
+}
+
 #[test]
 fn test_flexbox_order() {
     // Test order property
@@ -687,3 +848,46 @@ fn test_flexbox_flex_basis_zero() {
         .get_node_layout_rect(root_id)
         .expect("container rect");
 }
+
+#[test]
+fn test_flex_basis_content_ignores_width() {
+    // container (0) -> a (1) -> inner (2), b (3)
+    let dom = Dom::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class("container".into())].into())
+        .with_child(
+            Dom::create_div()
+                .with_ids_and_classes(vec![IdOrClass::Class("a".into())].into())
+                .with_child(
+                    Dom::create_div()
+                        .with_ids_and_classes(vec![IdOrClass::Class("inner".into())].into()),
+                ),
+        )
+        .with_child(
+            Dom::create_div().with_ids_and_classes(vec![IdOrClass::Class("b".into())].into()),
+        );
+
+    // a starts from its 100px content (not its 50px width), b from 100px,
+    // so the remaining 200px are split evenly
+    let css = r#"
+        .container { display: flex; flex-direction: row; width: 400px; height: 100px; }
+        .a { flex-basis: content; flex-grow: 1; width: 50px; }
+        .inner { width: 100px; height: 10px; }
+        .b { flex-basis: 100px; flex-grow: 1; }
+    "#;
+
+    let layout_window = layout_dom(dom, css, 1024.0, 768.0);
+    let width_of = |id: usize| {
+        layout_window
+            .get_node_layout_rect(DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+            })
+            .expect("item rect")
+            .size
+            .width
+    };
+
+    let (a, b) = (width_of(1), width_of(3));
+    assert!((a - 200.0).abs() < 1.0, "a should be 200px, got {}", a);
+    assert!((b - 200.0).abs() < 1.0, "b should be 200px, got {}", b);
+}
@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 
 use azul_core::{
     dom::{DomId, DomNodeId, NodeId},
+    events::{event_type_to_filters, EventData, EventFilter, EventType, FocusEventFilter},
     selection::{
         CursorAffinity, GraphemeClusterId, Selection, SelectionState, SelectionVec, TextCursor,
     },
@@ -11,7 +12,10 @@ use azul_core::{
 };
 use azul_layout::{
     callbacks::FocusUpdateRequest,
-    managers::{focus_cursor::FocusManager, selection::SelectionManager},
+    managers::{
+        focus_cursor::{FocusManager, FocusOrigin},
+        selection::SelectionManager,
+    },
     window::LayoutWindow,
 };
 
@@ -707,3 +711,41 @@ fn test_focus_update_request_equality() {
     assert_ne!(req2, req3);
     assert_ne!(req1, req3);
 }
+
+#[test]
+fn test_focus_visible_only_for_keyboard_focus() {
+    let mut manager = FocusManager::new();
+    let node = DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+    };
+
+    // Tab navigation: focus ring should be shown
+    manager.set_focused_node_with_origin(Some(node), FocusOrigin::Keyboard);
+    assert_eq!(manager.get_focus_origin(), FocusOrigin::Keyboard);
+    assert!(manager.is_focus_visible());
+
+    // Clicking the same node: focused, but no focus ring
+    manager.set_focused_node_with_origin(Some(node), FocusOrigin::Pointer);
+    assert_eq!(manager.get_focused_node(), Some(&node));
+    assert!(!manager.is_focus_visible());
+
+    // Programmatic focus does not count as keyboard focus
+    manager.set_focused_node(Some(node));
+    assert_eq!(manager.get_focus_origin(), FocusOrigin::Programmatic);
+    assert!(!manager.is_focus_visible());
+
+    // No focused node -> nothing visible, even after keyboard navigation
+    manager.set_focused_node_with_origin(None, FocusOrigin::Keyboard);
+    assert!(!manager.is_focus_visible());
+}
+
+#[test]
+fn test_focus_visible_event_maps_to_focus_filter() {
+    let filters = event_type_to_filters(EventType::FocusVisible, &EventData::None);
+    assert_eq!(filters, vec![EventFilter::Focus(FocusEventFilter::FocusVisible)]);
+
+    // Regular focus does not trigger FocusVisible callbacks
+    let filters = event_type_to_filters(EventType::Focus, &EventData::None);
+    assert!(!filters.contains(&EventFilter::Focus(FocusEventFilter::FocusVisible)));
+}
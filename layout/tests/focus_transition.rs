@@ -0,0 +1,65 @@
+//! Tests for the dispatch order of focus transition events
+
+use azul_core::{
+    dom::{DomId, DomNodeId, NodeId},
+    events::{
+        deduplicate_synthetic_events, event_type_to_filters, EventData, EventFilter, EventType,
+        FocusEventFilter,
+    },
+    styled_dom::NodeHierarchyItemId,
+    task::{Instant, SystemTick},
+};
+use azul_layout::event_determination::focus_transition_events;
+
+fn node(id: usize) -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+    }
+}
+
+#[test]
+fn test_focus_lost_fires_before_focus_received() {
+    // A comes after B in the DOM, so sorting by node would put B first
+    let a = node(5);
+    let b = node(2);
+
+    let events = focus_transition_events(Some(a), Some(b), false, Instant::Tick(SystemTick::new(0)));
+    // Deduplication must not reorder the transition
+    let events = deduplicate_synthetic_events(events);
+
+    let order: Vec<_> = events.iter().map(|e| (e.event_type, e.target)).collect();
+    assert_eq!(order, vec![(EventType::Blur, a), (EventType::Focus, b)]);
+
+    let filters: Vec<_> = events
+        .iter()
+        .flat_map(|e| event_type_to_filters(e.event_type, &EventData::None))
+        .collect();
+    assert_eq!(
+        filters,
+        vec![
+            EventFilter::Focus(FocusEventFilter::FocusLost),
+            EventFilter::Focus(FocusEventFilter::FocusReceived),
+        ]
+    );
+}
+
+#[test]
+fn test_keyboard_focus_adds_focus_visible_last() {
+    let events = focus_transition_events(
+        Some(node(1)),
+        Some(node(3)),
+        true,
+        Instant::Tick(SystemTick::new(0)),
+    );
+    let types: Vec<_> = events.iter().map(|e| e.event_type).collect();
+    assert_eq!(
+        types,
+        vec![EventType::Blur, EventType::Focus, EventType::FocusVisible]
+    );
+
+    // Initial focus: nothing to blur
+    let events = focus_transition_events(None, Some(node(3)), false, Instant::Tick(SystemTick::new(0)));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, EventType::Focus);
+}
@@ -0,0 +1,82 @@
+//! Tests for `FrameInput::process`, the single-call frame event pipeline
+
+use std::collections::BTreeSet;
+
+use azul_core::{
+    events::EventProvider,
+    geom::LogicalPosition,
+    task::{Instant, SystemTick},
+    window::CursorPosition,
+};
+use azul_layout::{
+    event_determination::{determine_all_events, FrameInput},
+    window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+#[test]
+fn test_mouse_move_frame_matches_manual_pipeline() {
+    let layout_window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let timestamp = Instant::Tick(SystemTick::new(0));
+
+    let mut previous = FullWindowState::default();
+    previous.mouse_state.cursor_position =
+        CursorPosition::InWindow(LogicalPosition::new(10.0, 10.0));
+    let mut current = previous.clone();
+    current.mouse_state.cursor_position =
+        CursorPosition::InWindow(LogicalPosition::new(30.0, 15.0));
+
+    let outcome = FrameInput {
+        current_state: &current,
+        previous_state: &previous,
+        layout_window: &layout_window,
+        timestamp: timestamp.clone(),
+    }
+    .process();
+
+    let providers: [&dyn EventProvider; 2] = [
+        &layout_window.text_input_manager,
+        &layout_window.lifecycle_manager,
+    ];
+    let manual = determine_all_events(
+        &current,
+        &previous,
+        &layout_window.hover_manager,
+        &layout_window.focus_manager,
+        &layout_window.file_drop_manager,
+        Some(&layout_window.gesture_drag_manager),
+        &providers,
+        timestamp,
+    );
+
+    assert!(!manual.is_empty());
+    assert_eq!(outcome.events, manual);
+    assert_eq!(
+        outcome.nodes_to_check,
+        manual.iter().map(|e| e.target).collect::<BTreeSet<_>>()
+    );
+    assert!(outcome.needs_hit_test);
+    assert!(!outcome.needs_relayout);
+    assert!(outcome.needs_redraw);
+}
+
+#[test]
+fn test_idle_frame_needs_nothing() {
+    let layout_window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let state = FullWindowState::default();
+
+    let outcome = FrameInput {
+        current_state: &state,
+        previous_state: &state,
+        layout_window: &layout_window,
+        timestamp: Instant::Tick(SystemTick::new(0)),
+    }
+    .process();
+
+    assert!(outcome.events.is_empty());
+    assert!(outcome.nodes_to_check.is_empty());
+    assert!(!outcome.needs_hit_test);
+    assert!(!outcome.needs_relayout);
+    assert!(!outcome.needs_redraw);
+}
@@ -0,0 +1,210 @@
+//! Tests for the CPU hit tester and its per-frame result cache
+
+use std::collections::BTreeMap;
+
+use azul_core::{
+    dom::{DomId, IdOrClass, NodeId},
+    geom::LogicalPosition,
+    gpu::GpuValueCache,
+};
+use azul_layout::headless::{CpuHitTester, HitTestCache};
+
+mod common;
+
+use common::{div, layout_dom};
+
+#[test]
+fn test_hit_test_cache_hits_on_identical_query() {
+    let dom = div("outer").with_child(div("inner"));
+    let css = ".outer { width: 200px; height: 200px; } .inner { width: 50px; height: 50px; }";
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let mut hit_tester = CpuHitTester::new();
+    hit_tester.rebuild_from_layout(&window.layout_results);
+
+    let mut cache = HitTestCache::new();
+    let no_scroll = BTreeMap::new();
+    let cursor = LogicalPosition::new(10.0, 10.0);
+
+    let first = hit_tester.hit_test_cached(cursor, &no_scroll, &mut cache);
+    let second = hit_tester.hit_test_cached(cursor, &no_scroll, &mut cache);
+    assert_eq!(first, second);
+    assert_eq!(first, hit_tester.hit_test(cursor));
+    assert!(first.contains(&(DomId::ROOT_ID, NodeId::new(1))));
+    assert_eq!((cache.hits, cache.misses), (1, 1));
+
+    // Different cursor: miss, and the inner node is no longer hit
+    let moved =
+        hit_tester.hit_test_cached(LogicalPosition::new(100.0, 100.0), &no_scroll, &mut cache);
+    assert_eq!((cache.hits, cache.misses), (1, 2));
+    assert!(!moved.contains(&(DomId::ROOT_ID, NodeId::new(1))));
+}
+
+#[test]
+fn test_hit_test_cache_invalidated_by_relayout_and_scroll() {
+    let dom = div("outer");
+    let window = layout_dom(dom, ".outer { width: 200px; height: 200px; }", 800.0, 600.0);
+
+    let mut hit_tester = CpuHitTester::new();
+    hit_tester.rebuild_from_layout(&window.layout_results);
+
+    let mut cache = HitTestCache::new();
+    let cursor = LogicalPosition::new(10.0, 10.0);
+    let no_scroll = BTreeMap::new();
+    let _ = hit_tester.hit_test_cached(cursor, &no_scroll, &mut cache);
+
+    let generation = hit_tester.generation();
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    assert_eq!(hit_tester.generation(), generation + 1);
+    let _ = hit_tester.hit_test_cached(cursor, &no_scroll, &mut cache);
+    assert_eq!((cache.hits, cache.misses), (0, 2));
+
+    let mut scrolled = BTreeMap::new();
+    scrolled.insert(
+        (DomId::ROOT_ID, NodeId::new(0)),
+        LogicalPosition::new(0.0, 5.0),
+    );
+    let _ = hit_tester.hit_test_cached(cursor, &scrolled, &mut cache);
+    assert_eq!((cache.hits, cache.misses), (0, 3));
+}
+
+#[test]
+fn test_rounded_corners_are_not_hit() {
+    // root (0) -> circle (1) at (0, 0), 100x100 with fully rounded corners
+    let dom = div("root").with_child(div("circle"));
+    let css = ".circle { width: 100px; height: 100px; border-radius: 50px; }";
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let mut hit_tester = CpuHitTester::new();
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    let circle = (DomId::ROOT_ID, NodeId::new(1));
+
+    assert!(hit_tester
+        .hit_test(LogicalPosition::new(50.0, 50.0))
+        .contains(&circle));
+    // Just inside the curve at the top edge
+    assert!(hit_tester
+        .hit_test(LogicalPosition::new(50.0, 1.0))
+        .contains(&circle));
+
+    for corner in [(2.0, 2.0), (98.0, 2.0), (2.0, 98.0), (98.0, 98.0)] {
+        let hits = hit_tester.hit_test(LogicalPosition::new(corner.0, corner.1));
+        assert!(!hits.contains(&circle), "corner {:?} should miss", corner);
+    }
+}
+
+#[test]
+fn test_display_none_flex_child_is_removed_from_layout() {
+    // row (0) -> a (1), b (2), c (3), each 100px wide
+    let dom = || {
+        div("row")
+            .with_child(div("item"))
+            .with_child(
+                div("item").with_ids_and_classes(
+                    vec![
+                        IdOrClass::Class("item".to_string().into()),
+                        IdOrClass::Class("hidden".to_string().into()),
+                    ]
+                    .into(),
+                ),
+            )
+            .with_child(div("item"))
+    };
+    let css = ".row { display: flex; flex-direction: row; } \
+               .item { width: 100px; height: 50px; flex-shrink: 0; }";
+    let b = (DomId::ROOT_ID, NodeId::new(2));
+    let c = (DomId::ROOT_ID, NodeId::new(3));
+
+    let window = layout_dom(dom(), css, 800.0, 600.0);
+    let mut hit_tester = CpuHitTester::new();
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    assert!(hit_tester
+        .hit_test(LogicalPosition::new(150.0, 25.0))
+        .contains(&b));
+
+    let window = layout_dom(
+        dom(),
+        &format!("{css} .hidden {{ display: none; }}"),
+        800.0,
+        600.0,
+    );
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    // c moves into b's slot, b itself is nowhere to be hit
+    let hits = hit_tester.hit_test(LogicalPosition::new(150.0, 25.0));
+    assert!(hits.contains(&c));
+    assert!(!hits.contains(&b));
+    assert!(!hit_tester
+        .hit_test(LogicalPosition::new(250.0, 25.0))
+        .contains(&c));
+}
+
+#[test]
+fn test_rotated_node_is_hit_where_it_is_painted() {
+    // root (0) -> diamond (1) at (0, 0), a 100x100 square rotated around its center
+    let dom = div("root").with_child(div("diamond"));
+    let css = ".diamond { width: 100px; height: 100px; \
+               transform: rotate(45deg); transform-origin: 50px 50px; }";
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let diamond = (DomId::ROOT_ID, NodeId::new(1));
+
+    let mut gpu_caches = BTreeMap::new();
+    let mut gpu_cache = GpuValueCache::empty();
+    let changes = gpu_cache.synchronize(&window.layout_results[&DomId::ROOT_ID].styled_dom);
+    assert_eq!(changes.transform_key_changes.len(), 1);
+    gpu_caches.insert(DomId::ROOT_ID, gpu_cache);
+
+    let mut hit_tester = CpuHitTester::new();
+    let corner = LogicalPosition::new(3.0, 3.0);
+    let center = LogicalPosition::new(50.0, 50.0);
+
+    // Without transforms the corner of the layout box is hit
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    assert!(hit_tester.hit_test(corner).contains(&diamond));
+
+    hit_tester.rebuild_from_layout_with_transforms(&window.layout_results, &gpu_caches);
+    assert!(hit_tester.hit_test(center).contains(&diamond));
+    assert!(!hit_tester.hit_test(corner).contains(&diamond));
+    // The rotated tips stick out of the layout box
+    assert!(hit_tester
+        .hit_test(LogicalPosition::new(50.0, -15.0))
+        .contains(&diamond));
+}
+
+#[test]
+fn test_topmost_hit_follows_paint_order() {
+    // root (0) -> a (1) at (0, 0), b (2) at (50, 50), both 100x100 and overlapping
+    let dom = || div("root").with_child(div("a")).with_child(div("b"));
+    let css = ".root { position: relative; width: 400px; height: 400px; } \
+               .a { position: absolute; left: 0px; top: 0px; width: 100px; height: 100px; } \
+               .b { position: absolute; left: 50px; top: 50px; width: 100px; height: 100px; }";
+    let a = (DomId::ROOT_ID, NodeId::new(1));
+    let b = (DomId::ROOT_ID, NodeId::new(2));
+    let overlap = LogicalPosition::new(75.0, 75.0);
+
+    let mut hit_tester = CpuHitTester::new();
+
+    // Later sibling is painted on top
+    let window = layout_dom(dom(), css, 800.0, 600.0);
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    assert_eq!(hit_tester.hit_test_topmost(overlap), Some(b));
+    assert_eq!(
+        hit_tester.hit_test_topmost(LogicalPosition::new(25.0, 25.0)),
+        Some(a)
+    );
+    let hits = hit_tester.hit_test(overlap);
+    assert!(hits.contains(&a) && hits.contains(&b));
+
+    // Higher z-index wins regardless of tree order
+    let window = layout_dom(
+        dom(),
+        &format!("{css} .a {{ z-index: 2; }} .b {{ z-index: 1; }}"),
+        800.0,
+        600.0,
+    );
+    hit_tester.rebuild_from_layout(&window.layout_results);
+    assert_eq!(hit_tester.hit_test_topmost(overlap), Some(a));
+    assert_eq!(
+        hit_tester.hit_test_topmost(LogicalPosition::new(125.0, 125.0)),
+        Some(b)
+    );
+}
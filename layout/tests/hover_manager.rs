@@ -1,5 +1,12 @@
 //! Tests for hover state management
 
+use azul_core::{
+    dom::{DomId, DomNodeId, NodeId},
+    geom::LogicalPosition,
+    hit_test::{HitTest, HitTestItem},
+    styled_dom::NodeHierarchyItemId,
+    task::{Duration, Instant, SystemTick, SystemTickDiff},
+};
 use azul_layout::{
     hit_test::FullHitTest,
     managers::hover::{HoverManager, InputPointId},
@@ -86,3 +93,78 @@ fn test_gesture_history_check() {
     assert!(manager.has_sufficient_history_for_gestures(&mouse_id));
     assert!(manager.any_has_sufficient_history_for_gestures());
 }
+
+/// Mouse hit test over a single node of the root DOM
+fn hit_on(node_id: usize) -> FullHitTest {
+    let mut ht = HitTest::empty();
+    ht.regular_hit_test_nodes.insert(
+        NodeId::new(node_id),
+        HitTestItem {
+            point_in_viewport: LogicalPosition::zero(),
+            point_relative_to_item: LogicalPosition::zero(),
+            is_focusable: false,
+            is_virtual_view_hit: None,
+            hit_depth: 0,
+        },
+    );
+    let mut hit = FullHitTest::empty(None);
+    hit.hovered_nodes.insert(DomId::ROOT_ID, ht);
+    hit
+}
+
+fn dom_node(node_id: usize) -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(node_id))),
+    }
+}
+
+fn ticks(n: u64) -> Duration {
+    Duration::Tick(SystemTickDiff { tick_diff: n })
+}
+
+#[test]
+fn test_hover_duration_accumulates_and_resets() {
+    let mut manager = HoverManager::new();
+    let frame = |tick| Instant::Tick(SystemTick::new(tick));
+
+    // Cursor rests on node 1 for three frames
+    for tick in [10, 20, 30] {
+        manager.push_hit_test(InputPointId::Mouse, hit_on(1));
+        manager.update_hover_durations(frame(tick));
+    }
+    assert_eq!(manager.hovered_for(dom_node(1)), ticks(20));
+    assert_eq!(manager.hovered_for(dom_node(2)), ticks(0));
+
+    // Moving to node 2 resets node 1 and starts counting for node 2
+    manager.push_hit_test(InputPointId::Mouse, hit_on(2));
+    manager.update_hover_durations(frame(40));
+    assert_eq!(manager.hovered_for(dom_node(1)), ticks(0));
+    assert_eq!(manager.hovered_for(dom_node(2)), ticks(0));
+
+    manager.update_hover_durations(frame(55));
+    assert_eq!(manager.hovered_for(dom_node(2)), ticks(15));
+
+    // Coming back to node 1 starts from zero again
+    manager.push_hit_test(InputPointId::Mouse, hit_on(1));
+    manager.update_hover_durations(frame(60));
+    manager.update_hover_durations(frame(65));
+    assert_eq!(manager.hovered_for(dom_node(1)), ticks(5));
+}
+
+#[test]
+fn test_hovered_for_grows_across_ticks() {
+    let mut manager = HoverManager::new();
+    manager.push_hit_test(InputPointId::Mouse, hit_on(1));
+
+    manager.update_hover_durations(Instant::Tick(SystemTick::new(100)));
+    manager.update_hover_durations(Instant::Tick(SystemTick::new(110)));
+    let first = manager.hovered_for(dom_node(1));
+
+    manager.update_hover_durations(Instant::Tick(SystemTick::new(125)));
+    let second = manager.hovered_for(dom_node(1));
+
+    assert_eq!(first, ticks(10));
+    assert_eq!(second, ticks(25));
+    assert!(second.greater_than(&first));
+}
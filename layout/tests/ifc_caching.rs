@@ -347,6 +347,16 @@ fn test_cached_inline_layout_validity_check_unchanged() {
 
 /// Helper to run a full layout and return the layout tree for inspection
 fn layout_html_and_get_tree(html: &str) -> azul_layout::Solver3LayoutCache {
+    let mut text_cache = TextLayoutCache::new();
+    layout_html_with_text_cache(html, &mut text_cache)
+}
+
+/// Same as `layout_html_and_get_tree`, but keeps the text cache alive across
+/// layouts so that cache reuse can be observed
+fn layout_html_with_text_cache(
+    html: &str,
+    text_cache: &mut TextLayoutCache,
+) -> azul_layout::Solver3LayoutCache {
     let styled_dom = Dom::from_xml_string(html);
     let fc_cache = build_font_cache();
     let mut font_manager = FontManager::new(fc_cache).expect("Failed to create font manager");
@@ -360,7 +370,6 @@ fn layout_html_and_get_tree(html: &str) -> azul_layout::Solver3LayoutCache {
         float_cache: HashMap::new(),
         cache_map: Default::default(),
     };
-    let mut text_cache = TextLayoutCache::new();
 
     let content_size = LogicalSize::new(800.0, 600.0);
     let fragmentation_context = FragmentationContext::new_paged(content_size);
@@ -376,7 +385,7 @@ fn layout_html_and_get_tree(html: &str) -> azul_layout::Solver3LayoutCache {
 
     let _ = layout_document_paged_with_config(
         &mut layout_cache,
-        &mut text_cache,
+        text_cache,
         fragmentation_context,
         &styled_dom,
         viewport,
@@ -615,3 +624,58 @@ fn test_ifc_layout_replace_preserves_metrics_structure() {
     // same width → false
     assert!(!cached.should_replace_with(AvailableSpace::Definite(800.0), false));
 }
+
+#[test]
+fn test_letter_spacing_change_reuses_shaped_glyphs() {
+    let html_for = |spacing: &str| {
+        format!(
+            r#"
+            <html>
+                <body>
+                    <p style="width: 120px; height: 200px; letter-spacing: {};">Hello World this wraps</p>
+                </body>
+            </html>
+            "#,
+            spacing
+        )
+    };
+
+    let mut text_cache = TextLayoutCache::new();
+
+    let first = layout_html_with_text_cache(&html_for("0px"), &mut text_cache);
+    let shaping_runs = text_cache.get_shaping_runs();
+    assert!(shaping_runs > 0, "initial layout must shape the text");
+
+    let second = layout_html_with_text_cache(&html_for("8px"), &mut text_cache);
+    assert_eq!(
+        text_cache.get_shaping_runs(),
+        shaping_runs,
+        "changing only letter-spacing should not re-shape the text"
+    );
+
+    let text_metrics = |cache: &azul_layout::Solver3LayoutCache| {
+        let tree = cache.tree.as_ref().expect("Layout tree should exist");
+        tree.nodes
+            .iter()
+            .filter_map(|n| n.inline_layout_result.as_ref())
+            .find(|c| !c.item_metrics.is_empty())
+            .expect("Should have an IFC with text")
+            .item_metrics
+            .clone()
+    };
+
+    let before = text_metrics(&first);
+    let after = text_metrics(&second);
+
+    // Positions must reflect the new spacing...
+    assert!(
+        after[1].x_offset > before[1].x_offset,
+        "second item should move right: {} -> {}",
+        before[1].x_offset,
+        after[1].x_offset
+    );
+
+    // ...and the wider text has to wrap onto more lines
+    let max_line = |m: &[InlineItemMetrics]| m.iter().map(|m| m.line_index).max().unwrap_or(0);
+    assert!(max_line(&after) > max_line(&before));
+}
@@ -0,0 +1,104 @@
+//! Tests for exposing physical scancodes next to virtual keycodes in callbacks
+
+use std::sync::Arc;
+
+use azul_core::{
+    callbacks::Update,
+    dom::Dom,
+    geom::LogicalSize,
+    gl::OptionGlContextPtr,
+    refany::{OptionRefAny, RefAny},
+    resources::RendererResources,
+    styled_dom::StyledDom,
+    window::{KeyboardState, RawWindowHandle, VirtualKeyCode},
+};
+use azul_css::{system::SystemStyle, OptionU32};
+use azul_layout::{
+    callbacks::{Callback, CallbackInfo, ExternalSystemCallbacks},
+    window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+/// Scancode of the key right of Q on a PC keyboard (W on QWERTY, Z on AZERTY)
+const SCANCODE_W_POSITION: u32 = 0x11;
+
+#[derive(Default)]
+struct PressedKey {
+    virtual_keycode: Option<VirtualKeyCode>,
+    scancode: Option<u32>,
+}
+
+extern "C" fn on_key_down(mut data: RefAny, info: CallbackInfo) -> Update {
+    if let Some(mut pressed) = data.downcast_mut::<PressedKey>() {
+        pressed.virtual_keycode = info.get_current_virtual_keycode().into_option();
+        pressed.scancode = info.get_current_scancode().into_option();
+    }
+    Update::DoNothing
+}
+
+/// Runs `on_key_down` with the given key pressed
+fn press(virtual_keycode: VirtualKeyCode, scancode: u32) -> PressedKey {
+    let mut dom = Dom::create_div();
+    let (css, _) = azul_css::parser2::new_from_str("");
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+    window_state.keyboard_state = KeyboardState {
+        current_virtual_keycode: Some(virtual_keycode).into(),
+        current_scancode: OptionU32::Some(scancode),
+        pressed_virtual_keycodes: vec![virtual_keycode].into(),
+        pressed_scancodes: vec![scancode].into(),
+        ..Default::default()
+    };
+
+    let renderer_resources = RendererResources::default();
+    let system_callbacks = ExternalSystemCallbacks::rust_internal();
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &renderer_resources,
+            &system_callbacks,
+            &mut None,
+        )
+        .unwrap();
+
+    let mut callback = Callback {
+        cb: on_key_down,
+        ctx: OptionRefAny::None,
+    };
+    let mut data = RefAny::new(PressedKey::default());
+    window.invoke_single_callback(
+        &mut callback,
+        &mut data,
+        &RawWindowHandle::Unsupported,
+        &OptionGlContextPtr::None,
+        Arc::new(SystemStyle::default()),
+        &system_callbacks,
+        &None,
+        &window_state,
+        &renderer_resources,
+    );
+
+    let pressed = data.downcast_ref::<PressedKey>().unwrap();
+    PressedKey {
+        virtual_keycode: pressed.virtual_keycode,
+        scancode: pressed.scancode,
+    }
+}
+
+#[test]
+fn test_key_press_exposes_virtual_keycode_and_scancode() {
+    let qwerty = press(VirtualKeyCode::W, SCANCODE_W_POSITION);
+    assert_eq!(qwerty.virtual_keycode, Some(VirtualKeyCode::W));
+    assert_eq!(qwerty.scancode, Some(SCANCODE_W_POSITION));
+
+    // Same physical key after switching to AZERTY
+    let azerty = press(VirtualKeyCode::Z, SCANCODE_W_POSITION);
+    assert_eq!(azerty.virtual_keycode, Some(VirtualKeyCode::Z));
+    assert_ne!(azerty.virtual_keycode, qwerty.virtual_keycode);
+    assert_eq!(azerty.scancode, qwerty.scancode);
+}
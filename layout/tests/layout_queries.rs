@@ -0,0 +1,906 @@
+//! Layout Query Tests
+//!
+//! Tests for the read-only queries that `LayoutWindow` exposes on top of a
+//! finished layout (offsets, rects, tree walks, ...).
+
+use azul_core::{
+    dom::{Dom, DomId, DomNodeId, IdOrClass, NodeId},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    resources::RendererResources,
+    selection::{CursorAffinity, SelectionRange, TextCursor},
+    styled_dom::StyledDom,
+};
+use azul_layout::{
+    callbacks::ExternalSystemCallbacks, window::LayoutWindow, window_state::FullWindowState,
+};
+
+mod common;
+
+use common::{div, layout_dom, node};
+
+/// Concatenated cluster text of each line of a text node, in line order
+fn line_texts(window: &LayoutWindow, node_id: DomNodeId) -> Vec<String> {
+    let nid = node_id.node.into_crate_internal().unwrap();
+    let layout = window.get_inline_layout_for_node(node_id.dom, nid).unwrap();
+    let mut lines = std::collections::BTreeMap::<usize, String>::new();
+    for item in layout.items.iter() {
+        if let Some(cluster) = item.item.as_cluster() {
+            if cluster.source_node_id == Some(nid) {
+                lines.entry(item.line_index).or_default().push_str(&cluster.text);
+            }
+        }
+    }
+    lines.into_values().collect()
+}
+
+fn assert_pos_eq(actual: LogicalPosition, expected: LogicalPosition) {
+    assert!(
+        (actual.x - expected.x).abs() < 0.5 && (actual.y - expected.y).abs() < 0.5,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_accumulated_offset_sums_nested_offsets() {
+    // outer (0) -> mid (1) -> inner (2)
+    let dom = div("outer").with_child(div("mid").with_child(div("inner")));
+    let css = r#"
+        .outer { width: 300px; height: 300px; padding: 10px; }
+        .mid { position: relative; padding: 5px; }
+        .inner { position: relative; height: 20px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let outer_pos = window.get_node_position(node(0)).unwrap();
+    let mid_pos = window.get_node_position(node(1)).unwrap();
+    let inner_pos = window.get_node_position(node(2)).unwrap();
+
+    let offset = window.get_node_accumulated_offset(node(2)).unwrap();
+
+    // The accumulated offset is the sum of every parent -> child step
+    let summed = LogicalPosition::new(
+        (inner_pos.x - mid_pos.x) + (mid_pos.x - outer_pos.x),
+        (inner_pos.y - mid_pos.y) + (mid_pos.y - outer_pos.y),
+    );
+    assert_pos_eq(offset, summed);
+    // 10px outer padding + 5px mid padding
+    assert_pos_eq(offset, LogicalPosition::new(15.0, 15.0));
+}
+
+#[test]
+fn test_accumulated_offset_resets_at_absolute_ancestor() {
+    // outer (0) -> abs (1) -> inner (2), both absolutely positioned
+    let dom = div("outer").with_child(div("abs").with_child(div("inner")));
+    let css = r#"
+        .outer { position: relative; width: 300px; height: 300px; padding: 10px; }
+        .abs { position: absolute; left: 30px; top: 40px; width: 100px; height: 100px; }
+        .inner { position: absolute; left: 5px; top: 7px; width: 10px; height: 10px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let abs_pos = window.get_node_position(node(1)).unwrap();
+    let inner_pos = window.get_node_position(node(2)).unwrap();
+    let offset = window.get_node_accumulated_offset(node(2)).unwrap();
+
+    // The absolute ancestor is the new accumulation base, the outer
+    // padding does not show up in the offset
+    assert_pos_eq(
+        offset,
+        LogicalPosition::new(inner_pos.x - abs_pos.x, inner_pos.y - abs_pos.y),
+    );
+    assert_pos_eq(offset, LogicalPosition::new(5.0, 7.0));
+}
+
+#[test]
+fn test_text_line_count_wrapped_text() {
+    // p (0) -> text (1)
+    let dom = div("p").with_child(Dom::create_text("aaa bbb ccc"));
+    // Wide enough for any single word, too narrow for two of them
+    let css = ".p { width: 45px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    assert_eq!(window.get_text_line_count(node(1)), Some(3));
+    let lines = line_texts(&window, node(1));
+    let words: Vec<&str> = lines.iter().map(|l| l.trim()).collect();
+    assert_eq!(words, ["aaa", "bbb", "ccc"]);
+}
+
+#[test]
+fn test_text_line_count_is_none_for_div() {
+    let dom = div("p").with_child(Dom::create_text("aaa bbb ccc"));
+    let css = ".p { width: 10px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    assert_eq!(window.get_text_line_count(node(0)), None);
+}
+
+const WHITE_SPACE_TEXT: &str = "a   b\nc  d";
+
+#[test]
+fn test_white_space_pre_breaks_only_on_newlines() {
+    let dom = div("p").with_child(Dom::create_text(WHITE_SPACE_TEXT));
+    // Far too narrow for either line, but `pre` never wraps
+    let css = ".p { white-space: pre; width: 10px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    assert_eq!(window.get_text_line_count(node(1)), Some(2));
+    let lines = line_texts(&window, node(1));
+    assert!(lines[0].contains("a   b"), "spaces collapsed: {:?}", lines);
+    assert!(lines[1].contains("c  d"), "spaces collapsed: {:?}", lines);
+}
+
+#[test]
+fn test_white_space_pre_wrap_breaks_on_newlines_and_width() {
+    let dom = div("p").with_child(Dom::create_text(WHITE_SPACE_TEXT));
+    let css = ".p { white-space: pre-wrap; width: 400px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    // Wide enough: only the explicit newline breaks
+    assert_eq!(window.get_text_line_count(node(1)), Some(2));
+    let lines = line_texts(&window, node(1));
+    assert!(lines[0].contains("a   b"), "spaces collapsed: {:?}", lines);
+
+    let dom = div("p").with_child(Dom::create_text(WHITE_SPACE_TEXT));
+    let css = ".p { white-space: pre-wrap; width: 10px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    // Too narrow: each line additionally wraps at the width
+    assert!(window.get_text_line_count(node(1)).unwrap() > 2);
+}
+
+#[test]
+fn test_white_space_normal_collapses_and_ignores_newlines() {
+    let dom = div("p").with_child(Dom::create_text(WHITE_SPACE_TEXT));
+    let css = ".p { width: 400px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    assert_eq!(window.get_text_line_count(node(1)), Some(1));
+    let lines = line_texts(&window, node(1));
+    assert!(!lines[0].contains("  "), "spaces preserved: {:?}", lines);
+}
+
+#[test]
+fn test_percentage_height_in_auto_height_parent_behaves_as_auto() {
+    // outer (0, height: auto) -> half (1, height: 50%) -> content (2, 40px)
+    let dom = div("outer").with_child(div("half").with_child(div("content")));
+    let css = r#"
+        .outer { width: 300px; }
+        .half { height: 50%; }
+        .content { height: 40px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    // The basis is indefinite, so `50%` falls back to the content height
+    let half = window.get_node_size(node(1)).unwrap();
+    assert!((half.height - 40.0).abs() < 0.5, "got {:?}", half);
+
+    // Same tree with a definite parent height resolves the percentage
+    let dom = div("outer").with_child(div("half").with_child(div("content")));
+    let css = r#"
+        .outer { width: 300px; height: 200px; }
+        .half { height: 50%; }
+        .content { height: 40px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let half = window.get_node_size(node(1)).unwrap();
+    assert!((half.height - 100.0).abs() < 0.5, "got {:?}", half);
+}
+
+/// Selects every cluster of the text node, from the leading edge of the first
+/// to the trailing edge of the last.
+fn select_all(window: &LayoutWindow, node_id: DomNodeId) -> (String, usize) {
+    let nid = node_id.node.into_crate_internal().unwrap();
+    let layout = window.get_inline_layout_for_node(node_id.dom, nid).unwrap();
+    let clusters = layout
+        .items
+        .iter()
+        .filter_map(|i| i.item.as_cluster())
+        .collect::<Vec<_>>();
+    let range = SelectionRange {
+        start: TextCursor {
+            cluster_id: clusters.first().unwrap().source_cluster_id,
+            affinity: CursorAffinity::Leading,
+        },
+        end: TextCursor {
+            cluster_id: clusters.last().unwrap().source_cluster_id,
+            affinity: CursorAffinity::Trailing,
+        },
+    };
+    let line_count = line_texts(window, node_id).len();
+    (layout.selected_text(&range), line_count)
+}
+
+#[test]
+fn test_selected_text_joins_soft_wrapped_lines() {
+    let dom = div("p").with_child(Dom::create_text("alpha beta gamma"));
+    let css = ".p { width: 10px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let (text, line_count) = select_all(&window, node(1));
+    assert!(line_count > 1, "text did not wrap");
+    assert!(!text.contains('\n'), "soft wrap produced a newline: {:?}", text);
+    assert!(text.starts_with("alpha"), "{:?}", text);
+    assert!(text.ends_with("gamma"), "{:?}", text);
+}
+
+#[test]
+fn test_selected_text_keeps_hard_breaks() {
+    let dom = div("p").with_child(Dom::create_text("alpha\nbeta"));
+    let css = ".p { white-space: pre; width: 400px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let (text, line_count) = select_all(&window, node(1));
+    assert_eq!(line_count, 2);
+    assert_eq!(text, "alpha\nbeta");
+}
+
+#[test]
+fn test_bounds_of_word() {
+    let dom = div("p").with_child(Dom::create_text("alpha beta gamma"));
+    let css = ".p { width: 400px; font-size: 16px; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let layout = window
+        .get_inline_layout_for_node(DomId::ROOT_ID, NodeId::new(1))
+        .unwrap();
+
+    let first = layout.bounds_of_word(0).unwrap();
+    let second = layout.bounds_of_word(1).unwrap();
+    let third = layout.bounds_of_word(2).unwrap();
+
+    assert!(second.size.width > 0.0 && second.size.height > 0.0);
+    // "beta" sits between the other two words, separated by spaces
+    assert!(second.origin.x > first.origin.x + first.size.width);
+    assert!(third.origin.x > second.origin.x + second.size.width);
+    assert_eq!(second.origin.y, first.origin.y);
+
+    assert_eq!(layout.bounds_of_word(3), None);
+}
+
+#[test]
+fn test_stacking_context_root() {
+    // root (0) -> [faded (1) -> inner (2), plain (3)]
+    let dom = div("root")
+        .with_child(div("faded").with_child(div("inner")))
+        .with_child(div("plain"));
+    let css = r#"
+        .faded { opacity: 0.5; height: 20px; }
+        .inner { height: 10px; }
+        .plain { height: 10px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    assert_eq!(window.get_stacking_context_root(node(2)), Some(node(1)));
+    assert_eq!(window.get_stacking_context_root(node(1)), Some(node(1)));
+    assert_eq!(window.get_stacking_context_root(node(3)), Some(node(0)));
+    assert_eq!(window.get_stacking_context_root(node(0)), Some(node(0)));
+}
+
+#[test]
+fn test_scrollable_nodes_lists_all_scroll_containers() {
+    // root (0) -> [first (1) -> tall (2), second (3) -> tall (4), plain (5)]
+    let dom = div("root")
+        .with_child(div("first").with_child(div("tall")))
+        .with_child(div("second").with_child(div("tall")))
+        .with_child(div("plain"));
+    let css = r#"
+        .first, .second { overflow: scroll; width: 100px; height: 100px; }
+        .tall { height: 500px; }
+        .plain { height: 10px; }
+    "#;
+
+    let mut window = layout_dom(dom, css, 800.0, 600.0);
+
+    // Scroll the second container
+    let now = azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0));
+    let container = azul_core::geom::LogicalRect::new(
+        LogicalPosition::zero(),
+        LogicalSize::new(100.0, 100.0),
+    );
+    let content = azul_core::geom::LogicalRect::new(
+        LogicalPosition::zero(),
+        LogicalSize::new(100.0, 500.0),
+    );
+    let second = NodeId::new(3);
+    window
+        .scroll_manager
+        .update_node_bounds(DomId::ROOT_ID, second, container, content, now.clone());
+    window.scroll_manager.set_scroll_position(
+        DomId::ROOT_ID,
+        second,
+        LogicalPosition::new(0.0, 40.0),
+        now,
+    );
+
+    let nodes = window.get_scrollable_nodes();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].node_id, node(1));
+    assert_eq!(nodes[1].node_id, node(3));
+
+    let layout_result = &window.layout_results[&DomId::ROOT_ID];
+    for info in nodes.iter() {
+        assert_eq!(
+            layout_result.scroll_id_to_node_id.get(&info.scroll_id).copied(),
+            info.node_id.node.into_crate_internal()
+        );
+        assert!(info.content_size.height > info.container_rect.size.height);
+    }
+
+    assert_eq!(nodes[0].current_offset, LogicalPosition::zero());
+    assert_eq!(nodes[1].current_offset, LogicalPosition::new(0.0, 40.0));
+}
+
+#[test]
+fn test_positioned_rectangle_clip_rect_per_axis() {
+    use azul_core::ui_solver::ResolvedOffsets;
+    use azul_css::props::layout::LayoutOverflow::{self, Hidden, Visible};
+    use azul_layout::solver3::geometry::PositionedRectangle;
+
+    // 100x50 border box at (10, 20) with a 5px border
+    let rect = |overflow_x: LayoutOverflow, overflow_y: LayoutOverflow| PositionedRectangle {
+        bounds: LogicalRect::new(
+            LogicalPosition::new(10.0, 20.0),
+            LogicalSize::new(100.0, 50.0),
+        ),
+        margin: Default::default(),
+        border: ResolvedOffsets {
+            top: 5.0,
+            left: 5.0,
+            right: 5.0,
+            bottom: 5.0,
+        },
+        padding: Default::default(),
+        border_radius: Default::default(),
+        overflow_x,
+        overflow_y,
+    };
+    // Does the clip rect cut off content at these coordinates on each axis?
+    let clips_x = |clip: &LogicalRect| clip.origin.x > -1.0e6 && clip.size.width < 1.0e6;
+    let clips_y = |clip: &LogicalRect| clip.origin.y > -1.0e6 && clip.size.height < 1.0e6;
+
+    assert_eq!(rect(Visible, Visible).get_clip_rect(), None);
+
+    let both = rect(Hidden, Hidden).get_clip_rect().unwrap();
+    assert_eq!(
+        both,
+        LogicalRect::new(LogicalPosition::new(15.0, 25.0), LogicalSize::new(90.0, 40.0))
+    );
+
+    let x_only = rect(Hidden, Visible).get_clip_rect().unwrap();
+    assert!(clips_x(&x_only) && !clips_y(&x_only));
+    assert_eq!((x_only.origin.x, x_only.size.width), (15.0, 90.0));
+
+    let y_only = rect(Visible, Hidden).get_clip_rect().unwrap();
+    assert!(!clips_x(&y_only) && clips_y(&y_only));
+    assert_eq!((y_only.origin.y, y_only.size.height), (25.0, 40.0));
+}
+
+#[test]
+fn test_layout_node_has_visible_content() {
+    // root (0) -> [empty (1), empty-with-text (2) -> text (3), normal (4)]
+    let dom = div("root")
+        .with_child(div("zero"))
+        .with_child(div("narrow").with_child(Dom::create_text("overflowing text")))
+        .with_child(div("normal"));
+    let css = r#"
+        .zero { width: 0px; height: 0px; }
+        .narrow { width: 0px; }
+        .normal { width: 50px; height: 50px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let tree = &window.layout_results[&DomId::ROOT_ID].layout_tree;
+    let index = |id: usize| tree.dom_to_layout[&NodeId::new(id)][0];
+
+    assert!(tree.nodes[index(1)].is_empty());
+    assert!(!tree.has_visible_content(index(1)));
+
+    // The text is laid out in the IFC of the zero-width div
+    assert!(tree.nodes[index(2)].is_empty());
+    assert!(tree.has_visible_content(index(2)));
+    assert!(tree.has_visible_content(index(3)));
+
+    assert!(!tree.nodes[index(4)].is_empty());
+    assert!(tree.has_visible_content(index(4)));
+}
+
+#[test]
+fn test_focusable_nodes_in_tab_order() {
+    use azul_core::{
+        a11y::{AccessibilityInfo, AccessibilityRole, AccessibilityState},
+        dom::{OptionDomNodeId, TabIndex},
+        window::OptionVirtualKeyCodeCombo,
+    };
+    use azul_css::OptionString;
+
+    let disabled = AccessibilityInfo {
+        accessibility_name: OptionString::None,
+        accessibility_value: OptionString::None,
+        accelerator: OptionVirtualKeyCodeCombo::None,
+        default_action: OptionString::None,
+        states: vec![AccessibilityState::Unavailable].into(),
+        supported_actions: Vec::new().into(),
+        labelled_by: OptionDomNodeId::None,
+        described_by: OptionDomNodeId::None,
+        role: AccessibilityRole::PushButton,
+        is_live_region: false,
+    };
+
+    // root (0) -> [zero (1), two (2), one (3), unset (4), no-keyboard (5), disabled (6), hidden (7)]
+    let dom = div("root")
+        .with_child(div("b").with_tab_index(TabIndex::Auto))
+        .with_child(div("b").with_tab_index(TabIndex::OverrideInParent(2)))
+        .with_child(div("b").with_tab_index(TabIndex::OverrideInParent(1)))
+        .with_child(div("b"))
+        .with_child(div("b").with_tab_index(TabIndex::NoKeyboardFocus))
+        .with_child(
+            div("b")
+                .with_tab_index(TabIndex::Auto)
+                .with_accessibility_info(disabled),
+        )
+        .with_child(div("hidden").with_tab_index(TabIndex::Auto));
+    let css = ".b { height: 10px; } .hidden { display: none; }";
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let focusable = window.layout_results[&DomId::ROOT_ID].focusable_nodes();
+
+    assert_eq!(
+        focusable,
+        vec![(NodeId::new(3), 1), (NodeId::new(2), 2), (NodeId::new(1), 0)]
+    );
+}
+
+#[test]
+fn test_print_tree_snapshot() {
+    // outer (0) -> [a (1) -> inner (2), b (3)]
+    let dom = div("outer")
+        .with_child(div("a").with_child(div("inner")))
+        .with_child(div("b"));
+    let css = r#"
+        .outer { width: 100px; height: 50px; }
+        .a { height: 20px; }
+        .inner { width: 30px; height: 10px; }
+        .b { height: 30px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let tree = window.layout_results[&DomId::ROOT_ID].print_tree();
+
+    let expected = "\
+div [x:0 y:0 w:100 h:50]
+├─ div [x:0 y:0 w:100 h:20]
+│ └─ div [x:0 y:0 w:30 h:10]
+└─ div [x:0 y:20 w:100 h:30]
+";
+    assert_eq!(tree, expected);
+
+    // Deterministic across runs
+    assert_eq!(window.layout_results[&DomId::ROOT_ID].print_tree(), tree);
+}
+
+#[test]
+fn test_accessibility_tree_button_label() {
+    use azul_core::a11y::AccessibilityRole;
+
+    // root (0) -> deco (1) -> button (2) -> "OK" (3)
+    let button = Dom::create_button("OK".into())
+        .with_ids_and_classes(vec![IdOrClass::Class("btn".to_string().into())].into());
+    let dom = div("root").with_child(div("deco").with_child(button));
+    let css = r#"
+        .root { width: 200px; height: 100px; }
+        .deco { padding: 10px; }
+        .btn { display: block; box-sizing: border-box; width: 80px; height: 30px; margin: 0; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let tree = window.layout_results[&DomId::ROOT_ID].accessibility_tree();
+
+    // The decorative wrapper is skipped, the button hangs off the root
+    assert_eq!(tree.node_id, NodeId::new(0));
+    assert_eq!(tree.children.len(), 1);
+
+    let button = &tree.children[0];
+    assert_eq!(button.node_id, NodeId::new(2));
+    assert_eq!(button.role, AccessibilityRole::PushButton);
+    assert_eq!(button.label.as_deref(), Some("OK"));
+    assert_pos_eq(button.bounds.origin, LogicalPosition::new(10.0, 10.0));
+    assert_eq!(button.bounds.size, LogicalSize::new(80.0, 30.0));
+    // The label text is not repeated as a separate child
+    assert!(button.children.is_empty());
+}
+
+#[test]
+fn test_button_baseline_matches_its_text_line() {
+    // root (0) -> button (1) -> "OK" (2)
+    let button = Dom::create_button("OK".into())
+        .with_ids_and_classes(vec![IdOrClass::Class("btn".to_string().into())].into());
+    let dom = div("root").with_child(button);
+    let css = r#"
+        .root { width: 200px; height: 100px; }
+        .btn { display: block; width: 80px; padding: 5px; border: 2px solid black; margin: 0; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let result = &window.layout_results[&DomId::ROOT_ID];
+    let button = NodeId::new(1);
+    let text = NodeId::new(2);
+
+    let line = window
+        .get_inline_layout_for_node(DomId::ROOT_ID, text)
+        .unwrap()
+        .first_line_baseline()
+        .unwrap();
+    let button_top = window.get_node_position(node(1)).unwrap().y;
+    // border-top + padding-top
+    let expected = button_top + 7.0 + line;
+
+    let baseline = result.first_baseline(button).unwrap();
+    assert!((baseline - expected).abs() < 0.5, "expected {expected}, got {baseline}");
+    assert_eq!(result.last_baseline(button), Some(baseline));
+    assert_eq!(result.first_baseline(text), Some(baseline));
+    // The root synthesizes its baseline from the button
+    assert_eq!(result.first_baseline(NodeId::new(0)), Some(baseline));
+}
+
+#[test]
+fn test_effective_opacity_multiplies_ancestors() {
+    // root (0) -> half (1) -> half (2) -> leaf (3)
+    //          -> hidden (4) -> leaf (5)
+    let dom = div("root")
+        .with_child(div("half").with_child(div("half").with_child(div("leaf"))))
+        .with_child(div("hidden").with_child(div("leaf")));
+    let css = r#"
+        .half { opacity: 0.5; }
+        .hidden { opacity: 0; }
+        .leaf { height: 10px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let result = &window.layout_results[&DomId::ROOT_ID];
+
+    assert_eq!(result.effective_opacity(NodeId::new(0)), 1.0);
+    assert_eq!(result.effective_opacity(NodeId::new(1)), 0.5);
+    assert_eq!(result.effective_opacity(NodeId::new(3)), 0.25);
+    assert_eq!(result.effective_opacity(NodeId::new(5)), 0.0);
+}
+
+#[test]
+fn test_diff_scroll_nodes_between_relayouts() {
+    let css = r#"
+        .a, .b, .c { overflow: scroll; width: 100px; height: 50px; }
+        .content { height: 200px; }
+    "#;
+    let scroller = |class: &str| div(class).with_child(div("content"));
+    // Scroll IDs of a layout, by DOM node
+    let scroll_id_of = |window: &LayoutWindow, node_id: usize| {
+        let result = &window.layout_results[&DomId::ROOT_ID];
+        *result
+            .scroll_id_to_node_id
+            .iter()
+            .find(|(_, nid)| **nid == NodeId::new(node_id))
+            .unwrap()
+            .0
+    };
+
+    // root (0) -> [a (1) -> content (2), b (3) -> content (4)]
+    let before = layout_dom(
+        div("root").with_child(scroller("a")).with_child(scroller("b")),
+        css,
+        800.0,
+        600.0,
+    );
+    // root (0) -> [b (1) -> content (2), c (3) -> content (4)]
+    let after = layout_dom(
+        div("root").with_child(scroller("b")).with_child(scroller("c")),
+        css,
+        800.0,
+        600.0,
+    );
+
+    let diff = after.layout_results[&DomId::ROOT_ID]
+        .diff_scroll_nodes(&before.layout_results[&DomId::ROOT_ID]);
+
+    let a = scroll_id_of(&before, 1);
+    let b = scroll_id_of(&before, 3);
+    let c = scroll_id_of(&after, 3);
+    assert_eq!(scroll_id_of(&after, 1), b);
+
+    assert_eq!(diff.added.iter().copied().collect::<Vec<_>>(), vec![c]);
+    assert_eq!(diff.removed.iter().copied().collect::<Vec<_>>(), vec![a]);
+    // b moved up from y = 50 to y = 0
+    assert_eq!(diff.changed.iter().copied().collect::<Vec<_>>(), vec![b]);
+}
+
+#[test]
+fn test_virtual_views_to_relayout_lists_resized_hosts_only() {
+    use azul_core::dom::NodeType;
+
+    let view = |class: &str| {
+        Dom::create_node(NodeType::VirtualView)
+            .with_ids_and_classes(vec![IdOrClass::Class(class.to_string().into())].into())
+    };
+    // root (0) -> [view (1), view (2), plain (3)]
+    let dom = || {
+        div("root")
+            .with_child(view("resized"))
+            .with_child(view("fixed"))
+            .with_child(div("plain"))
+    };
+
+    let before = layout_dom(
+        dom(),
+        ".resized, .fixed, .plain { width: 100px; height: 50px; }",
+        800.0,
+        600.0,
+    );
+    let after = layout_dom(
+        dom(),
+        ".resized, .plain { width: 200px; height: 80px; } .fixed { width: 100px; height: 50px; }",
+        800.0,
+        600.0,
+    );
+
+    let to_relayout = after.layout_results[&DomId::ROOT_ID]
+        .get_virtual_views_to_relayout(&before.layout_results[&DomId::ROOT_ID]);
+    assert_eq!(to_relayout, vec![NodeId::new(1)]);
+}
+
+#[test]
+fn test_gl_textures_to_redraw_lists_resized_callback_images_only() {
+    use azul_core::{refany::RefAny, resources::ImageRef};
+
+    // root (0) -> [gl (1), text (2) -> "..." (3)]
+    let dom = || {
+        div("root")
+            .with_child(
+                Dom::create_image(ImageRef::callback(0usize, RefAny::new(())))
+                    .with_ids_and_classes(vec![IdOrClass::Class("gl".to_string().into())].into()),
+            )
+            .with_child(div("text").with_child(Dom::create_text("Hello World")))
+    };
+
+    let before = layout_dom(
+        dom(),
+        ".gl, .text { display: block; width: 100px; height: 50px; }",
+        800.0,
+        600.0,
+    );
+    let after = layout_dom(
+        dom(),
+        ".gl, .text { display: block; width: 200px; height: 80px; }",
+        800.0,
+        600.0,
+    );
+
+    let to_redraw = after.layout_results[&DomId::ROOT_ID]
+        .get_gl_textures_to_redraw(&before.layout_results[&DomId::ROOT_ID]);
+    assert_eq!(to_redraw, vec![NodeId::new(1)]);
+}
+
+#[test]
+fn test_computed_style_reports_resolved_width() {
+    use azul_css::props::{
+        basic::PixelValue,
+        layout::{LayoutDisplay, LayoutMarginLeft, LayoutPaddingLeft, LayoutWidth},
+        property::{CssProperty, CssPropertyType},
+    };
+
+    // outer (0) -> half (1)
+    let dom = div("outer").with_child(div("half"));
+    let css = r#"
+        .outer { width: 400px; }
+        .half { width: 50%; padding: 10px; margin-left: 5px; }
+    "#;
+
+    let window = layout_dom(dom, css, 800.0, 600.0);
+    let result = &window.layout_results[&DomId::ROOT_ID];
+    let half = NodeId::new(1);
+
+    // Declared value: still the percentage
+    let styled_dom = &result.styled_dom;
+    let declared = styled_dom
+        .get_css_property_cache()
+        .get_property(
+            &styled_dom.node_data.as_container()[half],
+            &half,
+            &styled_dom.styled_nodes.as_container()[half].styled_node_state,
+            &CssPropertyType::Width,
+        )
+        .cloned();
+    assert_eq!(
+        declared,
+        Some(LayoutWidth::Px(PixelValue::percent(50.0)).into())
+    );
+
+    // Computed value: resolved against the 400px container, content-box
+    let computed = result.computed_style(half);
+    assert_eq!(
+        computed.get(&CssPropertyType::Width),
+        Some(&CssProperty::from(LayoutWidth::px(200.0)))
+    );
+    assert_eq!(
+        computed.get(&CssPropertyType::PaddingLeft),
+        Some(&CssProperty::from(LayoutPaddingLeft::px(10.0)))
+    );
+    assert_eq!(
+        computed.get(&CssPropertyType::MarginLeft),
+        Some(&CssProperty::from(LayoutMarginLeft::px(5.0)))
+    );
+    assert_eq!(
+        computed.get(&CssPropertyType::Display),
+        Some(&CssProperty::from(LayoutDisplay::Block))
+    );
+}
+
+#[test]
+fn test_generation_increments_on_relayout_only() {
+    let css = ".box { width: 100px; height: 50px; }";
+    let mut window = layout_dom(div("box"), css, 800.0, 600.0);
+    let generation = window.layout_results[&DomId::ROOT_ID].generation();
+
+    // Read-only queries leave the generation alone
+    let _ = window.layout_results[&DomId::ROOT_ID].computed_style(NodeId::new(0));
+    let _ = window.get_node_layout_rect(node(0));
+    assert_eq!(window.layout_results[&DomId::ROOT_ID].generation(), generation);
+
+    let (css, _) = azul_css::parser2::new_from_str(css);
+    let styled_dom = StyledDom::create(&mut div("box"), css);
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(400.0, 300.0);
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &RendererResources::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+            &mut None,
+        )
+        .unwrap();
+    assert_eq!(window.layout_results[&DomId::ROOT_ID].generation(), generation + 1);
+}
+
+fn layout_rect(window: &LayoutWindow, node_id: DomNodeId) -> LogicalRect {
+    LogicalRect::new(
+        window.get_node_position(node_id).unwrap(),
+        window.get_node_size(node_id).unwrap(),
+    )
+}
+
+#[test]
+fn test_screen_rect_of_plain_node_is_its_layout_rect() {
+    // root (0) -> box (1)
+    let dom = div("root").with_child(div("box"));
+    let css = ".box { width: 50px; height: 30px; margin-left: 20px; }";
+    let window = layout_dom(dom, css, 800.0, 600.0);
+
+    let rect = window.get_node_screen_rect(node(1)).unwrap();
+    assert_eq!(rect, layout_rect(&window, node(1)));
+    assert_eq!(rect.size, LogicalSize::new(50.0, 30.0));
+}
+
+#[test]
+fn test_screen_rect_subtracts_ancestor_scroll_offset() {
+    // root (0) -> scroller (1) -> tall (2)
+    let dom = div("root").with_child(div("scroller").with_child(div("tall")));
+    let css = r#"
+        .scroller { overflow: scroll; width: 100px; height: 100px; }
+        .tall { height: 500px; }
+    "#;
+    let mut window = layout_dom(dom, css, 800.0, 600.0);
+
+    let now = azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0));
+    let scroller = NodeId::new(1);
+    window.scroll_manager.update_node_bounds(
+        DomId::ROOT_ID,
+        scroller,
+        layout_rect(&window, node(1)),
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 500.0)),
+        now.clone(),
+    );
+    window.scroll_manager.set_scroll_position(
+        DomId::ROOT_ID,
+        scroller,
+        LogicalPosition::new(0.0, 40.0),
+        now,
+    );
+
+    let layout = layout_rect(&window, node(2));
+    let rect = window.get_node_screen_rect(node(2)).unwrap();
+    assert_pos_eq(rect.origin, LogicalPosition::new(layout.origin.x, layout.origin.y - 40.0));
+    assert_eq!(rect.size, layout.size);
+
+    // The scroll container itself does not move
+    assert_eq!(window.get_node_screen_rect(node(1)), Some(layout_rect(&window, node(1))));
+}
+
+#[test]
+fn test_sticky_header_stays_pinned_until_its_section_scrolls_away() {
+    // root (0) -> scroller (1) -> [section (2) -> [header (3), body (4)], filler (5)]
+    let dom = div("root").with_child(
+        div("scroller")
+            .with_child(div("section").with_child(div("header")).with_child(div("body")))
+            .with_child(div("filler")),
+    );
+    let css = r#"
+        .scroller { overflow: scroll; width: 100px; height: 100px; }
+        .section { height: 200px; }
+        .header { position: sticky; top: 0px; height: 20px; }
+        .body { height: 100px; }
+        .filler { height: 300px; }
+    "#;
+    let mut window = layout_dom(dom, css, 800.0, 600.0);
+
+    let now = azul_core::task::Instant::Tick(azul_core::task::SystemTick::new(0));
+    let scroller = NodeId::new(1);
+    window.scroll_manager.update_node_bounds(
+        DomId::ROOT_ID,
+        scroller,
+        layout_rect(&window, node(1)),
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 500.0)),
+        now.clone(),
+    );
+    let scroll_to = |window: &mut LayoutWindow, y: f32| {
+        window.scroll_manager.set_scroll_position(
+            DomId::ROOT_ID,
+            scroller,
+            LogicalPosition::new(0.0, y),
+            now.clone(),
+        );
+    };
+    let container_top = layout_rect(&window, node(1)).origin.y;
+    let header_y = |window: &LayoutWindow| window.get_node_screen_rect(node(3)).unwrap().origin.y;
+
+    assert!((header_y(&window) - container_top).abs() < 0.5);
+
+    // Scrolled past the header: it sticks to the top of the scroller
+    scroll_to(&mut window, 50.0);
+    assert!((header_y(&window) - container_top).abs() < 0.5);
+    // The rest of the section scrolls normally
+    let body_y = window.get_node_screen_rect(node(4)).unwrap().origin.y;
+    assert!((body_y - (container_top + 20.0 - 50.0)).abs() < 0.5);
+
+    // Scrolled past the section: the header leaves together with its bottom edge
+    scroll_to(&mut window, 250.0);
+    assert!((header_y(&window) - (container_top + 180.0 - 250.0)).abs() < 0.5);
+}
+
+#[test]
+fn test_screen_rect_applies_node_and_ancestor_transforms() {
+    // root (0) -> moved (1) -> inner (2)
+    let dom = div("root").with_child(div("moved").with_child(div("inner")));
+    let css = r#"
+        .moved { width: 50px; height: 50px; transform: translate(30px, 10px); }
+        .inner { width: 10px; height: 10px; }
+    "#;
+    let mut window = layout_dom(dom, css, 800.0, 600.0);
+    let _ = window
+        .gpu_state_manager
+        .get_or_create_cache(DomId::ROOT_ID)
+        .synchronize(&window.layout_results[&DomId::ROOT_ID].styled_dom);
+
+    for id in [1, 2] {
+        let layout = layout_rect(&window, node(id));
+        let rect = window.get_node_screen_rect(node(id)).unwrap();
+        assert_pos_eq(
+            rect.origin,
+            LogicalPosition::new(layout.origin.x + 30.0, layout.origin.y + 10.0),
+        );
+        assert_eq!(rect.size, layout.size);
+    }
+}
@@ -0,0 +1,138 @@
+//! Tests for component lifecycle events (`AfterMount`, `BeforeUnmount`,
+//! `NodeResized`) recorded by the `LifecycleManager` on relayout
+
+use azul_core::{
+    dom::{Dom, DomId, DomNodeId},
+    events::{
+        ComponentEventFilter, EventData, EventFilter, EventProvider, EventType, LifecycleReason,
+    },
+    refany::RefAny,
+    task::{Instant, SystemTick},
+};
+use azul_layout::window::LayoutWindow;
+use rust_fontconfig::FcFontCache;
+
+mod common;
+
+use common::{div, node, relayout};
+
+fn with_component_callback(dom: Dom, filter: ComponentEventFilter) -> Dom {
+    dom.with_callback(EventFilter::Component(filter), RefAny::new(()), 0usize)
+}
+
+/// Takes the pending lifecycle events, like the shell does after dispatching them
+fn take_events(window: &mut LayoutWindow) -> Vec<(EventType, DomNodeId, LifecycleReason)> {
+    let events = window
+        .lifecycle_manager
+        .get_pending_events(Instant::Tick(SystemTick::new(0)))
+        .into_iter()
+        .map(|e| match e.data {
+            EventData::Lifecycle(data) => (e.event_type, e.target, data.reason),
+            other => panic!("expected lifecycle data, got {:?}", other),
+        })
+        .collect();
+    window.lifecycle_manager.clear();
+    events
+}
+
+#[test]
+fn test_mount_then_unmount_fire_once_each() {
+    let css = ".parent { width: 100px; height: 100px; } .child { height: 20px; }";
+    let with_child = || {
+        let child = with_component_callback(div("child"), ComponentEventFilter::AfterMount);
+        let child = with_component_callback(child, ComponentEventFilter::BeforeUnmount);
+        div("parent").with_children(vec![child].into())
+    };
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    relayout(&mut window, div("parent"), css, 800.0, 600.0);
+    assert!(take_events(&mut window).is_empty());
+
+    relayout(&mut window, with_child(), css, 800.0, 600.0);
+    assert_eq!(
+        take_events(&mut window),
+        vec![(EventType::Mount, node(1), LifecycleReason::InitialMount)]
+    );
+
+    // Unchanged DOM: neither mounted nor unmounted again
+    relayout(&mut window, with_child(), css, 800.0, 600.0);
+    assert!(take_events(&mut window).is_empty());
+
+    relayout(&mut window, div("parent"), css, 800.0, 600.0);
+    assert_eq!(
+        take_events(&mut window),
+        vec![(EventType::Unmount, node(1), LifecycleReason::Unmount)]
+    );
+    assert!(take_events(&mut window).is_empty());
+}
+
+#[test]
+fn test_unmount_keeps_callbacks_of_removed_node() {
+    let css = ".a { height: 10px; } .b { height: 20px; }";
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+
+    let removed = with_component_callback(div("b"), ComponentEventFilter::BeforeUnmount);
+    relayout(&mut window, div("a").with_children(vec![removed].into()), css, 800.0, 600.0);
+    relayout(&mut window, div("a"), css, 800.0, 600.0);
+
+    let callbacks = window.lifecycle_manager.unmount_callbacks(&node(1));
+    assert_eq!(callbacks.len(), 1);
+    assert_eq!(
+        callbacks[0].event,
+        EventFilter::Component(ComponentEventFilter::BeforeUnmount)
+    );
+    // The node no longer exists in the current layout
+    assert_eq!(window.layout_results[&DomId::ROOT_ID].styled_dom.node_data.len(), 1);
+}
+
+#[test]
+fn test_node_resized_fires_only_for_resized_nodes() {
+    let dom = || {
+        Dom::create_div().with_children(
+            vec![
+                with_component_callback(div("grow"), ComponentEventFilter::NodeResized),
+                with_component_callback(div("fixed"), ComponentEventFilter::NodeResized),
+            ]
+            .into(),
+        )
+    };
+
+    let css = ".grow { width: 50%; height: 20px; } .fixed { width: 100px; height: 20px; }";
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    relayout(&mut window, dom(), css, 800.0, 600.0);
+    // The first layout of a DOM is a mount, not a resize
+    assert!(!window.lifecycle_manager.has_pending_events());
+
+    relayout(&mut window, dom(), css, 400.0, 600.0);
+
+    let events = window
+        .lifecycle_manager
+        .get_pending_events(Instant::Tick(SystemTick::new(0)));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, EventType::Resize);
+    assert_eq!(events[0].target, node(1));
+    match &events[0].data {
+        EventData::Lifecycle(data) => {
+            assert_eq!(data.reason, LifecycleReason::Resize);
+            assert_eq!(data.previous_bounds.unwrap().size.width, 400.0);
+            assert_eq!(data.current_bounds.size.width, 200.0);
+        }
+        other => panic!("expected lifecycle data, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lifecycle_events_map_to_component_filters() {
+    use azul_core::events::event_type_to_filters;
+
+    for (event_type, filter) in [
+        (EventType::Mount, ComponentEventFilter::AfterMount),
+        (EventType::Unmount, ComponentEventFilter::BeforeUnmount),
+        (EventType::Resize, ComponentEventFilter::NodeResized),
+    ] {
+        assert_eq!(
+            event_type_to_filters(event_type, &EventData::None),
+            vec![EventFilter::Component(filter)]
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::BTreeMap;
+
+use azul_core::{
+    diff::reconcile_dom,
+    dom::{DomId, IdOrClass, NodeData, NodeId, ScrollbarOrientation},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    hit_test::{FullHitTest, HitTest, ScrollHitTestItem, ScrollbarHitId},
+    task::Instant,
+    ui_solver::ResolvedOffsets,
+};
+use azul_layout::managers::scroll_state::ScrollManager;
+
+fn test_instant() -> Instant {
+    #[cfg(feature = "std")]
+    {
+        Instant::System(std::time::Instant::now().into())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use azul_core::task::SystemTick;
+        Instant::Tick(SystemTick { tick_counter: 0 })
+    }
+}
+
+/// 200x100 viewport over 500x400 content
+fn add_scroll_node(scroll_mgr: &mut ScrollManager, dom_id: DomId, node_id: NodeId) {
+    scroll_mgr.update_node_bounds(
+        dom_id,
+        node_id,
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(200.0, 100.0)),
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(500.0, 400.0)),
+        test_instant(),
+    );
+}
+
+fn scroll_manager_with_node(dom_id: DomId, node_id: NodeId) -> ScrollManager {
+    let mut scroll_mgr = ScrollManager::new();
+    add_scroll_node(&mut scroll_mgr, dom_id, node_id);
+    scroll_mgr
+}
+
+fn class_div(class: &str) -> NodeData {
+    NodeData::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class(class.to_string().into())].into())
+}
+
+#[test]
+fn test_scroll_edges_at_scroll_start() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let scroll_mgr = scroll_manager_with_node(dom_id, node_id);
+
+    let edges = scroll_mgr.get_scroll_edges(dom_id, node_id).unwrap();
+    assert_eq!(
+        edges,
+        ResolvedOffsets {
+            top: 0.0,
+            left: 0.0,
+            right: 300.0,
+            bottom: 300.0,
+        }
+    );
+}
+
+#[test]
+fn test_scroll_edges_mid_scroll() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let mut scroll_mgr = scroll_manager_with_node(dom_id, node_id);
+
+    scroll_mgr.set_scroll_position(
+        dom_id,
+        node_id,
+        LogicalPosition::new(120.0, 50.0),
+        test_instant(),
+    );
+
+    let edges = scroll_mgr.get_scroll_edges(dom_id, node_id).unwrap();
+    assert_eq!(
+        edges,
+        ResolvedOffsets {
+            top: 50.0,
+            left: 120.0,
+            right: 180.0,
+            bottom: 250.0,
+        }
+    );
+}
+
+#[test]
+fn test_scroll_edges_unknown_node() {
+    let scroll_mgr = ScrollManager::new();
+    assert!(scroll_mgr
+        .get_scroll_edges(DomId { inner: 0 }, NodeId::new(1))
+        .is_none());
+}
+
+/// 200x100 viewport over 200x400 content, with a 16px vertical scrollbar
+fn scroll_manager_with_scrollbar(dom_id: DomId, node_id: NodeId) -> ScrollManager {
+    let mut scroll_mgr = ScrollManager::new();
+    scroll_mgr.register_or_update_scroll_node(
+        dom_id,
+        node_id,
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(200.0, 100.0)),
+        LogicalSize::new(200.0, 400.0),
+        test_instant(),
+        16.0,
+        16.0,
+        false,
+        true,
+    );
+    scroll_mgr.calculate_scrollbar_states();
+    scroll_mgr
+}
+
+#[test]
+fn test_click_on_scrollbar_thumb_produces_scrollbar_hit() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let scroll_mgr = scroll_manager_with_scrollbar(dom_id, node_id);
+
+    let state = scroll_mgr
+        .get_scrollbar_state(dom_id, node_id, ScrollbarOrientation::Vertical)
+        .expect("content overflows vertically");
+    let thumb_center = LogicalPosition::new(
+        state.track_rect.origin.x + state.track_rect.size.width / 2.0,
+        state.track_rect.origin.y + state.button_size + state.thumb_offset + state.thumb_length / 2.0,
+    );
+
+    let mut hit_test = FullHitTest::empty(None);
+    scroll_mgr.append_scrollbar_hits(&mut hit_test, thumb_center);
+
+    let dom_hits = hit_test.hovered_nodes.get(&dom_id).expect("scrollbar was hit");
+    assert!(dom_hits.is_scrollbar_hit());
+    let hit_id = dom_hits.scrollbar_hit_test_nodes.keys().next().unwrap();
+    assert_eq!(*hit_id, ScrollbarHitId::VerticalThumb(dom_id, node_id));
+    assert!(hit_id.is_thumb());
+}
+
+#[test]
+fn test_click_on_content_produces_no_scrollbar_hit() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let scroll_mgr = scroll_manager_with_scrollbar(dom_id, node_id);
+
+    let mut hit_test = FullHitTest::empty(None);
+    scroll_mgr.append_scrollbar_hits(&mut hit_test, LogicalPosition::new(50.0, 50.0));
+
+    assert!(!hit_test
+        .hovered_nodes
+        .get(&dom_id)
+        .is_some_and(|h| h.is_scrollbar_hit()));
+}
+
+#[test]
+fn test_relayout_preserves_moved_and_prunes_removed_scroll_state() {
+    let dom_id = DomId { inner: 0 };
+    let old_dom = vec![class_div("root"), class_div("x"), class_div("a"), class_div("b")];
+    let new_dom = vec![class_div("root"), class_div("a")];
+
+    let mut scroll_mgr = ScrollManager::new();
+    for (node, y) in [(2, 30.0), (3, 60.0)] {
+        add_scroll_node(&mut scroll_mgr, dom_id, NodeId::new(node));
+        scroll_mgr.set_scroll_position(
+            dom_id,
+            NodeId::new(node),
+            LogicalPosition::new(0.0, y),
+            test_instant(),
+        );
+    }
+
+    // "a" moves from index 2 to 1 (matched by hash), "x" and "b" are gone
+    let diff = reconcile_dom(
+        &old_dom,
+        &new_dom,
+        &Default::default(),
+        &Default::default(),
+        dom_id,
+        test_instant(),
+    );
+    let node_id_map: BTreeMap<NodeId, NodeId> = diff
+        .node_moves
+        .iter()
+        .map(|m| (m.old_node_id, m.new_node_id))
+        .collect();
+
+    scroll_mgr.prune_removed_nodes(dom_id, &node_id_map);
+    scroll_mgr.remap_node_ids(dom_id, &node_id_map);
+
+    assert_eq!(
+        scroll_mgr.get_current_offset(dom_id, NodeId::new(1)),
+        Some(LogicalPosition::new(0.0, 30.0))
+    );
+    assert!(scroll_mgr.get_current_offset(dom_id, NodeId::new(2)).is_none());
+    assert!(scroll_mgr.get_current_offset(dom_id, NodeId::new(3)).is_none());
+}
+
+#[test]
+fn test_remap_shifted_nodes_does_not_overwrite_states() {
+    let dom_id = DomId { inner: 0 };
+    let mut scroll_mgr = ScrollManager::new();
+    for (node, y) in [(1, 10.0), (2, 20.0)] {
+        add_scroll_node(&mut scroll_mgr, dom_id, NodeId::new(node));
+        scroll_mgr.set_scroll_position(
+            dom_id,
+            NodeId::new(node),
+            LogicalPosition::new(0.0, y),
+            test_instant(),
+        );
+    }
+
+    // A node was inserted in front: everything shifts by one
+    let node_id_map: BTreeMap<NodeId, NodeId> = [(1, 2), (2, 3)]
+        .into_iter()
+        .map(|(old, new)| (NodeId::new(old), NodeId::new(new)))
+        .collect();
+    scroll_mgr.remap_node_ids(dom_id, &node_id_map);
+
+    assert!(scroll_mgr.get_current_offset(dom_id, NodeId::new(1)).is_none());
+    assert_eq!(
+        scroll_mgr.get_current_offset(dom_id, NodeId::new(2)),
+        Some(LogicalPosition::new(0.0, 10.0))
+    );
+    assert_eq!(
+        scroll_mgr.get_current_offset(dom_id, NodeId::new(3)),
+        Some(LogicalPosition::new(0.0, 20.0))
+    );
+}
+
+#[test]
+fn test_x_hidden_y_scroll_node_only_scrolls_vertically() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let mut scroll_mgr = scroll_manager_with_node(dom_id, node_id);
+
+    // overflow-x: hidden; overflow-y: scroll
+    scroll_mgr.set_scrollable_axes(dom_id, node_id, false, true);
+    assert_eq!(scroll_mgr.get_scrollable_axes(dom_id, node_id), Some((false, true)));
+
+    scroll_mgr.set_scroll_position(
+        dom_id,
+        node_id,
+        LogicalPosition::new(120.0, 80.0),
+        test_instant(),
+    );
+    assert_eq!(
+        scroll_mgr.get_current_offset(dom_id, node_id),
+        Some(LogicalPosition::new(0.0, 80.0))
+    );
+
+    let info = scroll_mgr.get_scroll_node_info(dom_id, node_id).unwrap();
+    assert!(!info.scrollable_x && info.scrollable_y);
+    assert_eq!(info.max_scroll_x, 0.0);
+    assert_eq!(info.max_scroll_y, 300.0);
+}
+
+#[test]
+fn test_disabling_axis_resets_existing_offset() {
+    let dom_id = DomId { inner: 0 };
+    let node_id = NodeId::new(1);
+    let mut scroll_mgr = scroll_manager_with_node(dom_id, node_id);
+
+    scroll_mgr.set_scroll_position(
+        dom_id,
+        node_id,
+        LogicalPosition::new(50.0, 60.0),
+        test_instant(),
+    );
+    scroll_mgr.set_scrollable_axes(dom_id, node_id, false, true);
+
+    assert_eq!(
+        scroll_mgr.get_current_offset(dom_id, node_id),
+        Some(LogicalPosition::new(0.0, 60.0))
+    );
+}
+
+/// Cursor over an inner scroll area (node 2) nested in an outer one (node 1)
+fn nested_scroll_hit(dom_id: DomId) -> FullHitTest {
+    let mut dom_hits = HitTest::empty();
+    for node in [1, 2] {
+        dom_hits.scroll_hit_test_nodes.insert(
+            NodeId::new(node),
+            ScrollHitTestItem {
+                point_in_viewport: LogicalPosition::new(50.0, 50.0),
+                point_relative_to_item: LogicalPosition::new(50.0, 50.0),
+                scroll_node: Default::default(),
+            },
+        );
+    }
+    let mut hit_test = FullHitTest::empty(None);
+    hit_test.hovered_nodes.insert(dom_id, dom_hits);
+    hit_test
+}
+
+#[test]
+fn test_scroll_hit_chain_is_innermost_first() {
+    let dom_id = DomId { inner: 0 };
+    let (outer, inner) = (NodeId::new(1), NodeId::new(2));
+    let mut scroll_mgr = ScrollManager::new();
+    add_scroll_node(&mut scroll_mgr, dom_id, outer);
+    add_scroll_node(&mut scroll_mgr, dom_id, inner);
+
+    let down = LogicalPosition::new(0.0, 10.0);
+    let chain = scroll_mgr.get_scroll_hit_chain(&nested_scroll_hit(dom_id), down);
+    assert_eq!(chain, vec![(dom_id, inner), (dom_id, outer)]);
+}
+
+#[test]
+fn test_scroll_hit_chain_skips_containers_at_their_limit() {
+    let dom_id = DomId { inner: 0 };
+    let (outer, inner) = (NodeId::new(1), NodeId::new(2));
+    let mut scroll_mgr = ScrollManager::new();
+    add_scroll_node(&mut scroll_mgr, dom_id, outer);
+    add_scroll_node(&mut scroll_mgr, dom_id, inner);
+
+    // Inner area is scrolled to the bottom: scrolling down goes to the outer one
+    scroll_mgr.set_scroll_position(
+        dom_id,
+        inner,
+        LogicalPosition::new(0.0, 300.0),
+        test_instant(),
+    );
+    let hit_test = nested_scroll_hit(dom_id);
+    let down = LogicalPosition::new(0.0, 10.0);
+    assert_eq!(scroll_mgr.get_scroll_hit_chain(&hit_test, down), vec![(dom_id, outer)]);
+
+    // ... while scrolling up still goes to the inner one first
+    let up = LogicalPosition::new(0.0, -10.0);
+    assert_eq!(scroll_mgr.get_scroll_hit_chain(&hit_test, up), vec![(dom_id, inner)]);
+}
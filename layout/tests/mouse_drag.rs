@@ -0,0 +1,121 @@
+//! Tests for plain mouse drags (button held + movement past the drag threshold)
+
+use azul_core::{
+    events::EventType,
+    geom::LogicalPosition,
+    task::{Instant, SystemTick},
+    window::{CursorPosition, WindowPosition},
+};
+use azul_layout::{
+    event_determination::determine_all_events,
+    managers::{
+        file_drop::FileDropManager, focus_cursor::FocusManager, gesture::GestureAndDragManager,
+        hover::HoverManager,
+    },
+    window_state::FullWindowState,
+};
+
+fn at(ms: u64) -> Instant {
+    Instant::Tick(SystemTick::new(ms))
+}
+
+fn pressed_at(x: f32, y: f32) -> FullWindowState {
+    let mut state = FullWindowState::default();
+    state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(x, y));
+    state.mouse_state.left_down = true;
+    state
+}
+
+/// Presses the button at `from`, moves to `to` with the button held and
+/// returns the events of the move frame.
+fn press_and_move(
+    manager: &mut GestureAndDragManager,
+    from: LogicalPosition,
+    to: LogicalPosition,
+) -> Vec<EventType> {
+    manager.start_input_session(from, at(0), 1, WindowPosition::Uninitialized, from);
+    manager.record_input_sample(to, at(16), 1, to);
+
+    determine_all_events(
+        &pressed_at(to.x, to.y),
+        &pressed_at(from.x, from.y),
+        &HoverManager::new(),
+        &FocusManager::new(),
+        &FileDropManager::new(),
+        Some(&*manager),
+        &[],
+        at(16),
+    )
+    .into_iter()
+    .map(|e| e.event_type)
+    .collect()
+}
+
+#[test]
+fn test_press_and_move_10px_produces_drag_with_delta() {
+    let mut manager = GestureAndDragManager::new();
+    let start = LogicalPosition::new(20.0, 20.0);
+    let events = press_and_move(&mut manager, start, LogicalPosition::new(30.0, 20.0));
+
+    assert!(events.contains(&EventType::DragStart));
+    assert!(events.contains(&EventType::Drag));
+    assert_eq!(manager.get_drag_delta_incremental(), Some((10.0, 0.0)));
+    assert_eq!(manager.get_drag_start_position(), Some(start));
+
+    // Next frame: DragStart is not repeated, Drag carries only the new movement
+    manager.record_input_sample(LogicalPosition::new(33.0, 24.0), at(32), 1, start);
+    let events: Vec<_> = determine_all_events(
+        &pressed_at(33.0, 24.0),
+        &pressed_at(30.0, 20.0),
+        &HoverManager::new(),
+        &FocusManager::new(),
+        &FileDropManager::new(),
+        Some(&manager),
+        &[],
+        at(32),
+    )
+    .into_iter()
+    .map(|e| e.event_type)
+    .collect();
+    assert!(!events.contains(&EventType::DragStart));
+    assert!(events.contains(&EventType::Drag));
+    assert_eq!(manager.get_drag_delta_incremental(), Some((3.0, 4.0)));
+    assert_eq!(manager.get_drag_start_position(), Some(start));
+}
+
+#[test]
+fn test_stationary_press_does_not_drag() {
+    let mut manager = GestureAndDragManager::new();
+    let start = LogicalPosition::new(20.0, 20.0);
+    let events = press_and_move(&mut manager, start, start);
+
+    assert!(!events.contains(&EventType::DragStart));
+    assert!(!events.contains(&EventType::Drag));
+}
+
+#[test]
+fn test_jitter_below_threshold_does_not_drag() {
+    let mut manager = GestureAndDragManager::new();
+    let events = press_and_move(
+        &mut manager,
+        LogicalPosition::new(20.0, 20.0),
+        LogicalPosition::new(22.0, 21.0),
+    );
+
+    assert!(!events.contains(&EventType::DragStart));
+    assert!(!events.contains(&EventType::Drag));
+}
+
+#[test]
+fn test_drag_start_position_is_cleared_on_release() {
+    let mut manager = GestureAndDragManager::new();
+    press_and_move(
+        &mut manager,
+        LogicalPosition::new(20.0, 20.0),
+        LogicalPosition::new(30.0, 20.0),
+    );
+    manager.end_current_session();
+
+    assert_eq!(manager.get_drag_start_position(), None);
+    assert_eq!(manager.get_drag_delta_incremental(), None);
+}
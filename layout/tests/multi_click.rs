@@ -0,0 +1,132 @@
+//! Tests for double- and triple-click detection via the gesture manager
+
+use azul_core::{
+    events::{
+        event_type_to_filters, EventData, EventFilter, EventType, HoverEventFilter,
+        WindowEventFilter,
+    },
+    geom::LogicalPosition,
+    task::{Instant, SystemTick},
+    window::{CursorPosition, WindowPosition},
+};
+use azul_layout::{
+    event_determination::determine_all_events,
+    managers::{
+        file_drop::FileDropManager, focus_cursor::FocusManager, gesture::GestureAndDragManager,
+        hover::HoverManager,
+    },
+    window_state::FullWindowState,
+};
+
+fn at(ms: u64) -> Instant {
+    Instant::Tick(SystemTick::new(ms))
+}
+
+/// Feeds a mouse-down at `down_ms` and a mouse-up 50ms later at `(x, y)` and
+/// returns the events of the mouse-up frame.
+fn click(manager: &mut GestureAndDragManager, x: f32, y: f32, down_ms: u64) -> Vec<EventType> {
+    let position = LogicalPosition::new(x, y);
+
+    let mut released = FullWindowState::default();
+    released.mouse_state.cursor_position = CursorPosition::InWindow(position);
+    let mut pressed = released.clone();
+    pressed.mouse_state.left_down = true;
+
+    manager.start_input_session(position, at(down_ms), 1, WindowPosition::Uninitialized, position);
+    manager.end_current_session();
+
+    determine_all_events(
+        &released,
+        &pressed,
+        &HoverManager::new(),
+        &FocusManager::new(),
+        &FileDropManager::new(),
+        Some(&*manager),
+        &[],
+        at(down_ms + 50),
+    )
+    .into_iter()
+    .map(|e| e.event_type)
+    .collect()
+}
+
+fn count(events: &[EventType], event_type: EventType) -> usize {
+    events.iter().filter(|e| **e == event_type).count()
+}
+
+#[test]
+fn test_two_quick_clicks_produce_double_click() {
+    let mut manager = GestureAndDragManager::new();
+
+    let first = click(&mut manager, 10.0, 10.0, 0);
+    assert_eq!(count(&first, EventType::DoubleClick), 0);
+    assert_eq!(manager.click_count(), 1);
+
+    let second = click(&mut manager, 12.0, 11.0, 200);
+    assert_eq!(count(&second, EventType::DoubleClick), 1);
+    assert_eq!(count(&second, EventType::TripleClick), 0);
+    assert_eq!(manager.click_count(), 2);
+}
+
+#[test]
+fn test_third_quick_click_produces_triple_click() {
+    let mut manager = GestureAndDragManager::new();
+    click(&mut manager, 10.0, 10.0, 0);
+    click(&mut manager, 10.0, 10.0, 300);
+
+    let third = click(&mut manager, 10.0, 10.0, 600);
+    assert_eq!(count(&third, EventType::TripleClick), 1);
+    assert_eq!(count(&third, EventType::DoubleClick), 0);
+    assert_eq!(manager.click_count(), 3);
+}
+
+#[test]
+fn test_slow_or_distant_clicks_do_not_chain() {
+    let mut manager = GestureAndDragManager::new();
+    click(&mut manager, 10.0, 10.0, 0);
+
+    // Outside the default 500ms window
+    let slow = click(&mut manager, 10.0, 10.0, 600);
+    assert_eq!(count(&slow, EventType::DoubleClick), 0);
+    assert_eq!(manager.click_count(), 1);
+
+    // Quick, but more than 5px away
+    let distant = click(&mut manager, 30.0, 10.0, 700);
+    assert_eq!(count(&distant, EventType::DoubleClick), 0);
+    assert_eq!(manager.click_count(), 1);
+}
+
+#[test]
+fn test_double_click_fires_only_on_the_completing_release() {
+    let mut manager = GestureAndDragManager::new();
+    click(&mut manager, 10.0, 10.0, 0);
+    click(&mut manager, 10.0, 10.0, 100);
+
+    // A later frame without a release (e.g. mouse move) must not repeat it
+    let mut idle = FullWindowState::default();
+    idle.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(10.0, 10.0));
+    let mut moved = idle.clone();
+    moved.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(40.0, 10.0));
+    let events = determine_all_events(
+        &moved,
+        &idle,
+        &HoverManager::new(),
+        &FocusManager::new(),
+        &FileDropManager::new(),
+        Some(&manager),
+        &[],
+        at(300),
+    );
+    assert!(events.iter().all(|e| e.event_type != EventType::DoubleClick));
+}
+
+#[test]
+fn test_triple_click_maps_to_hover_and_window_filters() {
+    assert_eq!(
+        event_type_to_filters(EventType::TripleClick, &EventData::None),
+        vec![
+            EventFilter::Hover(HoverEventFilter::TripleClick),
+            EventFilter::Window(WindowEventFilter::TripleClick),
+        ]
+    );
+}
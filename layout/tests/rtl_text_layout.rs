@@ -0,0 +1,122 @@
+//! Tests for `direction: rtl` paragraphs and mirroring words within a line
+
+use std::collections::BTreeMap;
+
+use azul_core::{
+    dom::{Dom, DomId, IdOrClass, NodeId},
+    geom::LogicalSize,
+    resources::RendererResources,
+    styled_dom::StyledDom,
+};
+use azul_layout::{
+    callbacks::ExternalSystemCallbacks, text3::cache::UnifiedLayout, window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+/// Lays out `<div class="p">text</div>` in a 400px wide paragraph
+fn paragraph_layout(text: &str, direction: &str) -> UnifiedLayout {
+    let css = format!(".p {{ width: 400px; direction: {direction}; }}");
+    let (css, _) = azul_css::parser2::new_from_str(&css);
+    let mut dom = Dom::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class("p".to_string().into())].into())
+        .with_child(Dom::create_text(text));
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &RendererResources::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+            &mut None,
+        )
+        .unwrap();
+
+    let layout = window
+        .get_inline_layout_for_node(DomId::ROOT_ID, NodeId::new(1))
+        .unwrap();
+    UnifiedLayout::clone(layout)
+}
+
+/// x position of every cluster on the first line, keyed by its text
+fn first_line_clusters(layout: &UnifiedLayout) -> BTreeMap<String, f32> {
+    layout
+        .items
+        .iter()
+        .filter(|item| item.line_index == 0)
+        .filter_map(|item| Some((item.item.as_cluster()?.text.clone(), item.position.x)))
+        .collect()
+}
+
+fn cluster_positions(text: &str, direction: &str) -> BTreeMap<String, f32> {
+    first_line_clusters(&paragraph_layout(text, direction))
+}
+
+/// x positions of the given (unique) first letters of each word
+fn word_starts(positions: &BTreeMap<String, f32>, letters: &[&str]) -> Vec<f32> {
+    letters.iter().map(|l| positions[*l]).collect()
+}
+
+fn leftmost(positions: &BTreeMap<String, f32>) -> f32 {
+    positions.values().copied().fold(f32::INFINITY, f32::min)
+}
+
+#[test]
+fn test_rtl_paragraph_mirrors_word_positions() {
+    // Every letter occurs only once, so clusters can be looked up by text
+    let hebrew = "אחד בית גשם";
+    let hebrew_words = ["א", "ב", "ג"];
+    let latin = "red big cow";
+    let latin_words = ["r", "b", "c"];
+
+    // LTR: words run left to right, starting at the left edge
+    let ltr = cluster_positions(latin, "ltr");
+    let x = word_starts(&ltr, &latin_words);
+    assert!(x[0] < x[1] && x[1] < x[2], "{x:?}");
+    assert!(leftmost(&ltr).abs() < 0.5);
+
+    // RTL: the first word is the rightmost one, the line hugs the right edge
+    let rtl = cluster_positions(hebrew, "rtl");
+    let x = word_starts(&rtl, &hebrew_words);
+    assert!(x[0] > x[1] && x[1] > x[2], "{x:?}");
+    assert!(leftmost(&rtl) > 100.0, "line should be right-aligned: {rtl:?}");
+
+    // Same Hebrew text in an LTR paragraph keeps its visual order but starts at the left
+    let rtl_in_ltr = cluster_positions(hebrew, "ltr");
+    let x = word_starts(&rtl_in_ltr, &hebrew_words);
+    assert!(x[0] > x[1] && x[1] > x[2], "{x:?}");
+    assert!(leftmost(&rtl_in_ltr).abs() < 0.5);
+}
+
+#[test]
+fn test_reverse_line_words_mirrors_words_within_the_line() {
+    let mut layout = paragraph_layout("red big cow", "ltr");
+    let before = first_line_clusters(&layout);
+    let width_of = |layout: &UnifiedLayout, text: &str| {
+        layout
+            .items
+            .iter()
+            .find(|item| item.item.as_cluster().is_some_and(|c| c.text == text))
+            .unwrap()
+            .item
+            .bounds()
+            .width
+    };
+    let red_end = before["d"] + width_of(&layout, "d");
+
+    layout.reverse_line_words(0, 400.0);
+    let after = first_line_clusters(&layout);
+
+    // First logical word is now the rightmost one
+    let x = word_starts(&after, &["r", "b", "c"]);
+    assert!(x[0] > x[1] && x[1] > x[2], "{x:?}");
+
+    // "red" now spans [400 - red_end, 400 - red_start], letters stay in order
+    assert!((after["r"] - (400.0 - red_end)).abs() < 0.01);
+    assert!((after["e"] - after["r"] - (before["e"] - before["r"])).abs() < 0.01);
+    assert!((after["d"] + width_of(&layout, "d") - (400.0 - before["r"])).abs() < 0.01);
+}
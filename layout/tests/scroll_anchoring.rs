@@ -0,0 +1,107 @@
+//! Tests for CSS scroll anchoring across relayouts
+
+use std::collections::BTreeMap;
+
+use azul_core::{
+    dom::{Dom, DomId, NodeId},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    task::{Instant, SystemTick},
+};
+use azul_layout::window::LayoutWindow;
+use rust_fontconfig::FcFontCache;
+
+mod common;
+
+use common::{div, relayout};
+
+const CSS: &str = r#"
+    .scroller { overflow: scroll; width: 100px; height: 100px; }
+    .item { height: 100px; }
+    .inserted { height: 50px; }
+"#;
+
+/// root (0) -> scroller (1) -> [inserted?] + 3 items
+fn scroller(with_inserted: bool) -> Dom {
+    let mut scroller = div("scroller");
+    if with_inserted {
+        scroller = scroller.with_child(div("inserted"));
+    }
+    for _ in 0..3 {
+        scroller = scroller.with_child(div("item"));
+    }
+    div("root").with_child(scroller)
+}
+
+const SCROLLER: NodeId = NodeId::new(1);
+
+fn scroll_to(window: &mut LayoutWindow, y: f32) {
+    let now = Instant::Tick(SystemTick::new(0));
+    let container = LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 100.0));
+    let content = LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 350.0));
+    window
+        .scroll_manager
+        .update_node_bounds(DomId::ROOT_ID, SCROLLER, container, content, now.clone());
+    window.scroll_manager.set_scroll_position(
+        DomId::ROOT_ID,
+        SCROLLER,
+        LogicalPosition::new(0.0, y),
+        now,
+    );
+}
+
+/// What DOM reconciliation reports when `.inserted` is added as first child:
+/// the three items (2, 3, 4) all shift down by one
+fn shifted_items() -> BTreeMap<NodeId, NodeId> {
+    (0..5)
+        .map(|i| {
+            let new = if i >= 2 { i + 1 } else { i };
+            (NodeId::new(i), NodeId::new(new))
+        })
+        .collect()
+}
+
+fn offset(window: &LayoutWindow) -> LogicalPosition {
+    window
+        .scroll_manager
+        .get_current_offset(DomId::ROOT_ID, SCROLLER)
+        .unwrap()
+}
+
+#[test]
+fn test_inserting_content_above_viewport_keeps_visible_content_in_place() {
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    relayout(&mut window, scroller(false), CSS, 800.0, 600.0);
+
+    // Looking at the second half of the second item
+    scroll_to(&mut window, 150.0);
+    let anchor = window
+        .scroll_manager
+        .get_scroll_anchor(DomId::ROOT_ID, SCROLLER)
+        .unwrap();
+    assert_eq!(anchor.node_id, NodeId::new(3));
+
+    window
+        .scroll_manager
+        .prune_removed_nodes(DomId::ROOT_ID, &shifted_items());
+    window
+        .scroll_manager
+        .remap_node_ids(DomId::ROOT_ID, &shifted_items());
+    relayout(&mut window, scroller(true), CSS, 800.0, 600.0);
+
+    assert_eq!(offset(&window), LogicalPosition::new(0.0, 200.0));
+}
+
+#[test]
+fn test_no_anchoring_when_scrolled_to_top() {
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    relayout(&mut window, scroller(false), CSS, 800.0, 600.0);
+    scroll_to(&mut window, 0.0);
+
+    window
+        .scroll_manager
+        .remap_node_ids(DomId::ROOT_ID, &shifted_items());
+    relayout(&mut window, scroller(true), CSS, 800.0, 600.0);
+
+    // The inserted content becomes visible instead
+    assert_eq!(offset(&window), LogicalPosition::zero());
+}
@@ -0,0 +1,77 @@
+//! Tests for `LayoutWindow::scroll_node_into_view` on a laid-out scroll container
+
+use azul_core::{
+    dom::{DomId, NodeId},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    task::{Instant, SystemTick},
+};
+use azul_layout::{managers::scroll_into_view::ScrollIntoViewOptions, window::LayoutWindow};
+
+mod common;
+
+use common::{div, layout_dom, node};
+
+const CSS: &str = r#"
+    .scroller { overflow: scroll; width: 100px; height: 100px; }
+    .item { height: 100px; }
+"#;
+
+const SCROLLER: NodeId = NodeId::new(1);
+
+fn now() -> Instant {
+    Instant::Tick(SystemTick::new(0))
+}
+
+/// root (0) -> scroller (1) -> 3 items (2, 3, 4), with the scroller's
+/// bounds registered in the scroll manager as the shell does after layout
+fn layout_scroller() -> LayoutWindow {
+    let dom = div("root").with_child(
+        div("scroller")
+            .with_child(div("item"))
+            .with_child(div("item"))
+            .with_child(div("item")),
+    );
+    let mut window = layout_dom(dom, CSS, 800.0, 600.0);
+
+    let container = LogicalRect::new(
+        window.get_node_position(node(1)).unwrap(),
+        window.get_node_size(node(1)).unwrap(),
+    );
+    let content = LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(100.0, 300.0));
+    window
+        .scroll_manager
+        .update_node_bounds(DomId::ROOT_ID, SCROLLER, container, content, now());
+    window
+}
+
+fn offset(window: &LayoutWindow) -> LogicalPosition {
+    window
+        .scroll_manager
+        .get_current_offset(DomId::ROOT_ID, SCROLLER)
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_node_below_the_fold_is_scrolled_into_view() {
+    let mut window = layout_scroller();
+    assert_eq!(offset(&window), LogicalPosition::zero());
+
+    // The last item starts 200px below the top of the 100px high scroller
+    let adjustments =
+        window.scroll_node_into_view(node(4), ScrollIntoViewOptions::nearest(), now());
+
+    assert_eq!(adjustments.len(), 1);
+    assert_eq!(adjustments[0].scroll_container_node_id, SCROLLER);
+    assert_eq!(offset(&window), LogicalPosition::new(0.0, 200.0));
+}
+
+#[test]
+fn test_visible_node_does_not_scroll() {
+    let mut window = layout_scroller();
+
+    let adjustments =
+        window.scroll_node_into_view(node(2), ScrollIntoViewOptions::nearest(), now());
+
+    assert!(adjustments.is_empty());
+    assert_eq!(offset(&window), LogicalPosition::zero());
+}
@@ -104,3 +104,98 @@ fn test_resolve_percentage_with_box_model_zero_percent() {
     );
     assert_eq!(result, 0.0);
 }
+
+fn used_size_with_aspect_ratio(css: &str, aspect_ratio: f32) -> azul_core::geom::LogicalSize {
+    use azul_core::{
+        dom::{Dom, IdOrClass, NodeId},
+        geom::LogicalSize,
+        styled_dom::StyledDom,
+    };
+    use azul_layout::solver3::geometry::{BoxProps, IntrinsicSizes};
+
+    let (css, _) = azul_css::parser2::new_from_str(css);
+    let mut dom = Dom::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class("a".to_string().into())].into());
+    let styled_dom = StyledDom::create(&mut dom, css);
+    let intrinsic = IntrinsicSizes {
+        aspect_ratio: Some(aspect_ratio),
+        ..Default::default()
+    };
+
+    calculate_used_size_for_node(
+        &styled_dom,
+        Some(NodeId::new(0)),
+        LogicalSize::new(800.0, 600.0),
+        intrinsic,
+        &BoxProps::default(),
+        LogicalSize::new(800.0, 600.0),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_aspect_ratio_derives_height_from_width() {
+    let size = used_size_with_aspect_ratio(".a { width: 200px; }", 2.0);
+    assert_eq!(size.width, 200.0);
+    assert_eq!(size.height, 100.0);
+}
+
+#[test]
+fn test_aspect_ratio_derives_width_from_height() {
+    let size = used_size_with_aspect_ratio(".a { height: 100px; }", 2.0);
+    assert_eq!(size.width, 200.0);
+    assert_eq!(size.height, 100.0);
+}
+
+#[test]
+fn test_aspect_ratio_ignored_when_both_dimensions_are_set() {
+    let size = used_size_with_aspect_ratio(".a { width: 300px; height: 50px; }", 2.0);
+    assert_eq!(size.width, 300.0);
+    assert_eq!(size.height, 50.0);
+}
+
+#[test]
+fn test_zero_height_image_has_no_aspect_ratio() {
+    use azul_core::{
+        dom::{Dom, DomId, DomNodeId, IdOrClass, NodeId},
+        geom::LogicalSize,
+        resources::{ImageRef, RawImageFormat, RendererResources},
+        styled_dom::{NodeHierarchyItemId, StyledDom},
+    };
+    use azul_layout::{
+        callbacks::ExternalSystemCallbacks, window::LayoutWindow, window_state::FullWindowState,
+    };
+    use rust_fontconfig::FcFontCache;
+
+    // 200x0 image: only the width is given, the height must not be derived
+    // from a ratio of the 100px fallback height
+    let image = ImageRef::null_image(200, 0, RawImageFormat::R8, Vec::new());
+    let mut dom = Dom::create_div().with_child(
+        Dom::create_image(image)
+            .with_ids_and_classes(vec![IdOrClass::Class("img".to_string().into())].into()),
+    );
+    let (css, _) = azul_css::parser2::new_from_str(".img { display: block; width: 50px; }");
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &RendererResources::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+            &mut None,
+        )
+        .unwrap();
+
+    let size = window
+        .get_node_size(DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+        })
+        .unwrap();
+    assert_eq!(size.width, 50.0);
+    assert_eq!(size.height, 100.0);
+}
@@ -0,0 +1,154 @@
+//! Tests for caret-index based text queries on `UnifiedLayout`:
+//! `hit_test_char` (caret index under a point) and `selection_rects`
+
+use std::collections::BTreeMap;
+
+use azul_core::{
+    dom::{Dom, DomId, IdOrClass, NodeId},
+    geom::{LogicalPosition, LogicalRect, LogicalSize},
+    resources::RendererResources,
+    styled_dom::StyledDom,
+};
+use azul_layout::{
+    callbacks::ExternalSystemCallbacks, text3::cache::UnifiedLayout, window::LayoutWindow,
+    window_state::FullWindowState,
+};
+use rust_fontconfig::FcFontCache;
+
+/// Lays out `<div class="p">text</div>` in a paragraph of the given width
+fn paragraph_layout(text: &str, width: f32) -> UnifiedLayout {
+    let css = format!(".p {{ width: {width}px; }}");
+    let (css, _) = azul_css::parser2::new_from_str(&css);
+    let mut dom = Dom::create_div()
+        .with_ids_and_classes(vec![IdOrClass::Class("p".to_string().into())].into())
+        .with_child(Dom::create_text(text));
+    let styled_dom = StyledDom::create(&mut dom, css);
+
+    let mut window = LayoutWindow::new(FcFontCache::build()).unwrap();
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+    window
+        .layout_and_generate_display_list(
+            styled_dom,
+            &window_state,
+            &RendererResources::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+            &mut None,
+        )
+        .unwrap();
+
+    let layout = window
+        .get_inline_layout_for_node(DomId::ROOT_ID, NodeId::new(1))
+        .unwrap();
+    UnifiedLayout::clone(layout)
+}
+
+/// (x, width) of every cluster, in visual order
+fn cluster_spans(layout: &UnifiedLayout) -> Vec<(f32, f32)> {
+    layout
+        .items
+        .iter()
+        .filter(|item| item.item.as_cluster().is_some())
+        .map(|item| (item.position.x, item.item.bounds().width))
+        .collect()
+}
+
+/// Bounding box of the clusters on each line, keyed by line index
+fn line_bounds(layout: &UnifiedLayout) -> BTreeMap<usize, LogicalRect> {
+    let mut lines = BTreeMap::<usize, (f32, f32, f32, f32)>::new();
+    for item in layout.items.iter().filter(|i| i.item.as_cluster().is_some()) {
+        let b = item.item.bounds();
+        let (x0, y0) = (item.position.x, item.position.y);
+        let (x1, y1) = (x0 + b.width, y0 + b.height);
+        let e = lines.entry(item.line_index).or_insert((x0, y0, x1, y1));
+        *e = (e.0.min(x0), e.1.min(y0), e.2.max(x1), e.3.max(y1));
+    }
+    lines
+        .into_iter()
+        .map(|(line, (x0, y0, x1, y1))| {
+            let rect = LogicalRect::new(
+                LogicalPosition::new(x0, y0),
+                LogicalSize::new(x1 - x0, y1 - y0),
+            );
+            (line, rect)
+        })
+        .collect()
+}
+
+fn is_within(inner: &LogicalRect, outer: &LogicalRect) -> bool {
+    const EPSILON: f32 = 0.01;
+    inner.origin.x >= outer.origin.x - EPSILON
+        && inner.origin.y >= outer.origin.y - EPSILON
+        && inner.origin.x + inner.size.width <= outer.origin.x + outer.size.width + EPSILON
+        && inner.origin.y + inner.size.height <= outer.origin.y + outer.size.height + EPSILON
+}
+
+#[test]
+fn test_hit_test_char_at_start_middle_and_past_end() {
+    let layout = paragraph_layout("hello", 400.0);
+    let spans = cluster_spans(&layout);
+    assert_eq!(spans.len(), 5);
+    let y = layout.items[0].position.y + 1.0;
+
+    // Before the first glyph
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(-10.0, y)), Some(0));
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(spans[0].0 + 0.1, y)), Some(0));
+
+    // Leading half of the third glyph: caret before it, trailing half: after it
+    let (x, width) = spans[2];
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(x + width * 0.25, y)), Some(2));
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(x + width * 0.75, y)), Some(3));
+
+    // Just past the end of the line
+    let (x, width) = spans[4];
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(x + width + 5.0, y)), Some(5));
+}
+
+#[test]
+fn test_hit_test_char_on_empty_layout_is_none() {
+    let layout = UnifiedLayout {
+        items: Vec::new(),
+        overflow: Default::default(),
+    };
+    assert_eq!(layout.hit_test_char(LogicalPosition::new(0.0, 0.0)), None);
+}
+
+#[test]
+fn test_selection_rects_single_line_partial() {
+    let layout = paragraph_layout("hello", 400.0);
+    let spans = cluster_spans(&layout);
+    let line = line_bounds(&layout)[&0];
+
+    // "ell"
+    let rects = layout.selection_rects(1, 4);
+    assert_eq!(rects.len(), 1);
+    assert!(is_within(&rects[0], &line), "{:?} not in {:?}", rects[0], line);
+    assert!((rects[0].origin.x - spans[1].0).abs() < 0.01);
+    assert!((rects[0].origin.x + rects[0].size.width - (spans[3].0 + spans[3].1)).abs() < 0.01);
+
+    // Reversed ranges are normalized, empty ranges select nothing
+    assert_eq!(layout.selection_rects(4, 1), rects);
+    assert!(layout.selection_rects(2, 2).is_empty());
+}
+
+#[test]
+fn test_selection_rects_multi_line() {
+    // Narrow enough that every word ends up on its own line
+    let layout = paragraph_layout("aaa bbb ccc", 40.0);
+    let lines = line_bounds(&layout);
+    assert_eq!(lines.len(), 3);
+
+    // From inside the first word to inside the last one
+    let rects = layout.selection_rects(1, 10);
+    assert_eq!(rects.len(), 3);
+    for (rect, line) in rects.iter().zip(lines.values()) {
+        assert!(is_within(rect, line), "{:?} not in {:?}", rect, line);
+    }
+
+    // First and last line are partial, the middle one covers the whole line
+    assert!(rects[0].origin.x > lines[&0].origin.x);
+    assert!((rects[1].size.width - lines[&1].size.width).abs() < 0.01);
+    assert!(
+        rects[2].origin.x + rects[2].size.width < lines[&2].origin.x + lines[&2].size.width
+    );
+}
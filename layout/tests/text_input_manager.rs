@@ -0,0 +1,52 @@
+//! Tests for recording text input and reading back the typed character
+
+use azul_core::{
+    dom::{DomId, DomNodeId, NodeId},
+    styled_dom::NodeHierarchyItemId,
+};
+use azul_layout::managers::text_input::{TextInputManager, TextInputSource};
+
+fn text_node() -> DomNodeId {
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+    }
+}
+
+fn type_text(text: &str, source: TextInputSource) -> TextInputManager {
+    let mut manager = TextInputManager::new();
+    manager.record_input(text_node(), text.to_string(), "hello ".to_string(), source);
+    manager
+}
+
+#[test]
+fn test_typed_char_is_exposed() {
+    let manager = type_text("a", TextInputSource::Keyboard);
+    let changeset = manager.get_pending_changeset().unwrap();
+    assert_eq!(changeset.inserted_char(), Some('a'));
+    assert_eq!(changeset.node, text_node());
+}
+
+#[test]
+fn test_composed_sequence_is_only_exposed_as_text() {
+    // Dead key composition delivering "e" + combining acute accent
+    let manager = type_text("e\u{301}", TextInputSource::Keyboard);
+    let changeset = manager.get_pending_changeset().unwrap();
+    assert_eq!(changeset.inserted_char(), None);
+    assert_eq!(changeset.inserted_text.as_str(), "e\u{301}");
+
+    let manager = type_text("日本", TextInputSource::Ime);
+    let changeset = manager.get_pending_changeset().unwrap();
+    assert_eq!(changeset.inserted_char(), None);
+    assert_eq!(changeset.inserted_text.as_str(), "日本");
+}
+
+#[test]
+fn test_no_char_without_pending_input() {
+    let mut manager = type_text("a", TextInputSource::Keyboard);
+    manager.clear_changeset();
+    assert!(manager.get_pending_changeset().is_none());
+
+    let manager = type_text("", TextInputSource::Keyboard);
+    assert_eq!(manager.get_pending_changeset().unwrap().inserted_char(), None);
+}
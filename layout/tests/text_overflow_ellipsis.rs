@@ -0,0 +1,96 @@
+//! Tests for `overflow_ellipsis` truncating no-wrap lines with "…"
+
+use std::{collections::HashMap, sync::Arc};
+
+use azul_css::props::basic::FontRef;
+use azul_layout::text3::cache::{
+    create_logical_items, perform_fragment_layout, reorder_logical_items, shape_visual_items,
+    AvailableSpace, BidiDirection, BreakCursor, FontStack, InlineContent, LoadedFonts,
+    StyleProperties, StyledRun, TextWrap, UnifiedConstraints, UnifiedLayout,
+};
+use rust_fontconfig::FcFontCache;
+
+mod common;
+
+/// Lays out `text` as a single no-wrap line with an ellipsis in `width` px
+fn ellipsis_layout(
+    fc_cache: &FcFontCache,
+    font: &FontRef,
+    text: &str,
+    width: f32,
+) -> UnifiedLayout {
+    let style = Arc::new(StyleProperties {
+        font_stack: FontStack::Ref(font.clone()),
+        ..Default::default()
+    });
+    let content = [InlineContent::Text(StyledRun {
+        text: text.to_string(),
+        style,
+        logical_start_byte: 0,
+        source_node_id: None,
+    })];
+
+    let logical = create_logical_items(&content, &[], &mut None);
+    let visual = reorder_logical_items(&logical, BidiDirection::Ltr, &mut None).unwrap();
+    let fonts = LoadedFonts::<FontRef>::new();
+    let shaped = shape_visual_items(&visual, &HashMap::new(), fc_cache, &fonts, &mut None).unwrap();
+
+    let constraints = UnifiedConstraints {
+        available_width: AvailableSpace::Definite(width),
+        text_wrap: TextWrap::NoWrap,
+        overflow_ellipsis: true,
+        ..Default::default()
+    };
+    perform_fragment_layout(
+        &mut BreakCursor::new(&shaped),
+        &logical,
+        &constraints,
+        &mut None,
+        &fonts,
+    )
+    .unwrap()
+}
+
+fn layout_text(layout: &UnifiedLayout) -> String {
+    layout
+        .items
+        .iter()
+        .filter_map(|item| Some(item.item.as_cluster()?.text.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_long_line_is_truncated_with_ellipsis() {
+    let fc_cache = FcFontCache::build();
+    let font = common::test_font();
+
+    let max_width = 80.0;
+    let layout = ellipsis_layout(
+        &fc_cache,
+        &font,
+        "The quick brown fox jumps over the lazy dog",
+        max_width,
+    );
+
+    let text = layout_text(&layout);
+    assert!(text.ends_with('\u{2026}'), "{text:?}");
+    assert!(
+        text.len() > '\u{2026}'.len_utf8(),
+        "nothing of the text was kept"
+    );
+    assert!(layout.items.iter().all(|item| item.line_index == 0));
+    let width = layout.bounds().width;
+    assert!(
+        width <= max_width,
+        "line is {width}px wide, max is {max_width}px"
+    );
+}
+
+#[test]
+fn test_fitting_line_is_untouched() {
+    let fc_cache = FcFontCache::build();
+    let font = common::test_font();
+
+    let layout = ellipsis_layout(&fc_cache, &font, "fox", 400.0);
+    assert_eq!(layout_text(&layout), "fox");
+}
@@ -0,0 +1,149 @@
+//! Tests for `text-transform` being applied before shaping
+
+use std::{collections::HashMap, sync::Arc};
+
+use azul_css::props::basic::FontRef;
+use azul_layout::text3::cache::{
+    create_logical_items, get_item_measure, reorder_logical_items, shape_visual_items,
+    BidiDirection, FontStack, InlineContent, LayoutCache, LayoutFragment, LoadedFonts, ShapedItem,
+    StyleProperties, StyledRun, TextTransform, UnifiedConstraints,
+};
+use rust_fontconfig::FcFontCache;
+
+mod common;
+
+fn text_content(font: &FontRef, text: &str, text_transform: TextTransform) -> [InlineContent; 1] {
+    let style = Arc::new(StyleProperties {
+        font_stack: FontStack::Ref(font.clone()),
+        text_transform,
+        ..Default::default()
+    });
+    [InlineContent::Text(StyledRun {
+        text: text.to_string(),
+        style,
+        logical_start_byte: 0,
+        source_node_id: None,
+    })]
+}
+
+/// Shapes `text` with the given transform, returns the clusters' text and the total advance
+fn shape(
+    fc_cache: &FcFontCache,
+    font: &FontRef,
+    text: &str,
+    text_transform: TextTransform,
+) -> (String, f32) {
+    let content = text_content(font, text, text_transform);
+    let logical = create_logical_items(&content, &[], &mut None);
+    let visual = reorder_logical_items(&logical, BidiDirection::Ltr, &mut None).unwrap();
+    let shaped = shape_visual_items(
+        &visual,
+        &HashMap::new(),
+        fc_cache,
+        &LoadedFonts::<FontRef>::new(),
+        &mut None,
+    )
+    .unwrap();
+
+    let text = shaped
+        .iter()
+        .filter_map(|item| match item {
+            ShapedItem::Cluster(c) => Some(c.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    let width = shaped
+        .iter()
+        .map(|item| get_item_measure(item, false))
+        .sum();
+    (text, width)
+}
+
+#[test]
+fn test_uppercase_is_measured_as_uppercase() {
+    let fc_cache = FcFontCache::build();
+    let font = common::test_font();
+
+    // Capitals are wider than their lowercase forms for these letters in any
+    // Latin font (unlike e.g. "m" vs "M")
+    let (_, lower) = shape(&fc_cache, &font, "wave", TextTransform::None);
+    let (_, upper) = shape(&fc_cache, &font, "WAVE", TextTransform::None);
+    let (text, transformed) = shape(&fc_cache, &font, "wave", TextTransform::Uppercase);
+
+    assert!(upper > lower);
+    assert!(
+        (transformed - upper).abs() < 0.01,
+        "expected {upper}, got {transformed}"
+    );
+    // Selection and copy still see the original text
+    assert_eq!(text, "wave");
+}
+
+/// Lays out `text` through the cached pipeline, returns the width of the line
+fn cached_layout_width(
+    cache: &mut LayoutCache,
+    fc_cache: &FcFontCache,
+    font: &FontRef,
+    text: &str,
+    text_transform: TextTransform,
+) -> f32 {
+    let flow_chain = [LayoutFragment {
+        id: "main".into(),
+        constraints: UnifiedConstraints::default(),
+    }];
+    let flow = cache
+        .layout_flow(
+            &text_content(font, text, text_transform),
+            &[],
+            &flow_chain,
+            &HashMap::new(),
+            fc_cache,
+            &LoadedFonts::<FontRef>::new(),
+            &mut None,
+        )
+        .unwrap();
+    flow.fragment_layouts["main"]
+        .items
+        .iter()
+        .map(|item| get_item_measure(&item.item, false))
+        .sum()
+}
+
+#[test]
+fn test_text_transform_change_reshapes_cached_text() {
+    let fc_cache = FcFontCache::build();
+    let font = common::test_font();
+
+    let mut cache = LayoutCache::new();
+    let upper = cached_layout_width(
+        &mut cache,
+        &fc_cache,
+        &font,
+        "wave",
+        TextTransform::Uppercase,
+    );
+    let shaping_runs = cache.get_shaping_runs();
+    let none = cached_layout_width(&mut cache, &fc_cache, &font, "wave", TextTransform::None);
+
+    // The uppercase glyphs must not be reused for the untransformed text
+    assert_eq!(cache.get_shaping_runs(), shaping_runs + 1);
+    let (_, lower) = shape(&fc_cache, &font, "wave", TextTransform::None);
+    assert!((none - lower).abs() < 0.01, "expected {lower}, got {none}");
+    assert!(upper > none);
+}
+
+#[test]
+fn test_text_transform_apply() {
+    assert_eq!(TextTransform::Uppercase.apply("hello wörld"), "HELLO WÖRLD");
+    assert_eq!(TextTransform::Lowercase.apply("HeLLo"), "hello");
+    assert_eq!(TextTransform::None.apply("HeLLo"), "HeLLo");
+
+    // Only word-initial letters change
+    assert_eq!(
+        TextTransform::Capitalize.apply("hello wORLD (again) don't"),
+        "Hello WORLD (Again) Don't"
+    );
+
+    // Mappings that would change the byte length are skipped
+    assert_eq!(TextTransform::Uppercase.apply("straße"), "STRAßE");
+}
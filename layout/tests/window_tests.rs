@@ -160,3 +160,27 @@ fn test_delta_accumulation() {
     assert_eq!(scroll_offset.x, 7.0);
     assert_eq!(scroll_offset.y, 10.0);
 }
+
+#[test]
+fn test_mouse_delta_between_frames() {
+    use azul_core::window::CursorPosition;
+    use azul_layout::window_state::FullWindowState;
+
+    let mut previous = FullWindowState::default();
+    previous.mouse_state.cursor_position =
+        CursorPosition::InWindow(LogicalPosition::new(10.0, 20.0));
+
+    let mut current = previous.clone();
+    current.mouse_state.cursor_position =
+        CursorPosition::InWindow(LogicalPosition::new(25.0, 15.0));
+
+    assert_eq!(
+        current.get_mouse_delta(&previous),
+        Some(LogicalPosition::new(15.0, -5.0))
+    );
+
+    // Cursor left the window: no meaningful delta
+    current.mouse_state.cursor_position =
+        CursorPosition::OutOfWindow(LogicalPosition::new(-1.0, -1.0));
+    assert_eq!(current.get_mouse_delta(&previous), None);
+}